@@ -6,7 +6,7 @@ pub enum Error {
 
 /// The number of acknowledgments the producer requires the leader to have received before
 /// considering a request complete.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AckRequirement {
     FullISR,
     NoAcknowledgements,
@@ -25,3 +25,13 @@ impl TryFrom<i16> for AckRequirement {
         }
     }
 }
+
+impl From<AckRequirement> for i16 {
+    fn from(value: AckRequirement) -> Self {
+        match value {
+            AckRequirement::FullISR => -1,
+            AckRequirement::NoAcknowledgements => 0,
+            AckRequirement::LeaderOnly => 1,
+        }
+    }
+}
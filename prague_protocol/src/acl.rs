@@ -0,0 +1,296 @@
+//! Includes types shared by the [DescribeAclsApi](crate::describe_acls::DescribeAclsApi),
+//! [CreateAclsApi](crate::create_acls::CreateAclsApi) and
+//! [DeleteAclsApi](crate::delete_acls::DeleteAclsApi), as well as the authorized operations
+//! reported by other APIs.
+//!
+//! See: <https://kafka.apache.org/documentation/#security_authz>
+
+use std::collections::HashSet;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Resource type `{0}` is not valid")]
+    InvalidResourceType(i8),
+
+    #[error("Pattern type `{0}` is not valid")]
+    InvalidPatternType(i8),
+
+    #[error("ACL operation `{0}` is not valid")]
+    InvalidAclOperation(i8),
+
+    #[error("ACL permission type `{0}` is not valid")]
+    InvalidAclPermissionType(i8),
+}
+
+/// The kinds of resources that ACLs can be applied to.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/java/org/apache/kafka/common/resource/ResourceType.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// Represents any resource type which this client cannot understand.
+    Unknown,
+
+    /// In a filter, matches any resource type.
+    Any,
+
+    /// A Kafka topic.
+    Topic,
+
+    /// A consumer group.
+    Group,
+
+    /// The cluster as a whole.
+    Cluster,
+
+    /// A transactional ID.
+    TransactionalId,
+
+    /// A token ID.
+    DelegationToken,
+
+    /// A user principal.
+    User,
+}
+
+impl TryFrom<i8> for ResourceType {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::Any),
+            2 => Ok(Self::Topic),
+            3 => Ok(Self::Group),
+            4 => Ok(Self::Cluster),
+            5 => Ok(Self::TransactionalId),
+            6 => Ok(Self::DelegationToken),
+            7 => Ok(Self::User),
+            code => Err(Error::InvalidResourceType(code)),
+        }
+    }
+}
+
+/// How a resource name in an ACL is matched against the names of resources.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/java/org/apache/kafka/common/resource/PatternType.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PatternType {
+    /// Represents any pattern type which this client cannot understand.
+    Unknown,
+
+    /// In a filter, matches any resource pattern type.
+    Any,
+
+    /// In a filter, performs pattern matching, e.g. a literal filter of `foo` will match literal
+    /// `foo`, literal `*` and prefixed `f`.
+    Match,
+
+    /// The resource name is matched exactly. The name `*` matches all resources of its type.
+    Literal,
+
+    /// The resource name is matched as a prefix.
+    Prefixed,
+}
+
+impl TryFrom<i8> for PatternType {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::Any),
+            2 => Ok(Self::Match),
+            3 => Ok(Self::Literal),
+            4 => Ok(Self::Prefixed),
+            code => Err(Error::InvalidPatternType(code)),
+        }
+    }
+}
+
+/// The operations that an ACL can allow or deny.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/java/org/apache/kafka/common/acl/AclOperation.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AclOperation {
+    /// Represents any operation which this client cannot understand.
+    Unknown,
+
+    /// In a filter, matches any operation.
+    Any,
+
+    /// Matches every operation.
+    All,
+    Read,
+    Write,
+    Create,
+    Delete,
+    Alter,
+    Describe,
+    ClusterAction,
+    DescribeConfigs,
+    AlterConfigs,
+    IdempotentWrite,
+    CreateTokens,
+    DescribeTokens,
+}
+
+impl AclOperation {
+    /// The value of an authorized operations bitfield when authorized operations were not
+    /// requested.
+    pub const AUTHORIZED_OPERATIONS_OMITTED: i32 = i32::MIN;
+
+    /// Decodes an authorized operations bitfield, in which bit `n` is set if the operation with
+    /// code `n` is allowed. Returns None if the bitfield was not requested.
+    ///
+    /// Bits that do not map to a known operation are decoded as [AclOperation::Unknown].
+    pub fn parse_bitfield(value: i32) -> Option<HashSet<AclOperation>> {
+        if value == Self::AUTHORIZED_OPERATIONS_OMITTED {
+            return None;
+        }
+
+        Some(
+            (0..i32::BITS as i8)
+                .filter(|bit| value & (1 << bit) != 0)
+                .map(|bit| bit.try_into().unwrap_or(Self::Unknown))
+                .collect(),
+        )
+    }
+}
+
+impl TryFrom<i8> for AclOperation {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::Any),
+            2 => Ok(Self::All),
+            3 => Ok(Self::Read),
+            4 => Ok(Self::Write),
+            5 => Ok(Self::Create),
+            6 => Ok(Self::Delete),
+            7 => Ok(Self::Alter),
+            8 => Ok(Self::Describe),
+            9 => Ok(Self::ClusterAction),
+            10 => Ok(Self::DescribeConfigs),
+            11 => Ok(Self::AlterConfigs),
+            12 => Ok(Self::IdempotentWrite),
+            13 => Ok(Self::CreateTokens),
+            14 => Ok(Self::DescribeTokens),
+            code => Err(Error::InvalidAclOperation(code)),
+        }
+    }
+}
+
+/// Whether an ACL allows or denies an operation.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/java/org/apache/kafka/common/acl/AclPermissionType.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AclPermissionType {
+    /// Represents any permission type which this client cannot understand.
+    Unknown,
+
+    /// In a filter, matches any permission type.
+    Any,
+
+    /// Disallows access.
+    Deny,
+
+    /// Grants access.
+    Allow,
+}
+
+impl TryFrom<i8> for AclPermissionType {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::Any),
+            2 => Ok(Self::Deny),
+            3 => Ok(Self::Allow),
+            code => Err(Error::InvalidAclPermissionType(code)),
+        }
+    }
+}
+
+/// A resource, or pattern of resources, that an ACL applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResourcePattern {
+    /// The resource type.
+    pub resource_type: ResourceType,
+
+    /// The resource name, or pattern of names.
+    pub name: String,
+
+    /// How [name](Self::name) is matched against resource names.
+    ///
+    /// Brokers before version 1 only support [PatternType::Literal].
+    pub pattern_type: PatternType,
+}
+
+/// Who an ACL applies to and what it allows or denies.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccessControlEntry {
+    /// The principal, e.g. `User:alice`.
+    pub principal: String,
+
+    /// The host the principal connects from, or `*` for any host.
+    pub host: String,
+
+    /// The operation.
+    pub operation: AclOperation,
+
+    /// Whether the operation is allowed or denied.
+    pub permission_type: AclPermissionType,
+}
+
+/// A single ACL, binding an [AccessControlEntry] to a [ResourcePattern].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AclBinding {
+    /// The resources this ACL applies to.
+    pub pattern: ResourcePattern,
+
+    /// The principal, host, operation and permission of this ACL.
+    pub entry: AccessControlEntry,
+}
+
+/// A filter that matches [ResourcePattern]s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResourcePatternFilter {
+    /// The resource type to match, or [ResourceType::Any].
+    pub resource_type: ResourceType,
+
+    /// The resource name to match, or None to match any name.
+    pub name: Option<String>,
+
+    /// The pattern type to match, [PatternType::Any], or [PatternType::Match] to match every
+    /// pattern that applies to [name](Self::name).
+    pub pattern_type: PatternType,
+}
+
+/// A filter that matches [AccessControlEntry]s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccessControlEntryFilter {
+    /// The principal to match, or None to match any principal.
+    pub principal: Option<String>,
+
+    /// The host to match, or None to match any host.
+    pub host: Option<String>,
+
+    /// The operation to match, or [AclOperation::Any].
+    pub operation: AclOperation,
+
+    /// The permission type to match, or [AclPermissionType::Any].
+    pub permission_type: AclPermissionType,
+}
+
+/// A filter that matches [AclBinding]s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AclBindingFilter {
+    /// The filter applied to each binding's resource pattern.
+    pub pattern: ResourcePatternFilter,
+
+    /// The filter applied to each binding's entry.
+    pub entry: AccessControlEntryFilter,
+}
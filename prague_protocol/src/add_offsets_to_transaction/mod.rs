@@ -0,0 +1,29 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::AddOffsetsToTransactionRequest, response::AddOffsetsToTransactionResponse,
+        AddOffsetsToTransactionApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct AddOffsetsToTransactionApi;
+
+impl Api for AddOffsetsToTransactionApi {
+    const KEY: ApiKey = ApiKey::AddOffsetsToTransaction;
+
+    type Request = AddOffsetsToTransactionRequest;
+    type Response = AddOffsetsToTransactionResponse;
+}
+
+impl VersionedApi for AddOffsetsToTransactionApi {
+    /// Version 4 only adds an error code.
+    const MIN_VERSION: i16 = 0;
+    const MAX_VERSION: i16 = 3;
+    const FLEXIBLE_VERSION: Option<i16> = Some(3);
+}
@@ -0,0 +1,58 @@
+//! Includes definitions for requests to
+//! [AddOffsetsToTransactionApi](super::AddOffsetsToTransactionApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AddOffsetsToTxnRequest.json>
+
+use crate::{
+    codec::{Encode, Error, Writer},
+    types::prelude::*,
+};
+
+/// A request to add the offsets of a consumer group to the ongoing transaction of a producer,
+/// before it commits them with a
+/// [TransactionOffsetCommitRequest](crate::transaction_offset_commit::request::TransactionOffsetCommitRequest).
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AddOffsetsToTxnRequest.json>
+pub struct AddOffsetsToTransactionRequest {
+    /// The transactional ID corresponding to the transaction.
+    ///
+    /// Versions: 0+
+    pub transactional_id: String,
+
+    /// The current producer ID in use by the transactional ID.
+    ///
+    /// Versions: 0+
+    pub producer_id: ProducerId,
+
+    /// The current epoch associated with the producer ID.
+    ///
+    /// Versions: 0+
+    pub producer_epoch: i16,
+
+    /// The unique group identifier.
+    ///
+    /// Versions: 0+
+    pub group_id: String,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Encode for AddOffsetsToTransactionRequest {
+    fn encode(&self, writer: &mut Writer, _version: i16) -> Result<(), Error> {
+        writer.string(&self.transactional_id);
+        writer.i64(self.producer_id.0);
+        writer.i16(self.producer_epoch);
+        writer.string(&self.group_id);
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+//! Includes definitions for responses from
+//! [AddOffsetsToTransactionApi](super::AddOffsetsToTransactionApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AddOffsetsToTxnResponse.json>
+
+use crate::{
+    codec::{Decode, Error, Reader},
+    error_code::ErrorCode,
+    types::prelude::*,
+};
+
+/// A response message associated with
+/// [AddOffsetsToTransactionRequest](super::request::AddOffsetsToTransactionRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AddOffsetsToTxnResponse.json>
+pub struct AddOffsetsToTransactionResponse {
+    /// The duration in milliseconds for which the request was throttled due to a quota violation,
+    /// or None if the request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The response error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Decode for AddOffsetsToTransactionResponse {
+    fn decode(reader: &mut Reader, _version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            throttle_time: reader.throttle_time()?,
+            error_code: reader.error_code()?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
@@ -0,0 +1,30 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::AddPartitionsToTransactionRequest, response::AddPartitionsToTransactionResponse,
+        AddPartitionsToTransactionApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct AddPartitionsToTransactionApi;
+
+impl Api for AddPartitionsToTransactionApi {
+    const KEY: ApiKey = ApiKey::AddPartitionsToTransaction;
+
+    type Request = AddPartitionsToTransactionRequest;
+    type Response = AddPartitionsToTransactionResponse;
+}
+
+impl VersionedApi for AddPartitionsToTransactionApi {
+    /// Versions 4 and later batch transactions for brokers verifying them, and are not sent by
+    /// producers.
+    const MIN_VERSION: i16 = 0;
+    const MAX_VERSION: i16 = 3;
+    const FLEXIBLE_VERSION: Option<i16> = Some(3);
+}
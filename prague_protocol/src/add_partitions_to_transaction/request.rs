@@ -0,0 +1,92 @@
+//! Includes definitions for requests to
+//! [AddPartitionsToTransactionApi](super::AddPartitionsToTransactionApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AddPartitionsToTxnRequest.json>
+
+use crate::{
+    codec::{Encode, Error, Writer},
+    types::prelude::*,
+};
+
+/// A request to add partitions to the ongoing transaction of a producer, before it produces to
+/// them.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AddPartitionsToTxnRequest.json>
+pub struct AddPartitionsToTransactionRequest {
+    /// The transactional ID of the transaction to add the partitions to.
+    ///
+    /// Versions: 0-3
+    pub transactional_id: String,
+
+    /// The current producer ID in use by the transactional ID.
+    ///
+    /// Versions: 0-3
+    pub producer_id: ProducerId,
+
+    /// The current epoch associated with the producer ID.
+    ///
+    /// Versions: 0-3
+    pub producer_epoch: i16,
+
+    /// The partitions to add to the transaction.
+    ///
+    /// Versions: 0-3
+    pub topics: Vec<AddPartitionsToTransactionTopic>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The partitions of a topic to add to a transaction.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AddPartitionsToTxnRequest.json>
+pub struct AddPartitionsToTransactionTopic {
+    /// The name of the topic.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The partition indexes to add to the transaction.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<PartitionIndex>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Encode for AddPartitionsToTransactionRequest {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), Error> {
+        writer.string(&self.transactional_id);
+        writer.i64(self.producer_id.0);
+        writer.i16(self.producer_epoch);
+        writer.array(&self.topics, |writer, topic| topic.encode(writer, version))?;
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
+
+impl Encode for AddPartitionsToTransactionTopic {
+    fn encode(&self, writer: &mut Writer, _version: i16) -> Result<(), Error> {
+        writer.string(&self.name);
+        writer.array(&self.partitions, |writer, partition| {
+            writer.i32(partition.0);
+            Ok(())
+        })?;
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
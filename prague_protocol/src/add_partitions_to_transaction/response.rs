@@ -0,0 +1,118 @@
+//! Includes definitions for responses from
+//! [AddPartitionsToTransactionApi](super::AddPartitionsToTransactionApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AddPartitionsToTxnResponse.json>
+
+use crate::{
+    codec::{Decode, Error, Reader},
+    error_code::ErrorCode,
+    types::prelude::*,
+};
+
+/// A response message associated with
+/// [AddPartitionsToTransactionRequest](super::request::AddPartitionsToTransactionRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AddPartitionsToTxnResponse.json>
+pub struct AddPartitionsToTransactionResponse {
+    /// The duration in milliseconds for which the request was throttled due to a quota violation,
+    /// or None if the request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The results for each topic.
+    ///
+    /// Versions: 0-3
+    pub results_by_topic: Vec<AddPartitionsToTransactionTopicResult>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The results of adding the partitions of a topic to a transaction.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AddPartitionsToTxnResponse.json>
+pub struct AddPartitionsToTransactionTopicResult {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The results for each partition.
+    ///
+    /// Versions: 0+
+    pub results_by_partition: Vec<AddPartitionsToTransactionPartitionResult>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The result of adding a partition to a transaction.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AddPartitionsToTxnResponse.json>
+pub struct AddPartitionsToTransactionPartitionResult {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub partition_index: PartitionIndex,
+
+    /// The response error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub partition_error_code: Option<ErrorCode>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Decode for AddPartitionsToTransactionResponse {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            throttle_time: reader.throttle_time()?,
+            results_by_topic: reader.array("results_by_topic", |reader| {
+                AddPartitionsToTransactionTopicResult::decode(reader, version)
+            })?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+impl Decode for AddPartitionsToTransactionTopicResult {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            name: reader.string("name")?,
+            results_by_partition: reader.array("results_by_partition", |reader| {
+                AddPartitionsToTransactionPartitionResult::decode(reader, version)
+            })?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+impl Decode for AddPartitionsToTransactionPartitionResult {
+    fn decode(reader: &mut Reader, _version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            partition_index: PartitionIndex(reader.i32()?),
+            partition_error_code: reader.error_code()?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::AlterClientQuotasRequest, response::AlterClientQuotasResponse,
+        AlterClientQuotasApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct AlterClientQuotasApi;
+
+impl Api for AlterClientQuotasApi {
+    const KEY: ApiKey = ApiKey::AlterClientQuotas;
+
+    type Request = AlterClientQuotasRequest;
+    type Response = AlterClientQuotasResponse;
+}
@@ -0,0 +1,89 @@
+//! Includes definitions for requests to [AlterClientQuotasApi](super::AlterClientQuotasApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterClientQuotasRequest.json>
+
+use crate::{quota::QuotaEntityComponent, types::prelude::*};
+
+/// A request to set or remove the quotas of one or more entities.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterClientQuotasRequest.json>
+pub struct AlterClientQuotasRequest {
+    /// The quota configuration entries to alter.
+    ///
+    /// Versions: 0+
+    pub entries: Vec<EntryData>,
+
+    /// Whether the alteration should be validated, but not performed.
+    ///
+    /// Versions: 0+
+    pub validate_only: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The alterations to make to the quotas of a single entity.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterClientQuotasRequest.json>
+pub struct EntryData {
+    /// The quota entity to alter.
+    ///
+    /// Versions: 0+
+    pub entity: Vec<QuotaEntityComponent>,
+
+    /// An individual quota configuration entry to alter.
+    ///
+    /// Versions: 0+
+    pub ops: Vec<OpData>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// An alteration of a single quota value.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterClientQuotasRequest.json>
+pub struct OpData {
+    /// The quota configuration key, e.g. [PRODUCER_BYTE_RATE](crate::quota::PRODUCER_BYTE_RATE).
+    ///
+    /// Versions: 0+
+    pub key: String,
+
+    /// What to do with the quota.
+    ///
+    /// Over the wire, this is transmitted as a `Value` and a `Remove` flag.
+    ///
+    /// Versions: 0+
+    pub op: QuotaOp,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// How an [OpData] alters a quota.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuotaOp {
+    /// Sets the quota to the given value.
+    Set(f64),
+
+    /// Removes the quota, so that a less specific quota applies instead.
+    Remove,
+}
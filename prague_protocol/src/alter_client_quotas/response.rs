@@ -0,0 +1,61 @@
+//! Includes definitions for responses from [AlterClientQuotasApi](super::AlterClientQuotasApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterClientQuotasResponse.json>
+
+use crate::{error_code::ErrorCode, quota::QuotaEntityComponent, types::prelude::*};
+
+/// A response message associated with
+/// [AlterClientQuotasRequest](super::request::AlterClientQuotasRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterClientQuotasResponse.json>
+pub struct AlterClientQuotasResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The quota configuration entries to alter.
+    ///
+    /// Versions: 0+
+    pub entries: Vec<EntryData>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the result of altering the quotas of a single entity.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterClientQuotasResponse.json>
+pub struct EntryData {
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The quota entity that was altered.
+    ///
+    /// Versions: 0+
+    pub entity: Vec<QuotaEntityComponent>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
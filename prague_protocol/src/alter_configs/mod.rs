@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::AlterConfigsRequest, response::AlterConfigsResponse, AlterConfigsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct AlterConfigsApi;
+
+impl Api for AlterConfigsApi {
+    const KEY: ApiKey = ApiKey::AlterConfigs;
+
+    type Request = AlterConfigsRequest;
+    type Response = AlterConfigsResponse;
+}
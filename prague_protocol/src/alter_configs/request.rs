@@ -0,0 +1,81 @@
+//! Includes definitions for requests to [AlterConfigsApi](super::AlterConfigsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterConfigsRequest.json>
+
+use crate::{config::ConfigResource, types::prelude::*};
+
+/// A request to replace the configuration of one or more resources.
+///
+/// Any configuration not included in a resource's list is reset to its default. To change
+/// individual configurations, see
+/// [IncrementalAlterConfigsApi](crate::incremental_alter_configs::IncrementalAlterConfigsApi).
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterConfigsRequest.json>
+pub struct AlterConfigsRequest {
+    /// The updates for each resource.
+    ///
+    /// Versions: 0+
+    pub resources: Vec<AlterConfigsResource>,
+
+    /// True if we should validate the request, but not change the configurations.
+    ///
+    /// Versions: 0+
+    pub validate_only: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A resource whose configuration we want to replace.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterConfigsRequest.json>
+pub struct AlterConfigsResource {
+    /// The resource type and name.
+    ///
+    /// Versions: 0+
+    pub resource: ConfigResource,
+
+    /// The configurations.
+    ///
+    /// Versions: 0+
+    pub configs: Vec<AlterableConfig>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A configuration entry to set.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterConfigsRequest.json>
+pub struct AlterableConfig {
+    /// The configuration key name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The value to set for the configuration key.
+    ///
+    /// Versions: 0+
+    pub value: Option<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,60 @@
+//! Includes definitions for responses from [AlterConfigsApi](super::AlterConfigsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterConfigsResponse.json>
+
+use crate::{config::ConfigResource, error_code::ErrorCode, types::prelude::*};
+
+/// A response message associated with [AlterConfigsRequest](super::request::AlterConfigsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterConfigsResponse.json>
+pub struct AlterConfigsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The responses for each resource.
+    ///
+    /// Versions: 0+
+    pub responses: Vec<AlterConfigsResourceResult>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the result of altering the configuration of a single resource.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterConfigsResponse.json>
+pub struct AlterConfigsResourceResult {
+    /// The resource error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The resource error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The resource type and name.
+    ///
+    /// Versions: 0+
+    pub resource: ConfigResource,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::AlterPartitionReassignmentsRequest, response::AlterPartitionReassignmentsResponse,
+        AlterPartitionReassignmentsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct AlterPartitionReassignmentsApi;
+
+impl Api for AlterPartitionReassignmentsApi {
+    const KEY: ApiKey = ApiKey::AlterPartitionReassignments;
+
+    type Request = AlterPartitionReassignmentsRequest;
+    type Response = AlterPartitionReassignmentsResponse;
+}
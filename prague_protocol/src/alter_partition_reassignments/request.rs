@@ -0,0 +1,79 @@
+//! Includes definitions for requests to
+//! [AlterPartitionReassignmentsApi](super::AlterPartitionReassignmentsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterPartitionReassignmentsRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request to start or cancel moving partitions to different replicas.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterPartitionReassignmentsRequest.json>
+pub struct AlterPartitionReassignmentsRequest {
+    /// The time in milliseconds to wait for the request to complete.
+    ///
+    /// Versions: 0+
+    pub timeout: Duration,
+
+    /// The topics to reassign.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<ReassignableTopic>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A topic whose partitions we want to reassign.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterPartitionReassignmentsRequest.json>
+pub struct ReassignableTopic {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The partitions to reassign.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<ReassignablePartition>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A partition we want to reassign.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterPartitionReassignmentsRequest.json>
+pub struct ReassignablePartition {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub index: PartitionIndex,
+
+    /// The replicas to place the partition on, or None to cancel a pending reassignment for this
+    /// partition.
+    ///
+    /// Versions: 0+
+    pub replicas: Option<Vec<BrokerId>>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
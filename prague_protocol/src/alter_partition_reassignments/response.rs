@@ -0,0 +1,95 @@
+//! Includes definitions for responses from
+//! [AlterPartitionReassignmentsApi](super::AlterPartitionReassignmentsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterPartitionReassignmentsResponse.json>
+
+use crate::{error_code::ErrorCode, types::prelude::*};
+
+/// A response message associated with
+/// [AlterPartitionReassignmentsRequest](super::request::AlterPartitionReassignmentsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterPartitionReassignmentsResponse.json>
+pub struct AlterPartitionReassignmentsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The top-level error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The top-level error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The responses to topics to reassign.
+    ///
+    /// Versions: 0+
+    pub responses: Vec<ReassignableTopicResponse>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the result of reassigning a topic's partitions.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterPartitionReassignmentsResponse.json>
+pub struct ReassignableTopicResponse {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The responses to partitions to reassign.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<ReassignablePartitionResponse>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the result of reassigning a single partition.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterPartitionReassignmentsResponse.json>
+pub struct ReassignablePartitionResponse {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub index: PartitionIndex,
+
+    /// The error code for this partition, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The error message for this partition, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
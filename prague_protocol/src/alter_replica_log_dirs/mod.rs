@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::AlterReplicaLogDirsRequest, response::AlterReplicaLogDirsResponse,
+        AlterReplicaLogDirsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct AlterReplicaLogDirsApi;
+
+impl Api for AlterReplicaLogDirsApi {
+    const KEY: ApiKey = ApiKey::AlterReplicaLogDirs;
+
+    type Request = AlterReplicaLogDirsRequest;
+    type Response = AlterReplicaLogDirsResponse;
+}
@@ -0,0 +1,72 @@
+//! Includes definitions for requests to [AlterReplicaLogDirsApi](super::AlterReplicaLogDirsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterReplicaLogDirsRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request to move replicas between the log directories of the broker it is sent to.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterReplicaLogDirsRequest.json>
+pub struct AlterReplicaLogDirsRequest {
+    /// The alterations to make for each directory.
+    ///
+    /// Versions: 0+
+    pub dirs: Vec<AlterReplicaLogDir>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A log directory to move replicas into.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterReplicaLogDirsRequest.json>
+pub struct AlterReplicaLogDir {
+    /// The absolute directory path.
+    ///
+    /// Versions: 0+
+    pub path: String,
+
+    /// The topics to move into this directory.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<AlterReplicaLogDirTopic>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A topic whose replicas should be moved into a log directory.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterReplicaLogDirsRequest.json>
+pub struct AlterReplicaLogDirTopic {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The partition indexes.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<PartitionIndex>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
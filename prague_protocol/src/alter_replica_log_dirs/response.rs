@@ -0,0 +1,79 @@
+//! Includes definitions for responses from [AlterReplicaLogDirsApi](super::AlterReplicaLogDirsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterReplicaLogDirsResponse.json>
+
+use crate::{error_code::ErrorCode, types::prelude::*};
+
+/// A response message associated with
+/// [AlterReplicaLogDirsRequest](super::request::AlterReplicaLogDirsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterReplicaLogDirsResponse.json>
+pub struct AlterReplicaLogDirsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The results for each topic.
+    ///
+    /// Versions: 0+
+    pub results: Vec<AlterReplicaLogDirTopicResult>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the result of moving a topic's replicas.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterReplicaLogDirsResponse.json>
+pub struct AlterReplicaLogDirTopicResult {
+    /// The name of the topic.
+    ///
+    /// Versions: 0+
+    pub topic_name: String,
+
+    /// The results for each partition.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<AlterReplicaLogDirPartitionResult>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the result of moving a single replica.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterReplicaLogDirsResponse.json>
+pub struct AlterReplicaLogDirPartitionResult {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub index: PartitionIndex,
+
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
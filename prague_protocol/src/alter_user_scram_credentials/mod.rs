@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::AlterUserScramCredentialsRequest, response::AlterUserScramCredentialsResponse,
+        AlterUserScramCredentialsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct AlterUserScramCredentialsApi;
+
+impl Api for AlterUserScramCredentialsApi {
+    const KEY: ApiKey = ApiKey::AlterUserScramCredentials;
+
+    type Request = AlterUserScramCredentialsRequest;
+    type Response = AlterUserScramCredentialsResponse;
+}
@@ -0,0 +1,95 @@
+//! Includes definitions for requests to
+//! [AlterUserScramCredentialsApi](super::AlterUserScramCredentialsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterUserScramCredentialsRequest.json>
+
+use crate::{scram::ScramMechanism, types::prelude::*};
+
+/// A request to delete, create or replace the SCRAM credentials of users.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterUserScramCredentialsRequest.json>
+pub struct AlterUserScramCredentialsRequest {
+    /// The SCRAM credentials to remove.
+    ///
+    /// Versions: 0+
+    pub deletions: Vec<ScramCredentialDeletion>,
+
+    /// The SCRAM credentials to update or insert.
+    ///
+    /// Versions: 0+
+    pub upsertions: Vec<ScramCredentialUpsertion>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A SCRAM credential to remove.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterUserScramCredentialsRequest.json>
+pub struct ScramCredentialDeletion {
+    /// The user name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The SCRAM mechanism.
+    ///
+    /// Versions: 0+
+    pub mechanism: ScramMechanism,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A SCRAM credential to update or insert.
+///
+/// Use [ScramCredential](crate::scram::ScramCredential) to derive one from a password.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterUserScramCredentialsRequest.json>
+pub struct ScramCredentialUpsertion {
+    /// The user name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The SCRAM mechanism.
+    ///
+    /// Versions: 0+
+    pub mechanism: ScramMechanism,
+
+    /// The number of iterations.
+    ///
+    /// Versions: 0+
+    pub iterations: i32,
+
+    /// A random salt generated by the client.
+    ///
+    /// Versions: 0+
+    pub salt: Vec<u8>,
+
+    /// The salted password.
+    ///
+    /// Versions: 0+
+    pub salted_password: Vec<u8>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
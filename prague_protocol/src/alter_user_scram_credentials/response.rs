@@ -0,0 +1,62 @@
+//! Includes definitions for responses from
+//! [AlterUserScramCredentialsApi](super::AlterUserScramCredentialsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterUserScramCredentialsResponse.json>
+
+use crate::{error_code::ErrorCode, types::prelude::*};
+
+/// A response message associated with
+/// [AlterUserScramCredentialsRequest](super::request::AlterUserScramCredentialsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterUserScramCredentialsResponse.json>
+pub struct AlterUserScramCredentialsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The results for deletions and alterations, one per affected user.
+    ///
+    /// Versions: 0+
+    pub results: Vec<AlterUserScramCredentialsResult>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the result of altering the SCRAM credentials of a single user.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/AlterUserScramCredentialsResponse.json>
+pub struct AlterUserScramCredentialsResult {
+    /// The user name.
+    ///
+    /// Versions: 0+
+    pub user: String,
+
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
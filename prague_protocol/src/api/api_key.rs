@@ -7,6 +7,7 @@ pub enum Error {
 /// Numeric codes that the ApiKey in a request can take.
 ///
 /// See: <https://kafka.apache.org/protocol.html#protocol_api_keys>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ApiKey {
     /// The API key for the [`ProduceApi`](crate::produce::ProduceApi).
     Produce,
@@ -21,62 +22,169 @@ pub enum ApiKey {
     ControlledShutdown,
     OffsetCommit,
     OffsetFetch,
+
+    /// The API key for the [`FindCoordinatorApi`](crate::find_coordinator::FindCoordinatorApi).
     FindCoordinator,
+
     JoinGroup,
     Heartbeat,
     LeaveGroup,
     SyncGroup,
+
+    /// The API key for the [`DescribeGroupsApi`](crate::describe_groups::DescribeGroupsApi).
     DescribeGroups,
+
+    /// The API key for the [`ListGroupsApi`](crate::list_groups::ListGroupsApi).
     ListGroups,
+
+    /// The API key for the [`SaslHandshakeApi`](crate::sasl_handshake::SaslHandshakeApi).
     SaslHandshake,
+
+    /// The API key for the [`ApiVersionsApi`](crate::api_versions::ApiVersionsApi).
     ApiVersions,
+
     CreateTopics,
     DeleteTopics,
     DeleteRecords,
+
+    /// The API key for the [`InitProducerIdApi`](crate::init_producer_id::InitProducerIdApi).
     InitProducerId,
+
+    /// The API key for the [`OffsetForLeaderEpochApi`](crate::offset_for_leader_epoch::OffsetForLeaderEpochApi).
     OffsetForLeaderEpoch,
+
+    /// The API key for the [`AddPartitionsToTransactionApi`](crate::add_partitions_to_transaction::AddPartitionsToTransactionApi).
     AddPartitionsToTransaction,
+
+    /// The API key for the [`AddOffsetsToTransactionApi`](crate::add_offsets_to_transaction::AddOffsetsToTransactionApi).
     AddOffsetsToTransaction,
+
+    /// The API key for the [`EndTransactionApi`](crate::end_transaction::EndTransactionApi).
     EndTransaction,
+
     WriteTransactionMarkers,
+
+    /// The API key for the [`TransactionOffsetCommitApi`](crate::transaction_offset_commit::TransactionOffsetCommitApi).
     TransactionOffsetCommit,
+
+    /// The API key for the [`DescribeAclsApi`](crate::describe_acls::DescribeAclsApi).
     DescribeAcls,
+
+    /// The API key for the [`CreateAclsApi`](crate::create_acls::CreateAclsApi).
     CreateAcls,
+
+    /// The API key for the [`DeleteAclsApi`](crate::delete_acls::DeleteAclsApi).
     DeleteAcls,
+
+    /// The API key for the [`DescribeConfigsApi`](crate::describe_configs::DescribeConfigsApi).
     DescribeConfigs,
+
+    /// The API key for the [`AlterConfigsApi`](crate::alter_configs::AlterConfigsApi).
     AlterConfigs,
+
+    /// The API key for the [`AlterReplicaLogDirsApi`](crate::alter_replica_log_dirs::AlterReplicaLogDirsApi).
     AlterReplicaLogDirs,
+
+    /// The API key for the [`DescribeLogDirsApi`](crate::describe_log_dirs::DescribeLogDirsApi).
     DescribeLogDirs,
+
+    /// The API key for the [`SaslAuthenticateApi`](crate::sasl_authenticate::SaslAuthenticateApi).
     SaslAuthenticate,
+
     CreatePartitions,
+
+    /// The API key for the [`CreateDelegationTokenApi`](crate::create_delegation_token::CreateDelegationTokenApi).
     CreateDelegationToken,
+
+    /// The API key for the [`RenewDelegationTokenApi`](crate::renew_delegation_token::RenewDelegationTokenApi).
     RenewDelegationToken,
+
+    /// The API key for the [`ExpireDelegationTokenApi`](crate::expire_delegation_token::ExpireDelegationTokenApi).
     ExpireDelegationToken,
+
+    /// The API key for the [`DescribeDelegationTokenApi`](crate::describe_delegation_token::DescribeDelegationTokenApi).
     DescribeDelegationToken,
+
+    /// The API key for the [`DeleteGroupsApi`](crate::delete_groups::DeleteGroupsApi).
     DeleteGroups,
+
+    /// The API key for the [`ElectLeadersApi`](crate::elect_leaders::ElectLeadersApi).
     ElectLeaders,
+
+    /// The API key for the [`IncrementalAlterConfigsApi`](crate::incremental_alter_configs::IncrementalAlterConfigsApi).
     IncrementalAlterConfigs,
+
+    /// The API key for the [`AlterPartitionReassignmentsApi`](crate::alter_partition_reassignments::AlterPartitionReassignmentsApi).
     AlterPartitionReassignments,
+
+    /// The API key for the [`ListPartitionReassignmentsApi`](crate::list_partition_reassignments::ListPartitionReassignmentsApi).
     ListPartitionReassignments,
+
     OffsetDelete,
+
+    /// The API key for the [`DescribeClientQuotasApi`](crate::describe_client_quotas::DescribeClientQuotasApi).
     DescribeClientQuotas,
+
+    /// The API key for the [`AlterClientQuotasApi`](crate::alter_client_quotas::AlterClientQuotasApi).
     AlterClientQuotas,
+
+    /// The API key for the [`DescribeUserScramCredentialsApi`](crate::describe_user_scram_credentials::DescribeUserScramCredentialsApi).
     DescribeUserScramCredentials,
+
+    /// The API key for the [`AlterUserScramCredentialsApi`](crate::alter_user_scram_credentials::AlterUserScramCredentialsApi).
     AlterUserScramCredentials,
+
+    /// The API key for the [`VoteApi`](crate::vote::VoteApi).
+    Vote,
+
+    /// The API key for the [`BeginQuorumEpochApi`](crate::begin_quorum_epoch::BeginQuorumEpochApi).
+    BeginQuorumEpoch,
+
+    /// The API key for the [`EndQuorumEpochApi`](crate::end_quorum_epoch::EndQuorumEpochApi).
+    EndQuorumEpoch,
+
+    /// The API key for the [`DescribeQuorumApi`](crate::describe_quorum::DescribeQuorumApi).
     DescribeQuorum,
+
     AlterPartition,
+
+    /// The API key for the [`UpdateFeaturesApi`](crate::update_features::UpdateFeaturesApi).
     UpdateFeatures,
+
     Envelope,
+
+    /// The API key for the [`FetchSnapshotApi`](crate::fetch_snapshot::FetchSnapshotApi).
+    FetchSnapshot,
+
+    /// The API key for the [`DescribeClusterApi`](crate::describe_cluster::DescribeClusterApi).
     DescribeCluster,
+
+    /// The API key for the [`DescribeProducersApi`](crate::describe_producers::DescribeProducersApi).
     DescribeProducers,
+
     UnregisterBroker,
+
+    /// The API key for the [`DescribeTransactionsApi`](crate::describe_transactions::DescribeTransactionsApi).
     DescribeTransactions,
+
+    /// The API key for the [`ListTransactionsApi`](crate::list_transactions::ListTransactionsApi).
     ListTransactions,
+
     AllocateProducerIds,
+
+    /// The API key for the [`ConsumerGroupHeartbeatApi`](crate::consumer_group_heartbeat::ConsumerGroupHeartbeatApi).
     ConsumerGroupHeartbeat,
+
+    /// The API key for the [`ConsumerGroupDescribeApi`](crate::consumer_group_describe::ConsumerGroupDescribeApi).
     ConsumerGroupDescribe,
+
+    /// The API key for the [`GetTelemetrySubscriptionsApi`](crate::get_telemetry_subscriptions::GetTelemetrySubscriptionsApi).
     GetTelemetrySubscriptions,
+
+    /// The API key for the [`PushTelemetryApi`](crate::push_telemetry::PushTelemetryApi).
     PushTelemetry,
+
+    /// The API key for the [`ListClientMetricsResourcesApi`](crate::list_client_metrics_resources::ListClientMetricsResourcesApi).
     ListClientMetricsResources,
 }
 
@@ -137,10 +245,14 @@ impl TryFrom<i16> for ApiKey {
             49 => Ok(Self::AlterClientQuotas),
             50 => Ok(Self::DescribeUserScramCredentials),
             51 => Ok(Self::AlterUserScramCredentials),
+            52 => Ok(Self::Vote),
+            53 => Ok(Self::BeginQuorumEpoch),
+            54 => Ok(Self::EndQuorumEpoch),
             55 => Ok(Self::DescribeQuorum),
             56 => Ok(Self::AlterPartition),
             57 => Ok(Self::UpdateFeatures),
             58 => Ok(Self::Envelope),
+            59 => Ok(Self::FetchSnapshot),
             60 => Ok(Self::DescribeCluster),
             61 => Ok(Self::DescribeProducers),
             64 => Ok(Self::UnregisterBroker),
@@ -156,3 +268,81 @@ impl TryFrom<i16> for ApiKey {
         }
     }
 }
+
+impl From<ApiKey> for i16 {
+    fn from(value: ApiKey) -> Self {
+        match value {
+            ApiKey::Produce => 0,
+            ApiKey::Fetch => 1,
+            ApiKey::ListOffsets => 2,
+            ApiKey::Metadata => 3,
+            ApiKey::LeaderAndIsr => 4,
+            ApiKey::StopReplica => 5,
+            ApiKey::UpdateMetadata => 6,
+            ApiKey::ControlledShutdown => 7,
+            ApiKey::OffsetCommit => 8,
+            ApiKey::OffsetFetch => 9,
+            ApiKey::FindCoordinator => 10,
+            ApiKey::JoinGroup => 11,
+            ApiKey::Heartbeat => 12,
+            ApiKey::LeaveGroup => 13,
+            ApiKey::SyncGroup => 14,
+            ApiKey::DescribeGroups => 15,
+            ApiKey::ListGroups => 16,
+            ApiKey::SaslHandshake => 17,
+            ApiKey::ApiVersions => 18,
+            ApiKey::CreateTopics => 19,
+            ApiKey::DeleteTopics => 20,
+            ApiKey::DeleteRecords => 21,
+            ApiKey::InitProducerId => 22,
+            ApiKey::OffsetForLeaderEpoch => 23,
+            ApiKey::AddPartitionsToTransaction => 24,
+            ApiKey::AddOffsetsToTransaction => 25,
+            ApiKey::EndTransaction => 26,
+            ApiKey::WriteTransactionMarkers => 27,
+            ApiKey::TransactionOffsetCommit => 28,
+            ApiKey::DescribeAcls => 29,
+            ApiKey::CreateAcls => 30,
+            ApiKey::DeleteAcls => 31,
+            ApiKey::DescribeConfigs => 32,
+            ApiKey::AlterConfigs => 33,
+            ApiKey::AlterReplicaLogDirs => 34,
+            ApiKey::DescribeLogDirs => 35,
+            ApiKey::SaslAuthenticate => 36,
+            ApiKey::CreatePartitions => 37,
+            ApiKey::CreateDelegationToken => 38,
+            ApiKey::RenewDelegationToken => 39,
+            ApiKey::ExpireDelegationToken => 40,
+            ApiKey::DescribeDelegationToken => 41,
+            ApiKey::DeleteGroups => 42,
+            ApiKey::ElectLeaders => 43,
+            ApiKey::IncrementalAlterConfigs => 44,
+            ApiKey::AlterPartitionReassignments => 45,
+            ApiKey::ListPartitionReassignments => 46,
+            ApiKey::OffsetDelete => 47,
+            ApiKey::DescribeClientQuotas => 48,
+            ApiKey::AlterClientQuotas => 49,
+            ApiKey::DescribeUserScramCredentials => 50,
+            ApiKey::AlterUserScramCredentials => 51,
+            ApiKey::Vote => 52,
+            ApiKey::BeginQuorumEpoch => 53,
+            ApiKey::EndQuorumEpoch => 54,
+            ApiKey::DescribeQuorum => 55,
+            ApiKey::AlterPartition => 56,
+            ApiKey::UpdateFeatures => 57,
+            ApiKey::Envelope => 58,
+            ApiKey::FetchSnapshot => 59,
+            ApiKey::DescribeCluster => 60,
+            ApiKey::DescribeProducers => 61,
+            ApiKey::UnregisterBroker => 64,
+            ApiKey::DescribeTransactions => 65,
+            ApiKey::ListTransactions => 66,
+            ApiKey::AllocateProducerIds => 67,
+            ApiKey::ConsumerGroupHeartbeat => 68,
+            ApiKey::ConsumerGroupDescribe => 69,
+            ApiKey::GetTelemetrySubscriptions => 71,
+            ApiKey::PushTelemetry => 72,
+            ApiKey::ListClientMetricsResources => 74,
+        }
+    }
+}
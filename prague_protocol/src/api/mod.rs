@@ -3,7 +3,7 @@ use self::prelude::*;
 pub mod api_key;
 
 pub mod prelude {
-    pub use super::{api_key::ApiKey, Api};
+    pub use super::{api_key::ApiKey, Api, VersionedApi};
 }
 
 pub trait Api {
@@ -12,3 +12,39 @@ pub trait Api {
     type Request;
     type Response;
 }
+
+/// Describes the versions of an [Api] whose messages can be encoded and decoded.
+pub trait VersionedApi: Api {
+    /// The oldest supported version.
+    const MIN_VERSION: i16;
+
+    /// The newest supported version.
+    const MAX_VERSION: i16;
+
+    /// The first version which uses the flexible encoding, i.e. compact strings and arrays with
+    /// tagged fields, or None if no supported version does.
+    const FLEXIBLE_VERSION: Option<i16>;
+
+    /// Returns whether the given version uses the flexible encoding.
+    fn is_flexible(version: i16) -> bool {
+        Self::FLEXIBLE_VERSION.is_some_and(|flexible| version >= flexible)
+    }
+
+    /// Returns the version of the [request header](crate::headers::Request) sent with the given
+    /// version of this API.
+    fn request_header_version(version: i16) -> i16 {
+        match Self::is_flexible(version) {
+            true => 2,
+            false => 1,
+        }
+    }
+
+    /// Returns the version of the [response header](crate::headers::Response) received with the
+    /// given version of this API.
+    fn response_header_version(version: i16) -> i16 {
+        match Self::is_flexible(version) {
+            true => 1,
+            false => 0,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{request::ApiVersionsRequest, response::ApiVersionsResponse, ApiVersionsApi};
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct ApiVersionsApi;
+
+impl Api for ApiVersionsApi {
+    const KEY: ApiKey = ApiKey::ApiVersions;
+
+    type Request = ApiVersionsRequest;
+    type Response = ApiVersionsResponse;
+}
+
+impl VersionedApi for ApiVersionsApi {
+    const MIN_VERSION: i16 = 0;
+    const MAX_VERSION: i16 = 4;
+    const FLEXIBLE_VERSION: Option<i16> = Some(3);
+
+    /// ApiVersions responses always use version 0 of the response header, so that clients can
+    /// read them before knowing which versions the broker supports.
+    fn response_header_version(_version: i16) -> i16 {
+        0
+    }
+}
@@ -0,0 +1,46 @@
+//! Includes definitions for requests to [ApiVersionsApi](super::ApiVersionsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ApiVersionsRequest.json>
+
+use crate::{
+    codec::{Encode, Error, Writer},
+    types::prelude::*,
+};
+
+/// A request for the versions of each API the broker supports.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ApiVersionsRequest.json>
+pub struct ApiVersionsRequest {
+    /// The name of the client software.
+    ///
+    /// Versions: 3+
+    pub client_software_name: String,
+
+    /// The version of the client software.
+    ///
+    /// Versions: 3+
+    pub client_software_version: String,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Encode for ApiVersionsRequest {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), Error> {
+        if version >= 3 {
+            writer.string(&self.client_software_name);
+            writer.string(&self.client_software_version);
+            writer.tagged_fields(&self.tagged_fields, vec![]);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,237 @@
+//! Includes definitions for responses from [ApiVersionsApi](super::ApiVersionsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ApiVersionsResponse.json>
+
+use crate::{
+    api::prelude::*,
+    codec::{Decode, Error, Reader},
+    error_code::ErrorCode,
+    types::prelude::*,
+};
+
+/// A response message associated with [ApiVersionsRequest](super::request::ApiVersionsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ApiVersionsResponse.json>
+pub struct ApiVersionsResponse {
+    /// The top-level error code, or None if there was no error.
+    ///
+    /// If the broker does not support the version of the request, this is
+    /// [UnsupportedVersion](ErrorCode::UnsupportedVersion), the response is encoded with version
+    /// 0, and [api_keys](Self::api_keys) contains the versions of ApiVersions the broker supports.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The versions of each API the broker supports.
+    ///
+    /// Versions: 0+
+    pub api_keys: Vec<ApiVersion>,
+
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 1+
+    pub throttle_time: Option<Duration>,
+
+    /// The features the broker supports.
+    ///
+    /// Versions: 3+
+    pub supported_features: Vec<SupportedFeatureKey>,
+
+    /// The epoch of the finalized features, or None if not known.
+    ///
+    /// Versions: 3+
+    pub finalized_features_epoch: Option<i64>,
+
+    /// The features which are finalized across the cluster.
+    ///
+    /// Versions: 3+
+    pub finalized_features: Vec<FinalizedFeatureKey>,
+
+    /// Whether the controller is ready for a ZooKeeper migration.
+    ///
+    /// Versions: 3+
+    pub zk_migration_ready: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl ApiVersionsResponse {
+    /// Returns the range of versions the broker supports for the given API, or None if it does
+    /// not support it.
+    pub fn versions(&self, api_key: ApiKey) -> Option<(i16, i16)> {
+        let api_key = i16::from(api_key);
+
+        self.api_keys
+            .iter()
+            .find(|api| api.api_key == api_key)
+            .map(|api| (api.min_version, api.max_version))
+    }
+}
+
+impl Decode for ApiVersionsResponse {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        // Brokers which do not support the request's version respond with version 0.
+        let error_code = Reader::new(reader.remaining(), false).error_code()?;
+        if version > 0 && error_code == Some(ErrorCode::UnsupportedVersion) {
+            let remaining = reader.take(reader.remaining().len())?;
+            return Self::decode(&mut Reader::new(remaining, false), 0);
+        }
+
+        let error_code = reader.error_code()?;
+        let api_keys = reader.array("api_keys", ApiVersion::read)?;
+        let throttle_time = match version >= 1 {
+            true => reader.throttle_time()?,
+            false => None,
+        };
+        let mut tagged_fields = reader.tagged_fields()?;
+
+        Ok(Self {
+            error_code,
+            api_keys,
+            throttle_time,
+            supported_features: Reader::take_tagged(&mut tagged_fields, 0, |reader| {
+                reader.array("supported_features", SupportedFeatureKey::read)
+            })?
+            .unwrap_or_default(),
+            finalized_features_epoch: Reader::take_tagged(&mut tagged_fields, 1, |reader| {
+                reader.i64()
+            })?
+            .filter(|epoch| *epoch >= 0),
+            finalized_features: Reader::take_tagged(&mut tagged_fields, 2, |reader| {
+                reader.array("finalized_features", FinalizedFeatureKey::read)
+            })?
+            .unwrap_or_default(),
+            zk_migration_ready: Reader::take_tagged(&mut tagged_fields, 3, |reader| reader.bool())?
+                .unwrap_or_default(),
+            tagged_fields,
+        })
+    }
+}
+
+/// The versions of a single API the broker supports.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ApiVersionsResponse.json>
+pub struct ApiVersion {
+    /// The API key. This is kept as an integer, since brokers may support APIs which this crate
+    /// does not know about.
+    ///
+    /// Versions: 0+
+    pub api_key: i16,
+
+    /// The minimum supported version.
+    ///
+    /// Versions: 0+
+    pub min_version: i16,
+
+    /// The maximum supported version.
+    ///
+    /// Versions: 0+
+    pub max_version: i16,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl ApiVersion {
+    fn read(reader: &mut Reader) -> Result<Self, Error> {
+        Ok(Self {
+            api_key: reader.i16()?,
+            min_version: reader.i16()?,
+            max_version: reader.i16()?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+/// The versions of a single feature the broker supports.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ApiVersionsResponse.json>
+pub struct SupportedFeatureKey {
+    /// The name of the feature.
+    ///
+    /// Versions: 3+
+    pub name: String,
+
+    /// The minimum supported version level.
+    ///
+    /// Versions: 3+
+    pub min_version: i16,
+
+    /// The maximum supported version level.
+    ///
+    /// Versions: 3+
+    pub max_version: i16,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl SupportedFeatureKey {
+    fn read(reader: &mut Reader) -> Result<Self, Error> {
+        Ok(Self {
+            name: reader.string("name")?,
+            min_version: reader.i16()?,
+            max_version: reader.i16()?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+/// The finalized version levels of a single feature.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ApiVersionsResponse.json>
+pub struct FinalizedFeatureKey {
+    /// The name of the feature.
+    ///
+    /// Versions: 3+
+    pub name: String,
+
+    /// The finalized maximum version level.
+    ///
+    /// Versions: 3+
+    pub max_version_level: i16,
+
+    /// The finalized minimum version level.
+    ///
+    /// Versions: 3+
+    pub min_version_level: i16,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl FinalizedFeatureKey {
+    fn read(reader: &mut Reader) -> Result<Self, Error> {
+        Ok(Self {
+            name: reader.string("name")?,
+            max_version_level: reader.i16()?,
+            min_version_level: reader.i16()?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::BeginQuorumEpochRequest, response::BeginQuorumEpochResponse, BeginQuorumEpochApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct BeginQuorumEpochApi;
+
+impl Api for BeginQuorumEpochApi {
+    const KEY: ApiKey = ApiKey::BeginQuorumEpoch;
+
+    type Request = BeginQuorumEpochRequest;
+    type Response = BeginQuorumEpochResponse;
+}
@@ -0,0 +1,99 @@
+//! Includes definitions for requests to [BeginQuorumEpochApi](super::BeginQuorumEpochApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/BeginQuorumEpochRequest.json>
+
+use crate::{raft::Listener, types::prelude::*};
+
+/// A request from a newly elected leader announcing its leadership to the voters.
+///
+/// This is only sent between controllers. See [raft](crate::raft) for details.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/BeginQuorumEpochRequest.json>
+pub struct BeginQuorumEpochRequest {
+    /// The ID of the cluster, or None if not known.
+    ///
+    /// Versions: 0+
+    pub cluster_id: Option<String>,
+
+    /// The ID of the voter receiving the request, or None if not sent.
+    ///
+    /// Versions: 1+
+    pub voter_id: Option<BrokerId>,
+
+    /// The topics whose leadership is changing.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<BeginQuorumEpochTopic>,
+
+    /// The endpoints of the leader.
+    ///
+    /// Versions: 1+
+    pub leader_endpoints: Vec<Listener>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A topic whose leadership is changing.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/BeginQuorumEpochRequest.json>
+pub struct BeginQuorumEpochTopic {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub topic_name: String,
+
+    /// The partitions whose leadership is changing.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<BeginQuorumEpochPartition>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A partition whose leadership is changing.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/BeginQuorumEpochRequest.json>
+pub struct BeginQuorumEpochPartition {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub partition_index: PartitionIndex,
+
+    /// The directory ID of the voter receiving the request.
+    ///
+    /// Versions: 1+
+    pub voter_directory_id: Uuid,
+
+    /// The ID of the newly elected leader.
+    ///
+    /// Versions: 0+
+    pub leader_id: BrokerId,
+
+    /// The epoch of the newly elected leader.
+    ///
+    /// Versions: 0+
+    pub leader_epoch: Epoch,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
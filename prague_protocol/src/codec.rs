@@ -0,0 +1,601 @@
+//! Includes the primitives for encoding and decoding messages, and for framing them with their
+//! headers.
+//!
+//! Messages implement [Encode] and [Decode] for each supported version of their API. Whether
+//! strings, arrays and bytes use the compact encoding is decided by the [Reader] or [Writer]
+//! itself, since it is the same for every field of a message.
+//!
+//! See: <https://kafka.apache.org/protocol.html#protocol_types>
+
+use crate::{api::prelude::*, headers, types::prelude::*};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Unexpected end of message")]
+    UnexpectedEof,
+
+    #[error("Invalid length `{0}`")]
+    InvalidLength(i64),
+
+    #[error("Variable-length integer is too long")]
+    VarIntTooLong,
+
+    #[error("Message contains invalid UTF-8")]
+    InvalidUtf8(#[from] std::string::FromUtf8Error),
+
+    #[error("Field `{0}` cannot be null")]
+    NullField(&'static str),
+
+    #[error("Version `{version}` of `{api_key:?}` is not supported")]
+    UnsupportedVersion { api_key: ApiKey, version: i16 },
+
+    #[error("Field `{field}` cannot be encoded with version `{version}`")]
+    UnsupportedField { field: &'static str, version: i16 },
+
+    #[error(transparent)]
+    ApiKey(#[from] crate::api::api_key::Error),
+
+    #[error(transparent)]
+    ErrorCode(#[from] crate::error_code::Error),
+
+    #[error(transparent)]
+    Metadata(#[from] crate::metadata::request::Error),
+
+    #[error(transparent)]
+    Fetch(#[from] crate::fetch::request::Error),
+
+    #[error(transparent)]
+    Records(#[from] crate::records::Error),
+
+    #[error("Compression `{0:?}` is not supported")]
+    UnsupportedCompression(crate::records::RecordBatchCompression),
+}
+
+/// A message which can be encoded with a given version.
+pub trait Encode {
+    /// Encodes this message with the given version.
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), Error>;
+}
+
+/// A message which can be decoded with a given version.
+pub trait Decode: Sized {
+    /// Decodes a message with the given version.
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error>;
+}
+
+/// Returns an error unless the given version of the API is supported.
+pub fn check_version<A: VersionedApi>(version: i16) -> Result<(), Error> {
+    match (A::MIN_VERSION..=A::MAX_VERSION).contains(&version) {
+        true => Ok(()),
+        false => Err(Error::UnsupportedVersion {
+            api_key: A::KEY,
+            version,
+        }),
+    }
+}
+
+/// Encodes a request and its header as a size-delimited frame, ready to be written to a broker.
+pub fn encode_request<A>(header: &headers::Request, request: &A::Request) -> Result<Vec<u8>, Error>
+where
+    A: VersionedApi,
+    A::Request: Encode,
+{
+    let version = header.api_version;
+    check_version::<A>(version)?;
+
+    let mut writer = Writer::new(A::is_flexible(version));
+    writer.i32(0);
+    header.encode(&mut writer, A::request_header_version(version))?;
+    request.encode(&mut writer, version)?;
+
+    let mut frame = writer.into_inner();
+    let size =
+        i32::try_from(frame.len() - 4).map_err(|_| Error::InvalidLength(frame.len() as i64))?;
+    frame[..4].copy_from_slice(&size.to_be_bytes());
+    Ok(frame)
+}
+
+/// Decodes a response and its header from a frame read from a broker, without its size.
+pub fn decode_response<A>(
+    frame: &[u8],
+    version: i16,
+) -> Result<(headers::Response, A::Response), Error>
+where
+    A: VersionedApi,
+    A::Response: Decode,
+{
+    check_version::<A>(version)?;
+
+    let mut reader = Reader::new(frame, A::is_flexible(version));
+    let header = headers::Response::decode(&mut reader, A::response_header_version(version))?;
+    let response = A::Response::decode(&mut reader, version)?;
+    Ok((header, response))
+}
+
+/// Returns the correlation ID of a response frame, without its size. Every version of the
+/// response header starts with the correlation ID, so this can be read before knowing which
+/// request the response is for.
+pub fn peek_correlation_id(frame: &[u8]) -> Result<i32, Error> {
+    Reader::new(frame, false).i32()
+}
+
+/// Reads primitive types from a message.
+pub struct Reader<'a> {
+    buf: &'a [u8],
+    flexible: bool,
+}
+
+impl<'a> Reader<'a> {
+    /// Creates a reader over the given bytes, which uses compact encodings if `flexible`.
+    pub fn new(buf: &'a [u8], flexible: bool) -> Self {
+        Self { buf, flexible }
+    }
+
+    /// Returns whether this reader uses compact encodings.
+    pub fn is_flexible(&self) -> bool {
+        self.flexible
+    }
+
+    /// Returns the bytes which have not been read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.buf
+    }
+
+    /// Reads the given number of bytes.
+    pub fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.buf.len() < len {
+            return Err(Error::UnexpectedEof);
+        }
+
+        let (head, tail) = self.buf.split_at(len);
+        self.buf = tail;
+        Ok(head)
+    }
+
+    fn array_of<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        Ok(self.take(N)?.try_into().expect("took exactly N bytes"))
+    }
+
+    pub fn i8(&mut self) -> Result<i8, Error> {
+        Ok(i8::from_be_bytes(self.array_of()?))
+    }
+
+    pub fn i16(&mut self) -> Result<i16, Error> {
+        Ok(i16::from_be_bytes(self.array_of()?))
+    }
+
+    pub fn i32(&mut self) -> Result<i32, Error> {
+        Ok(i32::from_be_bytes(self.array_of()?))
+    }
+
+    pub fn i64(&mut self) -> Result<i64, Error> {
+        Ok(i64::from_be_bytes(self.array_of()?))
+    }
+
+    pub fn u16(&mut self) -> Result<u16, Error> {
+        Ok(u16::from_be_bytes(self.array_of()?))
+    }
+
+    pub fn u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_be_bytes(self.array_of()?))
+    }
+
+    pub fn f64(&mut self) -> Result<f64, Error> {
+        Ok(f64::from_be_bytes(self.array_of()?))
+    }
+
+    pub fn bool(&mut self) -> Result<bool, Error> {
+        Ok(self.i8()? != 0)
+    }
+
+    pub fn uuid(&mut self) -> Result<Uuid, Error> {
+        Ok(Uuid(u128::from_be_bytes(self.array_of()?)))
+    }
+
+    /// Reads an unsigned variable-length integer.
+    pub fn unsigned_varint(&mut self) -> Result<u32, Error> {
+        let mut value = 0u32;
+
+        for shift in (0..35).step_by(7) {
+            let byte = self.array_of::<1>()?[0];
+            value |= u32::from(byte & 0x7f) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(Error::VarIntTooLong)
+    }
+
+    /// Reads a zigzag-encoded variable-length integer.
+    pub fn varint(&mut self) -> Result<i32, Error> {
+        let value = self.unsigned_varint()?;
+        Ok((value >> 1) as i32 ^ -((value & 1) as i32))
+    }
+
+    /// Reads a zigzag-encoded variable-length long.
+    pub fn varlong(&mut self) -> Result<i64, Error> {
+        let mut value = 0u64;
+
+        for shift in (0..70).step_by(7) {
+            let byte = self.array_of::<1>()?[0];
+            value |= u64::from(byte & 0x7f) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+            }
+        }
+
+        Err(Error::VarIntTooLong)
+    }
+
+    /// Reads an error code, which is None if there was no error.
+    pub fn error_code(&mut self) -> Result<Option<crate::error_code::ErrorCode>, Error> {
+        Ok(crate::error_code::ErrorCode::parse(self.i16()?.into())?)
+    }
+
+    /// Reads a throttle time, which is None if the request was not throttled.
+    pub fn throttle_time(&mut self) -> Result<Option<Duration>, Error> {
+        Ok(Some(Duration(self.i32()?)).filter(|duration| duration.0 > 0))
+    }
+
+    /// Reads the length of a string, array or byte sequence, which is None if it is null.
+    fn length(&mut self, compact: bool, short: bool) -> Result<Option<usize>, Error> {
+        let len = match (compact, short) {
+            (true, _) => i64::from(self.unsigned_varint()?) - 1,
+            (false, true) => self.i16()?.into(),
+            (false, false) => self.i32()?.into(),
+        };
+
+        match len {
+            -1 => Ok(None),
+            len if len < 0 => Err(Error::InvalidLength(len)),
+            len => Ok(Some(len as usize)),
+        }
+    }
+
+    pub fn nullable_string(&mut self) -> Result<Option<String>, Error> {
+        match self.length(self.flexible, true)? {
+            Some(len) => Ok(Some(String::from_utf8(self.take(len)?.to_vec())?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn string(&mut self, field: &'static str) -> Result<String, Error> {
+        self.nullable_string()?.ok_or(Error::NullField(field))
+    }
+
+    /// Reads a nullable string which always uses the non-compact encoding, as in request headers.
+    pub fn legacy_nullable_string(&mut self) -> Result<Option<String>, Error> {
+        match self.length(false, true)? {
+            Some(len) => Ok(Some(String::from_utf8(self.take(len)?.to_vec())?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn nullable_bytes(&mut self) -> Result<Option<Vec<u8>>, Error> {
+        match self.length(self.flexible, false)? {
+            Some(len) => Ok(Some(self.take(len)?.to_vec())),
+            None => Ok(None),
+        }
+    }
+
+    pub fn bytes(&mut self, field: &'static str) -> Result<Vec<u8>, Error> {
+        self.nullable_bytes()?.ok_or(Error::NullField(field))
+    }
+
+    pub fn nullable_array<T>(
+        &mut self,
+        mut read: impl FnMut(&mut Self) -> Result<T, Error>,
+    ) -> Result<Option<Vec<T>>, Error> {
+        match self.length(self.flexible, false)? {
+            Some(len) => (0..len)
+                .map(|_| read(self))
+                .collect::<Result<_, _>>()
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub fn array<T>(
+        &mut self,
+        field: &'static str,
+        read: impl FnMut(&mut Self) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        self.nullable_array(read)?.ok_or(Error::NullField(field))
+    }
+
+    /// Reads tagged fields if this reader is flexible, or returns none otherwise. Fields which
+    /// the message understands should be removed with [take_tagged](Self::take_tagged).
+    pub fn tagged_fields(&mut self) -> Result<TaggedFields, Error> {
+        let mut tagged_fields = TaggedFields::default();
+
+        if !self.flexible {
+            return Ok(tagged_fields);
+        }
+
+        for _ in 0..self.unsigned_varint()? {
+            let tag = self.unsigned_varint()? as i32;
+            let len = self.unsigned_varint()? as usize;
+            let value = self.take(len)?.to_vec();
+            tagged_fields.fields.insert(tag, Field(value));
+        }
+
+        Ok(tagged_fields)
+    }
+
+    /// Removes a tagged field and decodes it, returning None if it was not sent.
+    pub fn take_tagged<T>(
+        tagged_fields: &mut TaggedFields,
+        tag: i32,
+        read: impl FnOnce(&mut Reader) -> Result<T, Error>,
+    ) -> Result<Option<T>, Error> {
+        match tagged_fields.fields.remove(&tag) {
+            Some(Field(value)) => read(&mut Reader::new(&value, true)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Writes primitive types to a message.
+pub struct Writer {
+    buf: Vec<u8>,
+    flexible: bool,
+}
+
+impl Writer {
+    /// Creates an empty writer, which uses compact encodings if `flexible`.
+    pub fn new(flexible: bool) -> Self {
+        Self {
+            buf: Vec::new(),
+            flexible,
+        }
+    }
+
+    /// Returns whether this writer uses compact encodings.
+    pub fn is_flexible(&self) -> bool {
+        self.flexible
+    }
+
+    /// Returns the bytes written so far.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Returns the number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns whether nothing has been written yet.
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Writes raw bytes.
+    pub fn put(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Overwrites previously written bytes at the given position.
+    pub fn put_at(&mut self, position: usize, bytes: &[u8]) {
+        self.buf[position..position + bytes.len()].copy_from_slice(bytes);
+    }
+
+    pub fn i8(&mut self, value: i8) {
+        self.put(&value.to_be_bytes());
+    }
+
+    pub fn i16(&mut self, value: i16) {
+        self.put(&value.to_be_bytes());
+    }
+
+    pub fn i32(&mut self, value: i32) {
+        self.put(&value.to_be_bytes());
+    }
+
+    pub fn i64(&mut self, value: i64) {
+        self.put(&value.to_be_bytes());
+    }
+
+    pub fn u16(&mut self, value: u16) {
+        self.put(&value.to_be_bytes());
+    }
+
+    pub fn u32(&mut self, value: u32) {
+        self.put(&value.to_be_bytes());
+    }
+
+    pub fn f64(&mut self, value: f64) {
+        self.put(&value.to_be_bytes());
+    }
+
+    pub fn bool(&mut self, value: bool) {
+        self.i8(value.into());
+    }
+
+    pub fn uuid(&mut self, value: Uuid) {
+        self.put(&value.0.to_be_bytes());
+    }
+
+    /// Writes an unsigned variable-length integer.
+    pub fn unsigned_varint(&mut self, mut value: u32) {
+        while value >= 0x80 {
+            self.buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+
+    /// Writes a zigzag-encoded variable-length integer.
+    pub fn varint(&mut self, value: i32) {
+        self.unsigned_varint(((value << 1) ^ (value >> 31)) as u32);
+    }
+
+    /// Writes a zigzag-encoded variable-length long.
+    pub fn varlong(&mut self, value: i64) {
+        let mut value = ((value << 1) ^ (value >> 63)) as u64;
+        while value >= 0x80 {
+            self.buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+
+    /// Writes the length of a string, array or byte sequence, or None if it is null.
+    fn length(&mut self, compact: bool, short: bool, len: Option<usize>) {
+        match (compact, short, len) {
+            (true, _, Some(len)) => self.unsigned_varint(len as u32 + 1),
+            (true, _, None) => self.unsigned_varint(0),
+            (false, true, Some(len)) => self.i16(len as i16),
+            (false, true, None) => self.i16(-1),
+            (false, false, Some(len)) => self.i32(len as i32),
+            (false, false, None) => self.i32(-1),
+        }
+    }
+
+    pub fn string(&mut self, value: &str) {
+        self.length(self.flexible, true, Some(value.len()));
+        self.put(value.as_bytes());
+    }
+
+    pub fn nullable_string(&mut self, value: Option<&str>) {
+        match value {
+            Some(value) => self.string(value),
+            None => self.length(self.flexible, true, None),
+        }
+    }
+
+    /// Writes a nullable string which always uses the non-compact encoding, as in request
+    /// headers.
+    pub fn legacy_nullable_string(&mut self, value: Option<&str>) {
+        self.length(false, true, value.map(str::len));
+        self.put(value.unwrap_or_default().as_bytes());
+    }
+
+    pub fn bytes(&mut self, value: &[u8]) {
+        self.length(self.flexible, false, Some(value.len()));
+        self.put(value);
+    }
+
+    pub fn nullable_bytes(&mut self, value: Option<&[u8]>) {
+        match value {
+            Some(value) => self.bytes(value),
+            None => self.length(self.flexible, false, None),
+        }
+    }
+
+    pub fn array<T>(
+        &mut self,
+        values: &[T],
+        mut write: impl FnMut(&mut Self, &T) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        self.length(self.flexible, false, Some(values.len()));
+        values.iter().try_for_each(|value| write(self, value))
+    }
+
+    pub fn nullable_array<T>(
+        &mut self,
+        values: Option<&[T]>,
+        write: impl FnMut(&mut Self, &T) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        match values {
+            Some(values) => self.array(values, write),
+            None => {
+                self.length(self.flexible, false, None);
+                Ok(())
+            }
+        }
+    }
+
+    /// Writes tagged fields if this writer is flexible, along with the fields the message
+    /// understands, which are given as their tags and encoded values.
+    pub fn tagged_fields(&mut self, tagged_fields: &TaggedFields, known: Vec<(i32, Vec<u8>)>) {
+        if !self.flexible {
+            return;
+        }
+
+        let mut fields: Vec<_> = tagged_fields
+            .fields
+            .iter()
+            .map(|(tag, field)| (*tag, field.0.as_slice()))
+            .chain(known.iter().map(|(tag, value)| (*tag, value.as_slice())))
+            .collect();
+        fields.sort_by_key(|(tag, _)| *tag);
+
+        self.unsigned_varint(fields.len() as u32);
+        for (tag, value) in fields {
+            self.unsigned_varint(tag as u32);
+            self.unsigned_varint(value.len() as u32);
+            self.put(value);
+        }
+    }
+
+    /// Encodes a tagged field with the given writer function.
+    pub fn tagged(tag: i32, write: impl FnOnce(&mut Writer)) -> (i32, Vec<u8>) {
+        let mut writer = Writer::new(true);
+        write(&mut writer);
+        (tag, writer.into_inner())
+    }
+}
+
+impl Encode for headers::Request {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), Error> {
+        writer.i16(self.api_key.into());
+        writer.i16(self.api_version);
+        writer.i32(self.correlation_id);
+
+        if version >= 1 {
+            writer.legacy_nullable_string(self.client_id.as_deref());
+        }
+
+        if version >= 2 {
+            writer.tagged_fields(&self.tagged_fields, vec![]);
+        }
+
+        Ok(())
+    }
+}
+
+impl Decode for headers::Request {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            api_key: reader.i16()?.try_into()?,
+            api_version: reader.i16()?,
+            correlation_id: reader.i32()?,
+            client_id: match version >= 1 {
+                true => reader.legacy_nullable_string()?,
+                false => None,
+            },
+            tagged_fields: match version >= 2 {
+                true => reader.tagged_fields()?,
+                false => TaggedFields::default(),
+            },
+        })
+    }
+}
+
+impl Encode for headers::Response {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), Error> {
+        writer.i32(self.correlation_id);
+
+        if version >= 1 {
+            writer.tagged_fields(&self.tagged_fields, vec![]);
+        }
+
+        Ok(())
+    }
+}
+
+impl Decode for headers::Response {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            correlation_id: reader.i32()?,
+            tagged_fields: match version >= 1 {
+                true => reader.tagged_fields()?,
+                false => TaggedFields::default(),
+            },
+        })
+    }
+}
@@ -0,0 +1,148 @@
+//! Includes types shared by the [DescribeConfigsApi](crate::describe_configs::DescribeConfigsApi),
+//! [AlterConfigsApi](crate::alter_configs::AlterConfigsApi) and
+//! [IncrementalAlterConfigsApi](crate::incremental_alter_configs::IncrementalAlterConfigsApi).
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Config resource type `{0}` is not valid")]
+    InvalidConfigResourceType(i8),
+
+    #[error("Config source `{0}` is not valid")]
+    InvalidConfigSource(i8),
+
+    #[error("Config type `{0}` is not valid")]
+    InvalidConfigType(i8),
+}
+
+/// A resource whose configuration can be described or altered.
+///
+/// Over the wire, this is transmitted as a `ResourceType` and `ResourceName` pair.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConfigResource {
+    /// The type of this resource.
+    pub resource_type: ConfigResourceType,
+
+    /// The name of this resource. For brokers, this is the broker ID, or an empty string for the
+    /// cluster-wide default.
+    pub name: String,
+}
+
+/// The kinds of resources that have configuration.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/java/org/apache/kafka/common/config/ConfigResource.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigResourceType {
+    Unknown,
+    Topic,
+    Broker,
+    BrokerLogger,
+    ClientMetrics,
+    Group,
+}
+
+impl TryFrom<i8> for ConfigResourceType {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Unknown),
+            2 => Ok(Self::Topic),
+            4 => Ok(Self::Broker),
+            8 => Ok(Self::BrokerLogger),
+            16 => Ok(Self::ClientMetrics),
+            32 => Ok(Self::Group),
+            code => Err(Error::InvalidConfigResourceType(code)),
+        }
+    }
+}
+
+/// Where the value of a config entry came from.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/java/org/apache/kafka/clients/admin/ConfigEntry.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigSource {
+    /// The source of this config is not known.
+    Unknown,
+
+    /// A dynamic topic config that is configured for a specific topic.
+    DynamicTopicConfig,
+
+    /// A dynamic broker config that is configured for a specific broker.
+    DynamicBrokerConfig,
+
+    /// A dynamic broker config that is configured as the default for all brokers in the cluster.
+    DynamicDefaultBrokerConfig,
+
+    /// A static broker config provided as a broker property at startup (e.g. from
+    /// `server.properties`).
+    StaticBrokerConfig,
+
+    /// A built-in default config for configs that have a default value.
+    DefaultConfig,
+
+    /// A broker logger config that is configured for a specific broker.
+    DynamicBrokerLoggerConfig,
+
+    /// A client metrics config that is configured for a specific client metrics resource.
+    ClientMetricsConfig,
+
+    /// A dynamic group config that is configured for a specific group.
+    GroupConfig,
+}
+
+impl TryFrom<i8> for ConfigSource {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            // Versions before 1 do not send a source, in which case it defaults to -1.
+            -1 | 0 => Ok(Self::Unknown),
+            1 => Ok(Self::DynamicTopicConfig),
+            2 => Ok(Self::DynamicBrokerConfig),
+            3 => Ok(Self::DynamicDefaultBrokerConfig),
+            4 => Ok(Self::StaticBrokerConfig),
+            5 => Ok(Self::DefaultConfig),
+            6 => Ok(Self::DynamicBrokerLoggerConfig),
+            7 => Ok(Self::ClientMetricsConfig),
+            8 => Ok(Self::GroupConfig),
+            code => Err(Error::InvalidConfigSource(code)),
+        }
+    }
+}
+
+/// The data type of a config entry.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/java/org/apache/kafka/clients/admin/ConfigEntry.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigType {
+    Unknown,
+    Boolean,
+    String,
+    Int,
+    Short,
+    Long,
+    Double,
+    List,
+    Class,
+    Password,
+}
+
+impl TryFrom<i8> for ConfigType {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::Boolean),
+            2 => Ok(Self::String),
+            3 => Ok(Self::Int),
+            4 => Ok(Self::Short),
+            5 => Ok(Self::Long),
+            6 => Ok(Self::Double),
+            7 => Ok(Self::List),
+            8 => Ok(Self::Class),
+            9 => Ok(Self::Password),
+            code => Err(Error::InvalidConfigType(code)),
+        }
+    }
+}
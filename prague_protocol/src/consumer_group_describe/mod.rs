@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::ConsumerGroupDescribeRequest, response::ConsumerGroupDescribeResponse,
+        ConsumerGroupDescribeApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct ConsumerGroupDescribeApi;
+
+impl Api for ConsumerGroupDescribeApi {
+    const KEY: ApiKey = ApiKey::ConsumerGroupDescribe;
+
+    type Request = ConsumerGroupDescribeRequest;
+    type Response = ConsumerGroupDescribeResponse;
+}
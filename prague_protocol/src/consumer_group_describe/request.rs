@@ -0,0 +1,32 @@
+//! Includes definitions for requests to [ConsumerGroupDescribeApi](super::ConsumerGroupDescribeApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupDescribeRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request to describe consumer groups that use the next-generation rebalance protocol
+/// (KIP-848).
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupDescribeRequest.json>
+pub struct ConsumerGroupDescribeRequest {
+    /// The IDs of the groups to describe.
+    ///
+    /// Versions: 0+
+    pub group_ids: Vec<String>,
+
+    /// Whether to include the operations the client is authorized to perform on each group.
+    ///
+    /// Versions: 0+
+    pub include_authorized_operations: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,204 @@
+//! Includes definitions for responses from
+//! [ConsumerGroupDescribeApi](super::ConsumerGroupDescribeApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupDescribeResponse.json>
+
+use std::collections::HashSet;
+
+use crate::{acl::AclOperation, error_code::ErrorCode, group::GroupState, types::prelude::*};
+
+/// A response message associated with
+/// [ConsumerGroupDescribeRequest](super::request::ConsumerGroupDescribeRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupDescribeResponse.json>
+pub struct ConsumerGroupDescribeResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// Each described group.
+    ///
+    /// Versions: 0+
+    pub groups: Vec<DescribedGroup>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes a single consumer group.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupDescribeResponse.json>
+pub struct DescribedGroup {
+    /// The describe error, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The describe error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The group ID string.
+    ///
+    /// Versions: 0+
+    pub group_id: String,
+
+    /// The group state.
+    ///
+    /// Versions: 0+
+    pub group_state: GroupState,
+
+    /// The group epoch, which is bumped whenever the group's membership or subscriptions change.
+    ///
+    /// Versions: 0+
+    pub group_epoch: Epoch,
+
+    /// The epoch of the group's target assignment.
+    ///
+    /// Versions: 0+
+    pub assignment_epoch: Epoch,
+
+    /// The name of the server-side assignor used by the group.
+    ///
+    /// Versions: 0+
+    pub assignor_name: String,
+
+    /// The group members.
+    ///
+    /// Versions: 0+
+    pub members: Vec<Member>,
+
+    /// The operations the client is authorized to perform on this group, or None if they were not
+    /// requested.
+    ///
+    /// Versions: 0+
+    pub authorized_operations: Option<HashSet<AclOperation>>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes a member of a consumer group.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupDescribeResponse.json>
+pub struct Member {
+    /// The member ID.
+    ///
+    /// Versions: 0+
+    pub member_id: String,
+
+    /// The member's instance ID, or None if this is not a static member.
+    ///
+    /// Versions: 0+
+    pub instance_id: Option<String>,
+
+    /// The member's rack ID, if it has been assigned to one.
+    ///
+    /// Versions: 0+
+    pub rack_id: Option<String>,
+
+    /// The current member epoch.
+    ///
+    /// Versions: 0+
+    pub member_epoch: Epoch,
+
+    /// The client ID.
+    ///
+    /// Versions: 0+
+    pub client_id: String,
+
+    /// The client host.
+    ///
+    /// Versions: 0+
+    pub client_host: String,
+
+    /// The subscribed topic names.
+    ///
+    /// Versions: 0+
+    pub subscribed_topic_names: Vec<String>,
+
+    /// The subscribed topic regular expression, if the member subscribed with one.
+    ///
+    /// Versions: 0+
+    pub subscribed_topic_regex: Option<String>,
+
+    /// The partitions currently owned by the member.
+    ///
+    /// Versions: 0+
+    pub assignment: Assignment,
+
+    /// The partitions the member will own once it has converged to the group's target assignment.
+    ///
+    /// Versions: 0+
+    pub target_assignment: Assignment,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the partitions assigned to a member.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupDescribeResponse.json>
+pub struct Assignment {
+    /// The assigned topic partitions.
+    ///
+    /// Versions: 0+
+    pub topic_partitions: Vec<TopicPartitions>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the assigned partitions of a single topic.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupDescribeResponse.json>
+pub struct TopicPartitions {
+    /// The topic ID.
+    ///
+    /// Versions: 0+
+    pub topic_id: Uuid,
+
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub topic_name: String,
+
+    /// The partitions.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<PartitionIndex>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
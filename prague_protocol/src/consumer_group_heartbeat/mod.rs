@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::ConsumerGroupHeartbeatRequest, response::ConsumerGroupHeartbeatResponse,
+        ConsumerGroupHeartbeatApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct ConsumerGroupHeartbeatApi;
+
+impl Api for ConsumerGroupHeartbeatApi {
+    const KEY: ApiKey = ApiKey::ConsumerGroupHeartbeat;
+
+    type Request = ConsumerGroupHeartbeatRequest;
+    type Response = ConsumerGroupHeartbeatResponse;
+}
@@ -0,0 +1,118 @@
+//! Includes definitions for requests to
+//! [ConsumerGroupHeartbeatApi](super::ConsumerGroupHeartbeatApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupHeartbeatRequest.json>
+
+use crate::types::prelude::*;
+
+/// A heartbeat sent by a member of a consumer group using the next-generation rebalance protocol
+/// (KIP-848).
+///
+/// The group ID, member ID and member epoch are sent with every heartbeat. All other fields are
+/// only sent in full when joining the group (or rejoining after being fenced), or after the
+/// previous heartbeat failed. Otherwise, they are None unless their value changed since the last
+/// heartbeat, so that a steady-state heartbeat carries no subscription or assignment data.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupHeartbeatRequest.json>
+pub struct ConsumerGroupHeartbeatRequest {
+    /// The group identifier.
+    ///
+    /// Versions: 0+
+    pub group_id: String,
+
+    /// The member ID. In version 0 this is empty when joining and is then generated by the
+    /// coordinator; from version 1 the client generates it before joining.
+    ///
+    /// Versions: 0+
+    pub member_id: String,
+
+    /// The current member epoch, [JOIN_GROUP_MEMBER_EPOCH](Self::JOIN_GROUP_MEMBER_EPOCH) to join
+    /// the group, or [LEAVE_GROUP_MEMBER_EPOCH](Self::LEAVE_GROUP_MEMBER_EPOCH) or
+    /// [LEAVE_GROUP_STATIC_MEMBER_EPOCH](Self::LEAVE_GROUP_STATIC_MEMBER_EPOCH) to leave it.
+    ///
+    /// Versions: 0+
+    pub member_epoch: Epoch,
+
+    /// The instance ID if this is a static member, or None if unchanged since the last heartbeat.
+    ///
+    /// Versions: 0+
+    pub instance_id: Option<String>,
+
+    /// The rack ID of the member, or None if unchanged since the last heartbeat.
+    ///
+    /// Versions: 0+
+    pub rack_id: Option<String>,
+
+    /// The maximum time in milliseconds that the coordinator will wait on the member to revoke its
+    /// partitions, or None if unchanged since the last heartbeat.
+    ///
+    /// Versions: 0+
+    pub rebalance_timeout: Option<Duration>,
+
+    /// The names of the subscribed topics, or None if unchanged since the last heartbeat.
+    ///
+    /// Versions: 0+
+    pub subscribed_topic_names: Option<Vec<String>>,
+
+    /// The regular expression that topic names are matched against, or None if unchanged since
+    /// the last heartbeat. An empty string removes a previously set expression.
+    ///
+    /// Versions: 1+
+    pub subscribed_topic_regex: Option<String>,
+
+    /// The name of the server-side assignor to use, or None if unchanged since the last heartbeat.
+    ///
+    /// Versions: 0+
+    pub server_assignor: Option<String>,
+
+    /// The partitions owned by the member, or None if unchanged since the last heartbeat.
+    ///
+    /// Versions: 0+
+    pub topic_partitions: Option<Vec<TopicPartitions>>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+impl ConsumerGroupHeartbeatRequest {
+    /// The member epoch sent when joining, or rejoining, the group.
+    pub const JOIN_GROUP_MEMBER_EPOCH: Epoch = Epoch(0);
+
+    /// The member epoch sent when leaving the group.
+    pub const LEAVE_GROUP_MEMBER_EPOCH: Epoch = Epoch(-1);
+
+    /// The member epoch sent when a static member leaves the group temporarily, keeping its
+    /// assignment until its session times out.
+    pub const LEAVE_GROUP_STATIC_MEMBER_EPOCH: Epoch = Epoch(-2);
+}
+
+/// The partitions of a single topic, identified by its ID.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupHeartbeatRequest.json>
+pub struct TopicPartitions {
+    /// The topic ID.
+    ///
+    /// Versions: 0+
+    pub topic_id: Uuid,
+
+    /// The partitions.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<PartitionIndex>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,101 @@
+//! Includes definitions for responses from
+//! [ConsumerGroupHeartbeatApi](super::ConsumerGroupHeartbeatApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupHeartbeatResponse.json>
+
+use crate::{error_code::ErrorCode, types::prelude::*};
+
+/// A response message associated with
+/// [ConsumerGroupHeartbeatRequest](super::request::ConsumerGroupHeartbeatRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupHeartbeatResponse.json>
+pub struct ConsumerGroupHeartbeatResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The top-level error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The top-level error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The member ID, if the coordinator generated one for the member.
+    ///
+    /// Versions: 0+
+    pub member_id: Option<String>,
+
+    /// The member epoch.
+    ///
+    /// Versions: 0+
+    pub member_epoch: Epoch,
+
+    /// The heartbeat interval in milliseconds.
+    ///
+    /// Versions: 0+
+    pub heartbeat_interval: Duration,
+
+    /// The member's new assignment, or None if it did not change since the last heartbeat. When
+    /// present, this is the member's full assignment rather than a delta.
+    ///
+    /// Versions: 0+
+    pub assignment: Option<Assignment>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the partitions assigned to a member.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupHeartbeatResponse.json>
+pub struct Assignment {
+    /// The partitions assigned to the member that can be used immediately.
+    ///
+    /// Versions: 0+
+    pub topic_partitions: Vec<TopicPartitions>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The assigned partitions of a single topic, identified by its ID.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerGroupHeartbeatResponse.json>
+pub struct TopicPartitions {
+    /// The topic ID.
+    ///
+    /// Versions: 0+
+    pub topic_id: Uuid,
+
+    /// The partitions.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<PartitionIndex>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
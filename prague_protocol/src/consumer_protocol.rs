@@ -0,0 +1,201 @@
+//! Includes definitions for the embedded consumer protocol, which classic consumer groups use to
+//! exchange subscriptions and assignments as opaque bytes in JoinGroup, SyncGroup and
+//! DescribeGroups.
+//!
+//! ## See also
+//!
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerProtocolSubscription.json>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerProtocolAssignment.json>
+
+use crate::types::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Unexpected end of consumer protocol data")]
+    UnexpectedEof,
+
+    #[error("Invalid length `{0}` in consumer protocol data")]
+    InvalidLength(i32),
+
+    #[error("Consumer protocol data contains invalid UTF-8")]
+    InvalidUtf8(#[from] std::string::FromUtf8Error),
+}
+
+/// The protocol type of classic groups that use the embedded consumer protocol.
+pub const PROTOCOL_TYPE: &str = "consumer";
+
+/// The topics a consumer is subscribed to, as sent in its group member metadata.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerProtocolSubscription.json>
+pub struct ConsumerProtocolSubscription {
+    /// The version of the consumer protocol this subscription was encoded with.
+    pub version: i16,
+
+    /// The subscribed topics.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<String>,
+
+    /// Data specific to the assignor in use, if any.
+    ///
+    /// Versions: 0+
+    pub user_data: Option<Vec<u8>>,
+
+    /// The partitions this consumer owned before the rebalance.
+    ///
+    /// Versions: 1+
+    pub owned_partitions: Vec<TopicPartitions>,
+
+    /// The generation this consumer last joined, or None if not known.
+    ///
+    /// Versions: 2+
+    pub generation_id: Option<i32>,
+
+    /// The rack of this consumer, if it has been assigned to one.
+    ///
+    /// Versions: 3+
+    pub rack_id: Option<String>,
+}
+
+impl ConsumerProtocolSubscription {
+    /// Decodes a subscription from group member metadata.
+    pub fn decode(data: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader(data);
+        let version = reader.i16()?;
+
+        Ok(Self {
+            version,
+            topics: reader.array(Reader::string)?,
+            user_data: reader.nullable_bytes()?,
+            owned_partitions: match version >= 1 {
+                true => reader.array(TopicPartitions::read)?,
+                false => vec![],
+            },
+            generation_id: match version >= 2 {
+                true => Some(reader.i32()?).filter(|id| *id >= 0),
+                false => None,
+            },
+            rack_id: match version >= 3 {
+                true => reader.nullable_string()?,
+                false => None,
+            },
+        })
+    }
+}
+
+/// The partitions assigned to a consumer, as sent in its group member assignment.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ConsumerProtocolAssignment.json>
+pub struct ConsumerProtocolAssignment {
+    /// The version of the consumer protocol this assignment was encoded with.
+    pub version: i16,
+
+    /// The assigned partitions.
+    ///
+    /// Versions: 0+
+    pub assigned_partitions: Vec<TopicPartitions>,
+
+    /// Data specific to the assignor in use, if any.
+    ///
+    /// Versions: 0+
+    pub user_data: Option<Vec<u8>>,
+}
+
+impl ConsumerProtocolAssignment {
+    /// Decodes an assignment from a group member assignment. An empty assignment, as sent to
+    /// members that have not been assigned anything yet, decodes to no partitions.
+    pub fn decode(data: &[u8]) -> Result<Self, Error> {
+        if data.is_empty() {
+            return Ok(Self {
+                version: 0,
+                assigned_partitions: vec![],
+                user_data: None,
+            });
+        }
+
+        let mut reader = Reader(data);
+
+        Ok(Self {
+            version: reader.i16()?,
+            assigned_partitions: reader.array(TopicPartitions::read)?,
+            user_data: reader.nullable_bytes()?,
+        })
+    }
+}
+
+/// A set of partitions within a topic.
+pub struct TopicPartitions {
+    /// The topic name.
+    pub topic: String,
+
+    /// The partition indices.
+    pub partitions: Vec<PartitionIndex>,
+}
+
+impl TopicPartitions {
+    fn read(reader: &mut Reader) -> Result<Self, Error> {
+        Ok(Self {
+            topic: reader.string()?,
+            partitions: reader.array(|reader| reader.i32().map(PartitionIndex))?,
+        })
+    }
+}
+
+/// Reads the non-flexible primitive types used by the consumer protocol.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.0.len() < len {
+            return Err(Error::UnexpectedEof);
+        }
+
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn i16(&mut self) -> Result<i16, Error> {
+        Ok(i16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> Result<i32, Error> {
+        Ok(i32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        self.nullable_string()?.ok_or(Error::InvalidLength(-1))
+    }
+
+    fn nullable_string(&mut self) -> Result<Option<String>, Error> {
+        match self.i16()? {
+            -1 => Ok(None),
+            len if len < 0 => Err(Error::InvalidLength(len.into())),
+            len => Ok(Some(String::from_utf8(self.take(len as usize)?.to_vec())?)),
+        }
+    }
+
+    fn nullable_bytes(&mut self) -> Result<Option<Vec<u8>>, Error> {
+        // Older clients omit trailing user data entirely.
+        if self.0.is_empty() {
+            return Ok(None);
+        }
+
+        match self.i32()? {
+            -1 => Ok(None),
+            len if len < 0 => Err(Error::InvalidLength(len)),
+            len => Ok(Some(self.take(len as usize)?.to_vec())),
+        }
+    }
+
+    fn array<T>(
+        &mut self,
+        mut read: impl FnMut(&mut Self) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        match self.i32()? {
+            -1 => Ok(vec![]),
+            len if len < 0 => Err(Error::InvalidLength(len)),
+            len => (0..len).map(|_| read(self)).collect(),
+        }
+    }
+}
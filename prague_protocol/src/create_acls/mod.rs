@@ -0,0 +1,19 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{request::CreateAclsRequest, response::CreateAclsResponse, CreateAclsApi};
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct CreateAclsApi;
+
+impl Api for CreateAclsApi {
+    const KEY: ApiKey = ApiKey::CreateAcls;
+
+    type Request = CreateAclsRequest;
+    type Response = CreateAclsResponse;
+}
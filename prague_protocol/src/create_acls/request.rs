@@ -0,0 +1,45 @@
+//! Includes definitions for requests to [CreateAclsApi](super::CreateAclsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/CreateAclsRequest.json>
+
+use crate::{acl::AclBinding, types::prelude::*};
+
+/// A request to create ACLs.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/CreateAclsRequest.json>
+pub struct CreateAclsRequest {
+    /// The ACLs that we want to create.
+    ///
+    /// Versions: 0+
+    pub creations: Vec<AclCreation>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// An ACL that we want to create.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/CreateAclsRequest.json>
+pub struct AclCreation {
+    /// The ACL to create. Versions before 1 can only create
+    /// [Literal](crate::acl::PatternType::Literal) ACLs.
+    ///
+    /// Versions: 0+
+    pub binding: AclBinding,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
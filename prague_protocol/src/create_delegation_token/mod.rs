@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::CreateDelegationTokenRequest, response::CreateDelegationTokenResponse,
+        CreateDelegationTokenApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct CreateDelegationTokenApi;
+
+impl Api for CreateDelegationTokenApi {
+    const KEY: ApiKey = ApiKey::CreateDelegationToken;
+
+    type Request = CreateDelegationTokenRequest;
+    type Response = CreateDelegationTokenResponse;
+}
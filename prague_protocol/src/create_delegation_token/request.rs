@@ -0,0 +1,40 @@
+//! Includes definitions for requests to
+//! [CreateDelegationTokenApi](super::CreateDelegationTokenApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/CreateDelegationTokenRequest.json>
+
+use crate::{delegation_token::KafkaPrincipal, types::prelude::*};
+
+/// A request to create a delegation token.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/CreateDelegationTokenRequest.json>
+pub struct CreateDelegationTokenRequest {
+    /// The principal which will own the token, or None to create the token for the principal
+    /// making the request. Over the wire, this is transmitted as a nullable `OwnerPrincipalType`
+    /// and `OwnerPrincipalName`.
+    ///
+    /// Versions: 3+
+    pub owner: Option<KafkaPrincipal>,
+
+    /// The principals which may renew the token, in addition to its owner.
+    ///
+    /// Versions: 0+
+    pub renewers: Vec<KafkaPrincipal>,
+
+    /// The maximum lifetime of the token in milliseconds, or None to use the broker's
+    /// `delegation.token.max.lifetime.ms`.
+    ///
+    /// Versions: 0+
+    pub max_lifetime: Option<i64>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
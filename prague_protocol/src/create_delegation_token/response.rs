@@ -0,0 +1,78 @@
+//! Includes definitions for responses from
+//! [CreateDelegationTokenApi](super::CreateDelegationTokenApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/CreateDelegationTokenResponse.json>
+
+use crate::{
+    delegation_token::{DelegationTokenHmac, KafkaPrincipal},
+    error_code::ErrorCode,
+    types::prelude::*,
+};
+
+/// A response message associated with
+/// [CreateDelegationTokenRequest](super::request::CreateDelegationTokenRequest).
+///
+/// Use [DelegationToken::from_created](crate::delegation_token::DelegationToken::from_created) to
+/// collect the details of the created token.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/CreateDelegationTokenResponse.json>
+pub struct CreateDelegationTokenResponse {
+    /// The top-level error, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The principal which owns the token. Over the wire, this is transmitted as a
+    /// `PrincipalType` and `PrincipalName`.
+    ///
+    /// Versions: 0+
+    pub principal: KafkaPrincipal,
+
+    /// The principal which requested the token, or None if not sent. Over the wire, this is
+    /// transmitted as a `TokenRequesterPrincipalType` and `TokenRequesterPrincipalName`.
+    ///
+    /// Versions: 3+
+    pub token_requester: Option<KafkaPrincipal>,
+
+    /// When the token was issued.
+    ///
+    /// Versions: 0+
+    pub issue_timestamp: Timestamp,
+
+    /// When the token expires, unless it is renewed.
+    ///
+    /// Versions: 0+
+    pub expiry_timestamp: Timestamp,
+
+    /// The latest time the token can be renewed until.
+    ///
+    /// Versions: 0+
+    pub max_timestamp: Timestamp,
+
+    /// The token ID.
+    ///
+    /// Versions: 0+
+    pub token_id: String,
+
+    /// The HMAC of the token.
+    ///
+    /// Versions: 0+
+    pub hmac: DelegationTokenHmac,
+
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,181 @@
+//! Includes types shared by the delegation token APIs, along with the details needed to
+//! authenticate with a token.
+//!
+//! Delegation tokens let a long-lived service, authenticated by other means, issue short-lived
+//! credentials to workloads acting on its behalf. A workload authenticates by using SCRAM with the
+//! [token ID](DelegationToken::token_id) as its user name, the base64-encoded
+//! [HMAC](DelegationToken::hmac) as its password, and the [TOKEN_AUTH_EXTENSION] set to `true`.
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/documentation/#security_delegation_token>
+//! - <https://cwiki.apache.org/confluence/display/KAFKA/KIP-48+Delegation+token+support+for+Kafka>
+
+use std::fmt;
+
+use base64::Engine;
+
+use crate::{
+    create_delegation_token::response::CreateDelegationTokenResponse,
+    describe_delegation_token::response::DescribedDelegationToken, types::prelude::*,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Principal `{0}` is not of the form `<type>:<name>`")]
+    InvalidPrincipal(String),
+}
+
+/// The SCRAM extension which marks a SCRAM exchange as authenticating with a delegation token.
+pub const TOKEN_AUTH_EXTENSION: &str = "tokenauth";
+
+/// An authenticated identity, such as a user.
+///
+/// Principals are written as `<type>:<name>`, e.g. `User:alice`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KafkaPrincipal {
+    /// The principal type, e.g. `User`.
+    pub principal_type: String,
+
+    /// The principal name.
+    pub name: String,
+}
+
+impl KafkaPrincipal {
+    /// The principal type of users.
+    pub const USER_TYPE: &'static str = "User";
+
+    /// Creates a user principal with the given name.
+    pub fn user(name: impl Into<String>) -> Self {
+        Self {
+            principal_type: Self::USER_TYPE.to_owned(),
+            name: name.into(),
+        }
+    }
+
+    /// Creates a principal from a nullable type and name, as sent in fields that were added in
+    /// later versions. Returns None unless both are present.
+    pub fn from_nullable(principal_type: Option<String>, name: Option<String>) -> Option<Self> {
+        Some(Self {
+            principal_type: principal_type?,
+            name: name?,
+        })
+    }
+}
+
+impl fmt::Display for KafkaPrincipal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.principal_type, self.name)
+    }
+}
+
+impl TryFrom<&str> for KafkaPrincipal {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.split_once(':') {
+            Some((principal_type, name)) if !principal_type.is_empty() => Ok(Self {
+                principal_type: principal_type.to_owned(),
+                name: name.to_owned(),
+            }),
+            _ => Err(Error::InvalidPrincipal(value.to_owned())),
+        }
+    }
+}
+
+/// The HMAC of a delegation token, which acts as the token's secret.
+///
+/// The HMAC is redacted when debug formatted, so that tokens can be logged safely.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct DelegationTokenHmac(pub Vec<u8>);
+
+impl DelegationTokenHmac {
+    /// Returns the HMAC encoded as base64, as used for the SCRAM password.
+    pub fn to_base64(&self) -> String {
+        base64::engine::general_purpose::STANDARD.encode(&self.0)
+    }
+}
+
+impl fmt::Debug for DelegationTokenHmac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DelegationTokenHmac(..)")
+    }
+}
+
+/// A delegation token, as created or described by the broker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelegationToken {
+    /// The ID of the token, which is used as the SCRAM user name.
+    pub token_id: String,
+
+    /// The HMAC of the token.
+    pub hmac: DelegationTokenHmac,
+
+    /// The principal which owns the token.
+    pub owner: KafkaPrincipal,
+
+    /// The principal which requested the token, or None if the broker did not report it.
+    pub token_requester: Option<KafkaPrincipal>,
+
+    /// The principals which may renew the token, in addition to its owner.
+    pub renewers: Vec<KafkaPrincipal>,
+
+    /// When the token was issued.
+    pub issue_timestamp: Timestamp,
+
+    /// When the token expires, unless it is renewed.
+    pub expiry_timestamp: Timestamp,
+
+    /// The latest time the token can be renewed until.
+    pub max_timestamp: Timestamp,
+}
+
+impl DelegationToken {
+    /// Creates a token from a successful create response, along with the renewers that were
+    /// requested, since these are not echoed back.
+    pub fn from_created(
+        response: CreateDelegationTokenResponse,
+        renewers: Vec<KafkaPrincipal>,
+    ) -> Self {
+        Self {
+            token_id: response.token_id,
+            hmac: response.hmac,
+            owner: response.principal,
+            token_requester: response.token_requester,
+            renewers,
+            issue_timestamp: response.issue_timestamp,
+            expiry_timestamp: response.expiry_timestamp,
+            max_timestamp: response.max_timestamp,
+        }
+    }
+
+    /// Returns the SCRAM user name and password with which to authenticate using this token.
+    pub fn scram_credentials(&self) -> (&str, String) {
+        (&self.token_id, self.hmac.to_base64())
+    }
+
+    /// Returns whether the token has expired at the given time.
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        now >= self.expiry_timestamp
+    }
+
+    /// Returns whether the given principal may renew or expire the token.
+    pub fn can_renew(&self, principal: &KafkaPrincipal) -> bool {
+        self.owner == *principal || self.renewers.contains(principal)
+    }
+}
+
+impl From<DescribedDelegationToken> for DelegationToken {
+    fn from(value: DescribedDelegationToken) -> Self {
+        Self {
+            token_id: value.token_id,
+            hmac: value.hmac,
+            owner: value.principal,
+            token_requester: value.token_requester,
+            renewers: value.renewers,
+            issue_timestamp: value.issue_timestamp,
+            expiry_timestamp: value.expiry_timestamp,
+            max_timestamp: value.max_timestamp,
+        }
+    }
+}
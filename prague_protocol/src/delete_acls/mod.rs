@@ -0,0 +1,19 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{request::DeleteAclsRequest, response::DeleteAclsResponse, DeleteAclsApi};
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DeleteAclsApi;
+
+impl Api for DeleteAclsApi {
+    const KEY: ApiKey = ApiKey::DeleteAcls;
+
+    type Request = DeleteAclsRequest;
+    type Response = DeleteAclsResponse;
+}
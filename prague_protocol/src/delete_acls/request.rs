@@ -0,0 +1,45 @@
+//! Includes definitions for requests to [DeleteAclsApi](super::DeleteAclsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DeleteAclsRequest.json>
+
+use crate::{acl::AclBindingFilter, types::prelude::*};
+
+/// A request to delete the ACLs matching one or more filters.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DeleteAclsRequest.json>
+pub struct DeleteAclsRequest {
+    /// The filters to use when deleting ACLs.
+    ///
+    /// Versions: 0+
+    pub filters: Vec<DeleteAclsFilter>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A filter matching the ACLs that we want to delete.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DeleteAclsRequest.json>
+pub struct DeleteAclsFilter {
+    /// The filter that ACLs must match to be deleted. The pattern type filter is only sent in
+    /// versions 1+, and is otherwise treated as [Literal](crate::acl::PatternType::Literal).
+    ///
+    /// Versions: 0+
+    pub filter: AclBindingFilter,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
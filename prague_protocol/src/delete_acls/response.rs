@@ -0,0 +1,88 @@
+//! Includes definitions for responses from [DeleteAclsApi](super::DeleteAclsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DeleteAclsResponse.json>
+
+use crate::{acl::AclBinding, error_code::ErrorCode, types::prelude::*};
+
+/// A response message associated with [DeleteAclsRequest](super::request::DeleteAclsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DeleteAclsResponse.json>
+pub struct DeleteAclsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The results for each filter, in the order they were requested.
+    ///
+    /// Versions: 0+
+    pub filter_results: Vec<DeleteAclsFilterResult>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the result of deleting the ACLs matching a single filter.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DeleteAclsResponse.json>
+pub struct DeleteAclsFilterResult {
+    /// The error code, or None if the filter succeeded.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The error message, or None if the filter succeeded.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The ACLs which matched this filter.
+    ///
+    /// Versions: 0+
+    pub matching_acls: Vec<DeleteAclsMatchingAcl>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes an ACL which matched a delete filter.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DeleteAclsResponse.json>
+pub struct DeleteAclsMatchingAcl {
+    /// The deletion error code, or None if the deletion succeeded.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The deletion error message, or None if the deletion succeeded.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The matched ACL.
+    ///
+    /// Versions: 0+
+    pub binding: AclBinding,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
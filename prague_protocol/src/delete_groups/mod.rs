@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::DeleteGroupsRequest, response::DeleteGroupsResponse, DeleteGroupsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DeleteGroupsApi;
+
+impl Api for DeleteGroupsApi {
+    const KEY: ApiKey = ApiKey::DeleteGroups;
+
+    type Request = DeleteGroupsRequest;
+    type Response = DeleteGroupsResponse;
+}
@@ -0,0 +1,26 @@
+//! Includes definitions for requests to [DeleteGroupsApi](super::DeleteGroupsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DeleteGroupsRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request to delete groups, along with their committed offsets.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DeleteGroupsRequest.json>
+pub struct DeleteGroupsRequest {
+    /// The group names to delete.
+    ///
+    /// Versions: 0+
+    pub group_names: Vec<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::DescribeAclsRequest, response::DescribeAclsResponse, DescribeAclsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DescribeAclsApi;
+
+impl Api for DescribeAclsApi {
+    const KEY: ApiKey = ApiKey::DescribeAcls;
+
+    type Request = DescribeAclsRequest;
+    type Response = DescribeAclsResponse;
+}
@@ -0,0 +1,27 @@
+//! Includes definitions for requests to [DescribeAclsApi](super::DescribeAclsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeAclsRequest.json>
+
+use crate::{acl::AclBindingFilter, types::prelude::*};
+
+/// A request to describe the ACLs matching a filter.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeAclsRequest.json>
+pub struct DescribeAclsRequest {
+    /// The filter that ACLs must match to be described. The pattern type filter is only sent in
+    /// versions 1+, and is otherwise treated as [Literal](crate::acl::PatternType::Literal).
+    ///
+    /// Versions: 0+
+    pub filter: AclBindingFilter,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,69 @@
+//! Includes definitions for responses from [DescribeAclsApi](super::DescribeAclsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeAclsResponse.json>
+
+use crate::{
+    acl::{AccessControlEntry, ResourcePattern},
+    error_code::ErrorCode,
+    types::prelude::*,
+};
+
+/// A response message associated with [DescribeAclsRequest](super::request::DescribeAclsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeAclsResponse.json>
+pub struct DescribeAclsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// Each resource that is referenced in an ACL.
+    ///
+    /// Versions: 0+
+    pub resources: Vec<DescribeAclsResource>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the ACLs bound to a single resource pattern.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeAclsResponse.json>
+pub struct DescribeAclsResource {
+    /// The resource type, name and pattern type.
+    ///
+    /// Versions: 0+
+    pub pattern: ResourcePattern,
+
+    /// The ACLs.
+    ///
+    /// Versions: 0+
+    pub acls: Vec<AccessControlEntry>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::DescribeClientQuotasRequest, response::DescribeClientQuotasResponse,
+        DescribeClientQuotasApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DescribeClientQuotasApi;
+
+impl Api for DescribeClientQuotasApi {
+    const KEY: ApiKey = ApiKey::DescribeClientQuotas;
+
+    type Request = DescribeClientQuotasRequest;
+    type Response = DescribeClientQuotasResponse;
+}
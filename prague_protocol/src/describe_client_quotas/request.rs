@@ -0,0 +1,31 @@
+//! Includes definitions for requests to [DescribeClientQuotasApi](super::DescribeClientQuotasApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeClientQuotasRequest.json>
+
+use crate::{quota::QuotaFilterComponent, types::prelude::*};
+
+/// A request to describe the quotas of the entities matching a filter.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeClientQuotasRequest.json>
+pub struct DescribeClientQuotasRequest {
+    /// Filter components to apply to quota entities.
+    ///
+    /// Versions: 0+
+    pub components: Vec<QuotaFilterComponent>,
+
+    /// Whether the match is strict, i.e. should exclude entities with unspecified entity types.
+    ///
+    /// Versions: 0+
+    pub strict: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,90 @@
+//! Includes definitions for responses from
+//! [DescribeClientQuotasApi](super::DescribeClientQuotasApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeClientQuotasResponse.json>
+
+use crate::{error_code::ErrorCode, quota::QuotaEntityComponent, types::prelude::*};
+
+/// A response message associated with
+/// [DescribeClientQuotasRequest](super::request::DescribeClientQuotasRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeClientQuotasResponse.json>
+pub struct DescribeClientQuotasResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The error code, or None if the quota description succeeded.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The error message, or None if the quota description succeeded.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// A result entry, or None if there was an error.
+    ///
+    /// Versions: 0+
+    pub entries: Option<Vec<EntryData>>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the quotas of a single entity.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeClientQuotasResponse.json>
+pub struct EntryData {
+    /// The quota entity description.
+    ///
+    /// Versions: 0+
+    pub entity: Vec<QuotaEntityComponent>,
+
+    /// The quota values for the entity.
+    ///
+    /// Versions: 0+
+    pub values: Vec<ValueData>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes a single quota value.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeClientQuotasResponse.json>
+pub struct ValueData {
+    /// The quota configuration key, e.g. [PRODUCER_BYTE_RATE](crate::quota::PRODUCER_BYTE_RATE).
+    ///
+    /// Versions: 0+
+    pub key: String,
+
+    /// The quota configuration value.
+    ///
+    /// Versions: 0+
+    pub value: f64,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
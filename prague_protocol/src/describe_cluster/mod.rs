@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::DescribeClusterRequest, response::DescribeClusterResponse, DescribeClusterApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DescribeClusterApi;
+
+impl Api for DescribeClusterApi {
+    const KEY: ApiKey = ApiKey::DescribeCluster;
+
+    type Request = DescribeClusterRequest;
+    type Response = DescribeClusterResponse;
+}
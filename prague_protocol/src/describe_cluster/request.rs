@@ -0,0 +1,59 @@
+//! Includes definitions for requests to [DescribeClusterApi](super::DescribeClusterApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeClusterRequest.json>
+
+use crate::types::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Endpoint type `{0}` is not valid")]
+    InvalidEndpointType(i8),
+}
+
+/// A request to describe the cluster's brokers and controller.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeClusterRequest.json>
+pub struct DescribeClusterRequest {
+    /// Whether to include the operations the client is authorized to perform on the cluster.
+    ///
+    /// Versions: 0+
+    pub include_cluster_authorized_operations: bool,
+
+    /// The type of endpoint being described. Versions before 1 only support
+    /// [EndpointType::Broker].
+    ///
+    /// Versions: 1+
+    pub endpoint_type: EndpointType,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The kind of node a request is sent to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointType {
+    Unknown,
+    Broker,
+    Controller,
+}
+
+impl TryFrom<i8> for EndpointType {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::Broker),
+            2 => Ok(Self::Controller),
+            code => Err(Error::InvalidEndpointType(code)),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Includes definitions for responses from [DescribeClusterApi](super::DescribeClusterApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeClusterResponse.json>
+
+use std::collections::HashSet;
+
+use super::request::EndpointType;
+use crate::{acl::AclOperation, error_code::ErrorCode, types::prelude::*};
+
+/// A response message associated with
+/// [DescribeClusterRequest](super::request::DescribeClusterRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeClusterResponse.json>
+pub struct DescribeClusterResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The top-level error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The top-level error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The endpoint type that was described.
+    ///
+    /// Versions: 1+
+    pub endpoint_type: EndpointType,
+
+    /// The cluster ID that the responding broker belongs to.
+    ///
+    /// Versions: 0+
+    pub cluster_id: String,
+
+    /// The ID of the controller, or None if it is not known.
+    ///
+    /// Versions: 0+
+    pub controller_id: Option<BrokerId>,
+
+    /// Each broker in the response.
+    ///
+    /// Versions: 0+
+    pub brokers: Vec<DescribeClusterBroker>,
+
+    /// The operations the client is authorized to perform on the cluster, or None if they were not
+    /// requested.
+    ///
+    /// Versions: 0+
+    pub cluster_authorized_operations: Option<HashSet<AclOperation>>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes a broker in the cluster.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeClusterResponse.json>
+pub struct DescribeClusterBroker {
+    /// The broker ID.
+    ///
+    /// Versions: 0+
+    pub id: BrokerId,
+
+    /// The broker hostname.
+    ///
+    /// Versions: 0+
+    pub host: String,
+
+    /// The broker port.
+    ///
+    /// Versions: 0+
+    pub port: i32,
+
+    /// The rack of the broker, if it has been assigned to one.
+    ///
+    /// Versions: 0+
+    pub rack: Option<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::DescribeConfigsRequest, response::DescribeConfigsResponse, DescribeConfigsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DescribeConfigsApi;
+
+impl Api for DescribeConfigsApi {
+    const KEY: ApiKey = ApiKey::DescribeConfigs;
+
+    type Request = DescribeConfigsRequest;
+    type Response = DescribeConfigsResponse;
+}
@@ -0,0 +1,59 @@
+//! Includes definitions for requests to [DescribeConfigsApi](super::DescribeConfigsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeConfigsRequest.json>
+
+use crate::{config::ConfigResource, types::prelude::*};
+
+/// A request to describe the configuration of one or more resources.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeConfigsRequest.json>
+pub struct DescribeConfigsRequest {
+    /// The resources whose configurations we want to describe.
+    ///
+    /// Versions: 0+
+    pub resources: Vec<DescribeConfigsResource>,
+
+    /// True if we should include all synonyms.
+    ///
+    /// Versions: 1+
+    pub include_synonyms: bool,
+
+    /// True if we should include configuration documentation.
+    ///
+    /// Versions: 3+
+    pub include_documentation: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A resource whose configuration we want to describe.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeConfigsRequest.json>
+pub struct DescribeConfigsResource {
+    /// The resource type and name.
+    ///
+    /// Versions: 0+
+    pub resource: ConfigResource,
+
+    /// The configuration keys to list, or None to list all configuration keys.
+    ///
+    /// Versions: 0+
+    pub configuration_keys: Option<Vec<String>>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,161 @@
+//! Includes definitions for responses from [DescribeConfigsApi](super::DescribeConfigsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeConfigsResponse.json>
+
+use crate::{
+    config::{ConfigResource, ConfigSource, ConfigType},
+    error_code::ErrorCode,
+    types::prelude::*,
+};
+
+/// A response message associated with
+/// [DescribeConfigsRequest](super::request::DescribeConfigsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeConfigsResponse.json>
+pub struct DescribeConfigsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The results for each resource.
+    ///
+    /// Versions: 0+
+    pub results: Vec<DescribeConfigsResult>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the configuration of a single resource.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeConfigsResponse.json>
+pub struct DescribeConfigsResult {
+    /// The error code, or None if we were able to successfully describe the configurations.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The error message, or None if we were able to successfully describe the configurations.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The resource type and name.
+    ///
+    /// Versions: 0+
+    pub resource: ConfigResource,
+
+    /// Each listed configuration.
+    ///
+    /// Versions: 0+
+    pub configs: Vec<DescribeConfigsEntry>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes a single configuration entry of a resource.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeConfigsResponse.json>
+pub struct DescribeConfigsEntry {
+    /// The configuration name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The configuration value, or None if the configuration is sensitive.
+    ///
+    /// Versions: 0+
+    pub value: Option<String>,
+
+    /// True if the configuration is read-only.
+    ///
+    /// Versions: 0+
+    pub read_only: bool,
+
+    /// The configuration source. Brokers before version 1 only report whether the configuration
+    /// is a default, in which case this is [ConfigSource::DefaultConfig] or
+    /// [ConfigSource::Unknown].
+    ///
+    /// Versions: 0+
+    pub source: ConfigSource,
+
+    /// True if this configuration is sensitive.
+    ///
+    /// Versions: 0+
+    pub is_sensitive: bool,
+
+    /// The synonyms for this configuration key, in order of precedence.
+    ///
+    /// Versions: 1+
+    pub synonyms: Vec<DescribeConfigsSynonym>,
+
+    /// The configuration data type.
+    ///
+    /// Versions: 3+
+    pub config_type: ConfigType,
+
+    /// The configuration documentation, or None if it was not requested or is not available.
+    ///
+    /// Versions: 3+
+    pub documentation: Option<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
+
+impl DescribeConfigsEntry {
+    /// Returns whether this configuration is set to its built-in default value.
+    pub fn is_default(&self) -> bool {
+        self.source == ConfigSource::DefaultConfig
+    }
+}
+
+/// Describes a synonym of a configuration entry, such as the broker-level default of a topic
+/// config.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeConfigsResponse.json>
+pub struct DescribeConfigsSynonym {
+    /// The synonym name.
+    ///
+    /// Versions: 1+
+    pub name: String,
+
+    /// The synonym value, or None if the synonym is sensitive.
+    ///
+    /// Versions: 1+
+    pub value: Option<String>,
+
+    /// The synonym source.
+    ///
+    /// Versions: 1+
+    pub source: ConfigSource,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
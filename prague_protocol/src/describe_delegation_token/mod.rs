@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::DescribeDelegationTokenRequest, response::DescribeDelegationTokenResponse,
+        DescribeDelegationTokenApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DescribeDelegationTokenApi;
+
+impl Api for DescribeDelegationTokenApi {
+    const KEY: ApiKey = ApiKey::DescribeDelegationToken;
+
+    type Request = DescribeDelegationTokenRequest;
+    type Response = DescribeDelegationTokenResponse;
+}
@@ -0,0 +1,28 @@
+//! Includes definitions for requests to
+//! [DescribeDelegationTokenApi](super::DescribeDelegationTokenApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeDelegationTokenRequest.json>
+
+use crate::{delegation_token::KafkaPrincipal, types::prelude::*};
+
+/// A request to describe delegation tokens.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeDelegationTokenRequest.json>
+pub struct DescribeDelegationTokenRequest {
+    /// The owners of the tokens to describe, or None to describe every token the requester may
+    /// see.
+    ///
+    /// Versions: 0+
+    pub owners: Option<Vec<KafkaPrincipal>>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::DescribeGroupsRequest, response::DescribeGroupsResponse, DescribeGroupsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DescribeGroupsApi;
+
+impl Api for DescribeGroupsApi {
+    const KEY: ApiKey = ApiKey::DescribeGroups;
+
+    type Request = DescribeGroupsRequest;
+    type Response = DescribeGroupsResponse;
+}
@@ -0,0 +1,31 @@
+//! Includes definitions for requests to [DescribeGroupsApi](super::DescribeGroupsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeGroupsRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request to describe classic groups.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeGroupsRequest.json>
+pub struct DescribeGroupsRequest {
+    /// The names of the groups to describe.
+    ///
+    /// Versions: 0+
+    pub groups: Vec<String>,
+
+    /// Whether to include the operations the client is authorized to perform on each group.
+    ///
+    /// Versions: 3+
+    pub include_authorized_operations: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 5+
+    pub tagged_fields: TaggedFields,
+}
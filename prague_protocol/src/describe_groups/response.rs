@@ -0,0 +1,162 @@
+//! Includes definitions for responses from [DescribeGroupsApi](super::DescribeGroupsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeGroupsResponse.json>
+
+use std::collections::HashSet;
+
+use crate::{
+    acl::AclOperation,
+    consumer_protocol::{self, ConsumerProtocolAssignment, ConsumerProtocolSubscription},
+    error_code::ErrorCode,
+    group::GroupState,
+    types::prelude::*,
+};
+
+/// A response message associated with
+/// [DescribeGroupsRequest](super::request::DescribeGroupsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeGroupsResponse.json>
+pub struct DescribeGroupsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 1+
+    pub throttle_time: Option<Duration>,
+
+    /// Each described group.
+    ///
+    /// Versions: 0+
+    pub groups: Vec<DescribedGroup>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 5+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes a single group.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeGroupsResponse.json>
+pub struct DescribedGroup {
+    /// The describe error, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The describe error message, or None if there was no error.
+    ///
+    /// Versions: 6+
+    pub error_message: Option<String>,
+
+    /// The group ID string.
+    ///
+    /// Versions: 0+
+    pub group_id: String,
+
+    /// The group state.
+    ///
+    /// Versions: 0+
+    pub group_state: GroupState,
+
+    /// The group protocol type, or an empty string if the group is empty.
+    ///
+    /// Versions: 0+
+    pub protocol_type: String,
+
+    /// The group protocol data, e.g. the name of the partition assignor of a consumer group, or an
+    /// empty string if the group is empty.
+    ///
+    /// Versions: 0+
+    pub protocol_data: String,
+
+    /// The group members.
+    ///
+    /// Versions: 0+
+    pub members: Vec<DescribedGroupMember>,
+
+    /// The operations the client is authorized to perform on this group, or None if they were not
+    /// requested.
+    ///
+    /// Versions: 3+
+    pub authorized_operations: Option<HashSet<AclOperation>>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 5+
+    pub tagged_fields: TaggedFields,
+}
+
+impl DescribedGroup {
+    /// Returns whether this group uses the embedded consumer protocol, in which case the metadata
+    /// and assignments of its members can be decoded.
+    pub fn is_consumer_group(&self) -> bool {
+        self.protocol_type == consumer_protocol::PROTOCOL_TYPE
+    }
+}
+
+/// Describes a member of a group.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeGroupsResponse.json>
+pub struct DescribedGroupMember {
+    /// The member ID assigned by the group coordinator.
+    ///
+    /// Versions: 0+
+    pub member_id: String,
+
+    /// The unique identifier of the consumer instance provided by the end user, or None if this is
+    /// not a static member.
+    ///
+    /// Versions: 4+
+    pub group_instance_id: Option<String>,
+
+    /// The client ID used in the member's latest join group request.
+    ///
+    /// Versions: 0+
+    pub client_id: String,
+
+    /// The client host.
+    ///
+    /// Versions: 0+
+    pub client_host: String,
+
+    /// The metadata corresponding to the current group protocol in use.
+    ///
+    /// Versions: 0+
+    pub member_metadata: Vec<u8>,
+
+    /// The current assignment provided by the group leader.
+    ///
+    /// Versions: 0+
+    pub member_assignment: Vec<u8>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 5+
+    pub tagged_fields: TaggedFields,
+}
+
+impl DescribedGroupMember {
+    /// Decodes [member_metadata](Self::member_metadata) as the subscription of a consumer. Only
+    /// meaningful if the group [is a consumer group](DescribedGroup::is_consumer_group).
+    pub fn subscription(&self) -> Result<ConsumerProtocolSubscription, consumer_protocol::Error> {
+        ConsumerProtocolSubscription::decode(&self.member_metadata)
+    }
+
+    /// Decodes [member_assignment](Self::member_assignment) as the assignment of a consumer. Only
+    /// meaningful if the group [is a consumer group](DescribedGroup::is_consumer_group).
+    pub fn assignment(&self) -> Result<ConsumerProtocolAssignment, consumer_protocol::Error> {
+        ConsumerProtocolAssignment::decode(&self.member_assignment)
+    }
+}
@@ -0,0 +1,23 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+pub mod summary;
+
+pub mod prelude {
+    pub use super::{
+        request::DescribeLogDirsRequest, response::DescribeLogDirsResponse, summary::LogDirSummary,
+        DescribeLogDirsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DescribeLogDirsApi;
+
+impl Api for DescribeLogDirsApi {
+    const KEY: ApiKey = ApiKey::DescribeLogDirs;
+
+    type Request = DescribeLogDirsRequest;
+    type Response = DescribeLogDirsResponse;
+}
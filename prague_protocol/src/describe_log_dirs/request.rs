@@ -0,0 +1,49 @@
+//! Includes definitions for requests to [DescribeLogDirsApi](super::DescribeLogDirsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeLogDirsRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request to describe the log directories of the broker it is sent to.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeLogDirsRequest.json>
+pub struct DescribeLogDirsRequest {
+    /// Each topic that we want to describe log directories for, or None for all topics.
+    ///
+    /// Versions: 0+
+    pub topics: Option<Vec<DescribableLogDirTopic>>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A topic that we want to describe log directories for.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeLogDirsRequest.json>
+pub struct DescribableLogDirTopic {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub topic: String,
+
+    /// The partition indexes.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<PartitionIndex>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,137 @@
+//! Includes definitions for responses from [DescribeLogDirsApi](super::DescribeLogDirsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeLogDirsResponse.json>
+
+use crate::{error_code::ErrorCode, types::prelude::*};
+
+/// A response message associated with
+/// [DescribeLogDirsRequest](super::request::DescribeLogDirsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeLogDirsResponse.json>
+pub struct DescribeLogDirsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The top-level error code, or None if there was no error.
+    ///
+    /// Versions: 3+
+    pub error_code: Option<ErrorCode>,
+
+    /// The log directories.
+    ///
+    /// Versions: 0+
+    pub results: Vec<LogDir>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes a single log directory on a broker.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeLogDirsResponse.json>
+pub struct LogDir {
+    /// The error code, or None if there was no error. A
+    /// [KafkaStorageError](crate::error_code::ErrorCode::KafkaStorageError) means the directory is
+    /// offline.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The absolute log directory path.
+    ///
+    /// Versions: 0+
+    pub log_dir: String,
+
+    /// Each topic with replicas in this directory.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<LogDirTopic>,
+
+    /// The total size in bytes of the volume the log directory is in, or None if not known.
+    ///
+    /// Versions: 4+
+    pub total_bytes: Option<i64>,
+
+    /// The usable size in bytes of the volume the log directory is in, or None if not known.
+    ///
+    /// Versions: 4+
+    pub usable_bytes: Option<i64>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the replicas of a topic in a log directory.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeLogDirsResponse.json>
+pub struct LogDirTopic {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// Each partition replica in this directory.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<LogDirPartition>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes a partition replica in a log directory.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeLogDirsResponse.json>
+pub struct LogDirPartition {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub index: PartitionIndex,
+
+    /// The size of the log segments in this partition in bytes.
+    ///
+    /// Versions: 0+
+    pub size: i64,
+
+    /// The lag of the log's log end offset compared to the partition's high watermark (if it is
+    /// the current log for the partition) or the current replica's log end offset (if it is the
+    /// future log for the partition).
+    ///
+    /// Versions: 0+
+    pub offset_lag: i64,
+
+    /// True if this log is created by AlterReplicaLogDirs and will replace the current log of the
+    /// replica in the future.
+    ///
+    /// Versions: 0+
+    pub is_future_key: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,209 @@
+//! Includes a helper for aggregating the disk usage reported by
+//! [DescribeLogDirsApi](super::DescribeLogDirsApi) across a cluster.
+
+use std::collections::HashMap;
+
+use super::response::DescribeLogDirsResponse;
+use crate::types::prelude::*;
+
+/// Disk usage rolled up per broker, topic and log directory.
+///
+/// Each broker only describes its own log directories, so a cluster-wide view is built by sending
+/// a [DescribeLogDirsRequest](super::request::DescribeLogDirsRequest) to every broker and
+/// [adding](Self::add) each response. Log directories that reported an error, such as offline
+/// directories, are skipped.
+#[derive(Debug, Default)]
+pub struct LogDirSummary {
+    /// The total size in bytes of all replicas on each broker.
+    pub brokers: HashMap<BrokerId, i64>,
+
+    /// The total size in bytes of all replicas of each topic, across every broker.
+    pub topics: HashMap<String, i64>,
+
+    /// The usage of each log directory, keyed by broker and directory path.
+    pub directories: HashMap<(BrokerId, String), LogDirUsage>,
+}
+
+/// The usage of a single log directory.
+#[derive(Debug, Default)]
+pub struct LogDirUsage {
+    /// The total size in bytes of all replicas in this directory.
+    pub replica_bytes: i64,
+
+    /// The size in bytes of each topic's replicas in this directory.
+    pub topics: HashMap<String, i64>,
+
+    /// The largest offset lag of any replica in this directory.
+    pub max_offset_lag: i64,
+
+    /// The total size in bytes of the volume the directory is in, if known.
+    pub total_bytes: Option<i64>,
+
+    /// The usable size in bytes of the volume the directory is in, if known.
+    pub usable_bytes: Option<i64>,
+}
+
+impl LogDirSummary {
+    /// Builds a summary from the responses of each broker.
+    pub fn from_responses<'a>(
+        responses: impl IntoIterator<Item = (BrokerId, &'a DescribeLogDirsResponse)>,
+    ) -> Self {
+        responses
+            .into_iter()
+            .fold(Self::default(), |mut summary, (broker, response)| {
+                summary.add(broker, response);
+                summary
+            })
+    }
+
+    /// Adds the log directories described by the given broker to this summary.
+    ///
+    /// Future replicas created by AlterReplicaLogDirs occupy disk space like any other replica, so
+    /// they are included in every total.
+    pub fn add(&mut self, broker: BrokerId, response: &DescribeLogDirsResponse) {
+        self.brokers.entry(broker).or_default();
+
+        for log_dir in response
+            .results
+            .iter()
+            .filter(|dir| dir.error_code.is_none())
+        {
+            let usage = self
+                .directories
+                .entry((broker, log_dir.log_dir.clone()))
+                .or_default();
+
+            usage.total_bytes = log_dir.total_bytes;
+            usage.usable_bytes = log_dir.usable_bytes;
+
+            for topic in &log_dir.topics {
+                let size: i64 = topic.partitions.iter().map(|p| p.size).sum();
+                let max_lag = topic.partitions.iter().map(|p| p.offset_lag).max();
+
+                usage.replica_bytes += size;
+                usage.max_offset_lag = usage.max_offset_lag.max(max_lag.unwrap_or(0));
+                *usage.topics.entry(topic.name.clone()).or_default() += size;
+                *self.topics.entry(topic.name.clone()).or_default() += size;
+                *self.brokers.entry(broker).or_default() += size;
+            }
+        }
+    }
+
+    /// Returns the total size in bytes of all replicas in the cluster.
+    pub fn total_bytes(&self) -> i64 {
+        self.brokers.values().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        describe_log_dirs::response::{LogDir, LogDirPartition, LogDirTopic},
+        error_code::ErrorCode,
+    };
+
+    /// A topic with one partition of each given size and offset lag.
+    fn topic(name: &str, partitions: &[(i64, i64)]) -> LogDirTopic {
+        LogDirTopic {
+            name: name.to_owned(),
+            partitions: partitions
+                .iter()
+                .enumerate()
+                .map(|(index, &(size, offset_lag))| LogDirPartition {
+                    index: PartitionIndex(index as i32),
+                    size,
+                    offset_lag,
+                    is_future_key: false,
+                    tagged_fields: Default::default(),
+                })
+                .collect(),
+            tagged_fields: Default::default(),
+        }
+    }
+
+    fn log_dir(path: &str, topics: Vec<LogDirTopic>, volume: Option<(i64, i64)>) -> LogDir {
+        LogDir {
+            error_code: None,
+            log_dir: path.to_owned(),
+            topics,
+            total_bytes: volume.map(|(total, _)| total),
+            usable_bytes: volume.map(|(_, usable)| usable),
+            tagged_fields: Default::default(),
+        }
+    }
+
+    /// An offline directory, whose replicas are not counted.
+    fn offline() -> LogDir {
+        LogDir {
+            error_code: Some(ErrorCode::KafkaStorageError),
+            ..log_dir("/offline", vec![topic("orders", &[(999, 0)])], None)
+        }
+    }
+
+    fn response(results: Vec<LogDir>) -> DescribeLogDirsResponse {
+        DescribeLogDirsResponse {
+            throttle_time: None,
+            error_code: None,
+            results,
+            tagged_fields: Default::default(),
+        }
+    }
+
+    #[test]
+    fn from_responses() {
+        let first = response(vec![
+            log_dir(
+                "/data",
+                vec![
+                    topic("orders", &[(10, 0), (20, 5)]),
+                    topic("users", &[(5, 1)]),
+                ],
+                Some((1000, 400)),
+            ),
+            offline(),
+            // Brokers which cannot tell the size of the volume report -1, which is held as None.
+            log_dir("/unknown", vec![topic("orders", &[(7, 2)])], None),
+        ]);
+
+        let mut future = topic("users", &[(4, 0)]);
+        future.partitions[0].is_future_key = true;
+        let second = response(vec![log_dir(
+            "/data",
+            vec![topic("orders", &[(3, 0)]), future],
+            Some((2000, 1500)),
+        )]);
+        let third = response(vec![offline()]);
+
+        let summary = LogDirSummary::from_responses([
+            (BrokerId(1), &first),
+            (BrokerId(2), &second),
+            (BrokerId(3), &third),
+        ]);
+
+        assert_eq!(summary.topics["orders"], 40);
+        assert_eq!(summary.topics["users"], 9);
+        assert_eq!(summary.brokers[&BrokerId(1)], 42);
+        assert_eq!(summary.brokers[&BrokerId(2)], 7);
+        assert_eq!(summary.brokers[&BrokerId(3)], 0);
+        assert_eq!(summary.total_bytes(), 49);
+
+        assert_eq!(summary.directories.len(), 3);
+        assert!(!summary
+            .directories
+            .contains_key(&(BrokerId(1), "/offline".to_owned())));
+
+        let data = &summary.directories[&(BrokerId(1), "/data".to_owned())];
+        assert_eq!(data.replica_bytes, 35);
+        assert_eq!(data.topics["orders"], 30);
+        assert_eq!(data.max_offset_lag, 5);
+        assert_eq!(data.total_bytes, Some(1000));
+        assert_eq!(data.usable_bytes, Some(400));
+
+        let unknown = &summary.directories[&(BrokerId(1), "/unknown".to_owned())];
+        assert_eq!(unknown.replica_bytes, 7);
+        assert_eq!(unknown.max_offset_lag, 2);
+        assert_eq!(unknown.total_bytes, None);
+        assert_eq!(unknown.usable_bytes, None);
+    }
+}
@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::DescribeProducersRequest, response::DescribeProducersResponse,
+        DescribeProducersApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DescribeProducersApi;
+
+impl Api for DescribeProducersApi {
+    const KEY: ApiKey = ApiKey::DescribeProducers;
+
+    type Request = DescribeProducersRequest;
+    type Response = DescribeProducersResponse;
+}
@@ -0,0 +1,50 @@
+//! Includes definitions for requests to [DescribeProducersApi](super::DescribeProducersApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeProducersRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request to describe the producers with state on a set of partitions. Must be sent to each
+/// partition's leader.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeProducersRequest.json>
+pub struct DescribeProducersRequest {
+    /// The topics to describe producers for.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<TopicRequest>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A topic to describe producers for.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeProducersRequest.json>
+pub struct TopicRequest {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The indexes of the partitions to describe producers for.
+    ///
+    /// Versions: 0+
+    pub partition_indexes: Vec<PartitionIndex>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,133 @@
+//! Includes definitions for responses from [DescribeProducersApi](super::DescribeProducersApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeProducersResponse.json>
+
+use crate::{error_code::ErrorCode, types::prelude::*};
+
+/// A response message associated with
+/// [DescribeProducersRequest](super::request::DescribeProducersRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeProducersResponse.json>
+pub struct DescribeProducersResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// Each topic in the response.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<TopicResponse>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the producers of a topic's partitions.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeProducersResponse.json>
+pub struct TopicResponse {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// Each partition in the response.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<PartitionResponse>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the producers of a single partition.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeProducersResponse.json>
+pub struct PartitionResponse {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub index: PartitionIndex,
+
+    /// The partition error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The partition error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The producers with state on this partition.
+    ///
+    /// Versions: 0+
+    pub active_producers: Vec<ProducerState>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the state a partition leader keeps for a single producer.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeProducersResponse.json>
+pub struct ProducerState {
+    /// The producer ID.
+    ///
+    /// Versions: 0+
+    pub producer_id: ProducerId,
+
+    /// The producer epoch.
+    ///
+    /// Versions: 0+
+    pub producer_epoch: Epoch,
+
+    /// The sequence number of the last record written by the producer, or None if not known.
+    ///
+    /// Versions: 0+
+    pub last_sequence: Option<i32>,
+
+    /// The timestamp of the last record written by the producer, or None if not known.
+    ///
+    /// Versions: 0+
+    pub last_timestamp: Option<Timestamp>,
+
+    /// The epoch of the transaction coordinator that last wrote a marker for this producer.
+    ///
+    /// Versions: 0+
+    pub coordinator_epoch: Epoch,
+
+    /// The offset of the first record of the producer's ongoing transaction, or None if it has no
+    /// ongoing transaction. A producer whose transaction started long ago is likely hung.
+    ///
+    /// Versions: 0+
+    pub current_txn_start_offset: Option<Offset>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::DescribeQuorumRequest, response::DescribeQuorumResponse, DescribeQuorumApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DescribeQuorumApi;
+
+impl Api for DescribeQuorumApi {
+    const KEY: ApiKey = ApiKey::DescribeQuorum;
+
+    type Request = DescribeQuorumRequest;
+    type Response = DescribeQuorumResponse;
+}
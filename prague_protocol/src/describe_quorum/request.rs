@@ -0,0 +1,67 @@
+//! Includes definitions for requests to [DescribeQuorumApi](super::DescribeQuorumApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeQuorumRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request to describe the state of the KRaft metadata quorum.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeQuorumRequest.json>
+pub struct DescribeQuorumRequest {
+    /// The topics to describe. In practice, this is only the `__cluster_metadata` topic.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<DescribeQuorumTopic>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A topic to describe the quorum of.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeQuorumRequest.json>
+pub struct DescribeQuorumTopic {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub topic_name: String,
+
+    /// The partitions to describe.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<DescribeQuorumPartition>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A partition to describe the quorum of.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeQuorumRequest.json>
+pub struct DescribeQuorumPartition {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub partition_index: PartitionIndex,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
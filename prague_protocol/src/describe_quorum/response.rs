@@ -0,0 +1,206 @@
+//! Includes definitions for responses from [DescribeQuorumApi](super::DescribeQuorumApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeQuorumResponse.json>
+
+use crate::{error_code::ErrorCode, raft::Listener, types::prelude::*};
+
+/// A response message associated with [DescribeQuorumRequest](super::request::DescribeQuorumRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeQuorumResponse.json>
+pub struct DescribeQuorumResponse {
+    /// The top-level error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The top-level error message, or None if there was no error.
+    ///
+    /// Versions: 2+
+    pub error_message: Option<String>,
+
+    /// The quorum of each topic.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<DescribeQuorumTopicResponse>,
+
+    /// The controllers in the quorum and their listeners.
+    ///
+    /// Versions: 2+
+    pub nodes: Vec<Node>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The quorum of a single topic.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeQuorumResponse.json>
+pub struct DescribeQuorumTopicResponse {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub topic_name: String,
+
+    /// The quorum of each partition.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<PartitionQuorum>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The quorum of a single partition.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeQuorumResponse.json>
+pub struct PartitionQuorum {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub partition_index: PartitionIndex,
+
+    /// The partition-level error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The partition-level error message, or None if there was no error.
+    ///
+    /// Versions: 2+
+    pub error_message: Option<String>,
+
+    /// The ID of the current leader, or None if not known.
+    ///
+    /// Versions: 0+
+    pub leader_id: Option<BrokerId>,
+
+    /// The latest known leader epoch.
+    ///
+    /// Versions: 0+
+    pub leader_epoch: Epoch,
+
+    /// The high watermark of the metadata log.
+    ///
+    /// Versions: 0+
+    pub high_watermark: Offset,
+
+    /// The replicas which vote in leader elections.
+    ///
+    /// Versions: 0+
+    pub current_voters: Vec<ReplicaState>,
+
+    /// The replicas which follow the log without voting, such as brokers.
+    ///
+    /// Versions: 0+
+    pub observers: Vec<ReplicaState>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+impl PartitionQuorum {
+    /// Returns the state of the leader, or None if there is no leader.
+    pub fn leader(&self) -> Option<&ReplicaState> {
+        let leader_id = self.leader_id?;
+
+        self.current_voters
+            .iter()
+            .find(|voter| voter.replica_id == leader_id)
+    }
+
+    /// Returns how many records the given replica is behind the leader, or None if there is no
+    /// leader. This matches the lag reported by `kafka-metadata-quorum.sh`.
+    pub fn lag(&self, replica: &ReplicaState) -> Option<i64> {
+        let leader = self.leader()?;
+        Some((leader.log_end_offset.0 - replica.log_end_offset.0).max(0))
+    }
+
+    /// Returns the largest lag of any voter or observer, or None if there is no leader.
+    pub fn max_lag(&self) -> Option<i64> {
+        self.current_voters
+            .iter()
+            .chain(&self.observers)
+            .map(|replica| self.lag(replica))
+            .max()
+            .flatten()
+    }
+}
+
+/// The replication state of a single voter or observer.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeQuorumResponse.json>
+pub struct ReplicaState {
+    /// The ID of the replica.
+    ///
+    /// Versions: 0+
+    pub replica_id: BrokerId,
+
+    /// The directory ID of the replica.
+    ///
+    /// Versions: 2+
+    pub replica_directory_id: Uuid,
+
+    /// The end offset of the replica's log, as last known to the leader.
+    ///
+    /// Versions: 0+
+    pub log_end_offset: Offset,
+
+    /// When the replica last fetched from the leader, or None if not known.
+    ///
+    /// Versions: 1+
+    pub last_fetch_timestamp: Option<Timestamp>,
+
+    /// When the replica was last caught up with the leader, or None if not known.
+    ///
+    /// Versions: 1+
+    pub last_caught_up_timestamp: Option<Timestamp>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A controller in the quorum.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeQuorumResponse.json>
+pub struct Node {
+    /// The ID of the controller.
+    ///
+    /// Versions: 2+
+    pub node_id: BrokerId,
+
+    /// The listeners of the controller.
+    ///
+    /// Versions: 2+
+    pub listeners: Vec<Listener>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::DescribeTransactionsRequest, response::DescribeTransactionsResponse,
+        DescribeTransactionsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DescribeTransactionsApi;
+
+impl Api for DescribeTransactionsApi {
+    const KEY: ApiKey = ApiKey::DescribeTransactions;
+
+    type Request = DescribeTransactionsRequest;
+    type Response = DescribeTransactionsResponse;
+}
@@ -0,0 +1,27 @@
+//! Includes definitions for requests to [DescribeTransactionsApi](super::DescribeTransactionsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeTransactionsRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request to describe the state of transactions. Must be sent to the transaction coordinator of
+/// each transactional ID.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeTransactionsRequest.json>
+pub struct DescribeTransactionsRequest {
+    /// The transactional IDs to describe.
+    ///
+    /// Versions: 0+
+    pub transactional_ids: Vec<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
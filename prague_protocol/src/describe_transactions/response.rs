@@ -0,0 +1,110 @@
+//! Includes definitions for responses from
+//! [DescribeTransactionsApi](super::DescribeTransactionsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeTransactionsResponse.json>
+
+use crate::{error_code::ErrorCode, transaction::TransactionState, types::prelude::*};
+
+/// A response message associated with
+/// [DescribeTransactionsRequest](super::request::DescribeTransactionsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeTransactionsResponse.json>
+pub struct DescribeTransactionsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The state of each transaction.
+    ///
+    /// Versions: 0+
+    pub transaction_states: Vec<DescribedTransaction>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the state of a single transaction.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeTransactionsResponse.json>
+pub struct DescribedTransaction {
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The transactional ID.
+    ///
+    /// Versions: 0+
+    pub transactional_id: String,
+
+    /// The state of the transaction.
+    ///
+    /// Versions: 0+
+    pub transaction_state: TransactionState,
+
+    /// The transaction timeout in milliseconds.
+    ///
+    /// Versions: 0+
+    pub transaction_timeout: Duration,
+
+    /// The time the ongoing transaction started, or None if there is no ongoing transaction.
+    ///
+    /// Versions: 0+
+    pub transaction_start_time: Option<Timestamp>,
+
+    /// The producer ID of the transactional ID.
+    ///
+    /// Versions: 0+
+    pub producer_id: ProducerId,
+
+    /// The producer epoch of the transactional ID.
+    ///
+    /// Versions: 0+
+    pub producer_epoch: i16,
+
+    /// The partitions included in the ongoing transaction.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<TopicData>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The partitions of a topic included in a transaction.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeTransactionsResponse.json>
+pub struct TopicData {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub topic: String,
+
+    /// The partition indexes.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<PartitionIndex>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
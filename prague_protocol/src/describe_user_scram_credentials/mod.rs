@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::DescribeUserScramCredentialsRequest,
+        response::DescribeUserScramCredentialsResponse, DescribeUserScramCredentialsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct DescribeUserScramCredentialsApi;
+
+impl Api for DescribeUserScramCredentialsApi {
+    const KEY: ApiKey = ApiKey::DescribeUserScramCredentials;
+
+    type Request = DescribeUserScramCredentialsRequest;
+    type Response = DescribeUserScramCredentialsResponse;
+}
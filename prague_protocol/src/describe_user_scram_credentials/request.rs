@@ -0,0 +1,45 @@
+//! Includes definitions for requests to
+//! [DescribeUserScramCredentialsApi](super::DescribeUserScramCredentialsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeUserScramCredentialsRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request to describe the SCRAM credentials of users.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeUserScramCredentialsRequest.json>
+pub struct DescribeUserScramCredentialsRequest {
+    /// The users to describe, or None to describe all users.
+    ///
+    /// Versions: 0+
+    pub users: Option<Vec<UserName>>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A user to describe.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeUserScramCredentialsRequest.json>
+pub struct UserName {
+    /// The user name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
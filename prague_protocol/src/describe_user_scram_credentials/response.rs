@@ -0,0 +1,100 @@
+//! Includes definitions for responses from
+//! [DescribeUserScramCredentialsApi](super::DescribeUserScramCredentialsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeUserScramCredentialsResponse.json>
+
+use crate::{error_code::ErrorCode, scram::ScramMechanism, types::prelude::*};
+
+/// A response message associated with
+/// [DescribeUserScramCredentialsRequest](super::request::DescribeUserScramCredentialsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeUserScramCredentialsResponse.json>
+pub struct DescribeUserScramCredentialsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The message-level error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The message-level error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The results for descriptions, one per user.
+    ///
+    /// Versions: 0+
+    pub results: Vec<DescribeUserScramCredentialsResult>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the SCRAM credentials of a single user.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeUserScramCredentialsResponse.json>
+pub struct DescribeUserScramCredentialsResult {
+    /// The user name.
+    ///
+    /// Versions: 0+
+    pub user: String,
+
+    /// The user-level error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The user-level error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The mechanism and related information associated with the user's SCRAM credentials.
+    ///
+    /// Versions: 0+
+    pub credential_infos: Vec<CredentialInfo>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes a single SCRAM credential, without revealing any key material.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/DescribeUserScramCredentialsResponse.json>
+pub struct CredentialInfo {
+    /// The SCRAM mechanism.
+    ///
+    /// Versions: 0+
+    pub mechanism: ScramMechanism,
+
+    /// The number of iterations used in the SCRAM credential.
+    ///
+    /// Versions: 0+
+    pub iterations: i32,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::ElectLeadersRequest, response::ElectLeadersResponse, ElectLeadersApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct ElectLeadersApi;
+
+impl Api for ElectLeadersApi {
+    const KEY: ApiKey = ApiKey::ElectLeaders;
+
+    type Request = ElectLeadersRequest;
+    type Response = ElectLeadersResponse;
+}
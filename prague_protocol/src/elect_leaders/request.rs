@@ -0,0 +1,90 @@
+//! Includes definitions for requests to [ElectLeadersApi](super::ElectLeadersApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ElectLeadersRequest.json>
+
+use crate::types::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Election type `{0}` is not valid")]
+    InvalidElectionType(i8),
+}
+
+/// A request to elect new leaders for partitions.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ElectLeadersRequest.json>
+pub struct ElectLeadersRequest {
+    /// The type of elections to conduct. Versions before 1 only support
+    /// [ElectionType::Preferred].
+    ///
+    /// Versions: 1+
+    pub election_type: ElectionType,
+
+    /// The topic partitions to elect leaders for, or None to elect leaders for all partitions.
+    ///
+    /// Versions: 0+
+    pub topic_partitions: Option<Vec<TopicPartitions>>,
+
+    /// The time in milliseconds to wait for the election to complete.
+    ///
+    /// Versions: 0+
+    pub timeout: Duration,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The partitions of a topic to elect leaders for.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ElectLeadersRequest.json>
+pub struct TopicPartitions {
+    /// The name of the topic.
+    ///
+    /// Versions: 0+
+    pub topic: String,
+
+    /// The partitions of this topic whose leader should be elected.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<PartitionIndex>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The kind of leader election to conduct.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/java/org/apache/kafka/common/ElectionType.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElectionType {
+    /// Elects the preferred replica, i.e. the first replica in the assignment, if it is in sync.
+    Preferred,
+
+    /// Elects any live replica if there are no in-sync replicas, which may lose data.
+    Unclean,
+}
+
+impl TryFrom<i8> for ElectionType {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Preferred),
+            1 => Ok(Self::Unclean),
+            code => Err(Error::InvalidElectionType(code)),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Includes definitions for responses from [ElectLeadersApi](super::ElectLeadersApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ElectLeadersResponse.json>
+
+use crate::{error_code::ErrorCode, types::prelude::*};
+
+/// A response message associated with [ElectLeadersRequest](super::request::ElectLeadersRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ElectLeadersResponse.json>
+pub struct ElectLeadersResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The top level response error code, or None if there was no error.
+    ///
+    /// Versions: 1+
+    pub error_code: Option<ErrorCode>,
+
+    /// The election results for each topic.
+    ///
+    /// Versions: 0+
+    pub replica_election_results: Vec<ReplicaElectionResult>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the results of the elections for a topic's partitions.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ElectLeadersResponse.json>
+pub struct ReplicaElectionResult {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub topic: String,
+
+    /// The results for each partition.
+    ///
+    /// Versions: 0+
+    pub partition_results: Vec<PartitionResult>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the result of the election for a single partition.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ElectLeadersResponse.json>
+pub struct PartitionResult {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub index: PartitionIndex,
+
+    /// The result error, or None if the election succeeded.
+    /// [ElectionNotNeeded](crate::error_code::ErrorCode::ElectionNotNeeded) means that the
+    /// partition already had the desired leader.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The result message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::EndQuorumEpochRequest, response::EndQuorumEpochResponse, EndQuorumEpochApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct EndQuorumEpochApi;
+
+impl Api for EndQuorumEpochApi {
+    const KEY: ApiKey = ApiKey::EndQuorumEpoch;
+
+    type Request = EndQuorumEpochRequest;
+    type Response = EndQuorumEpochResponse;
+}
@@ -0,0 +1,122 @@
+//! Includes definitions for requests to [EndQuorumEpochApi](super::EndQuorumEpochApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/EndQuorumEpochRequest.json>
+
+use crate::{raft::Listener, types::prelude::*};
+
+/// A request from a leader which is resigning, so that the voters can elect a new leader sooner.
+///
+/// This is only sent between controllers. See [raft](crate::raft) for details.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/EndQuorumEpochRequest.json>
+pub struct EndQuorumEpochRequest {
+    /// The ID of the cluster, or None if not known.
+    ///
+    /// Versions: 0+
+    pub cluster_id: Option<String>,
+
+    /// The topics whose leadership is changing.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<EndQuorumEpochTopic>,
+
+    /// The endpoints of the leader.
+    ///
+    /// Versions: 1+
+    pub leader_endpoints: Vec<Listener>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A topic whose leadership is changing.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/EndQuorumEpochRequest.json>
+pub struct EndQuorumEpochTopic {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub topic_name: String,
+
+    /// The partitions whose leadership is changing.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<EndQuorumEpochPartition>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A partition whose leadership is changing.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/EndQuorumEpochRequest.json>
+pub struct EndQuorumEpochPartition {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub partition_index: PartitionIndex,
+
+    /// The ID of the resigning leader.
+    ///
+    /// Versions: 0+
+    pub leader_id: BrokerId,
+
+    /// The epoch of the resigning leader.
+    ///
+    /// Versions: 0+
+    pub leader_epoch: Epoch,
+
+    /// The IDs of the replicas the leader would prefer to succeed it, in order of preference.
+    ///
+    /// Versions: 0
+    pub preferred_successors: Vec<BrokerId>,
+
+    /// The replicas the leader would prefer to succeed it, in order of preference.
+    ///
+    /// Versions: 1+
+    pub preferred_candidates: Vec<ReplicaInfo>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Identifies a replica the leader would prefer to succeed it.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/EndQuorumEpochRequest.json>
+pub struct ReplicaInfo {
+    /// The ID of the replica.
+    ///
+    /// Versions: 1+
+    pub candidate_id: BrokerId,
+
+    /// The directory ID of the replica.
+    ///
+    /// Versions: 1+
+    pub candidate_directory_id: Uuid,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
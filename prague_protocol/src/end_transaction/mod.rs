@@ -0,0 +1,28 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::EndTransactionRequest, response::EndTransactionResponse, EndTransactionApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct EndTransactionApi;
+
+impl Api for EndTransactionApi {
+    const KEY: ApiKey = ApiKey::EndTransaction;
+
+    type Request = EndTransactionRequest;
+    type Response = EndTransactionResponse;
+}
+
+impl VersionedApi for EndTransactionApi {
+    /// Version 4 only adds an error code, and version 5 the bumped epoch to the response.
+    const MIN_VERSION: i16 = 0;
+    const MAX_VERSION: i16 = 3;
+    const FLEXIBLE_VERSION: Option<i16> = Some(3);
+}
@@ -0,0 +1,55 @@
+//! Includes definitions for requests to [EndTransactionApi](super::EndTransactionApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/EndTxnRequest.json>
+
+use crate::{
+    codec::{Encode, Error, Writer},
+    types::prelude::*,
+};
+
+/// A request to commit or abort the ongoing transaction of a producer.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/EndTxnRequest.json>
+pub struct EndTransactionRequest {
+    /// The ID of the transaction to end.
+    ///
+    /// Versions: 0+
+    pub transactional_id: String,
+
+    /// The producer ID.
+    ///
+    /// Versions: 0+
+    pub producer_id: ProducerId,
+
+    /// The current epoch associated with the producer.
+    ///
+    /// Versions: 0+
+    pub producer_epoch: i16,
+
+    /// True if the transaction was committed, false if it was aborted.
+    ///
+    /// Versions: 0+
+    pub committed: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Encode for EndTransactionRequest {
+    fn encode(&self, writer: &mut Writer, _version: i16) -> Result<(), Error> {
+        writer.string(&self.transactional_id);
+        writer.i64(self.producer_id.0);
+        writer.i16(self.producer_epoch);
+        writer.bool(self.committed);
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+//! Includes definitions for responses from [EndTransactionApi](super::EndTransactionApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/EndTxnResponse.json>
+
+use crate::{
+    codec::{Decode, Error, Reader},
+    error_code::ErrorCode,
+    types::prelude::*,
+};
+
+/// A response message associated with [EndTransactionRequest](super::request::EndTransactionRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/EndTxnResponse.json>
+pub struct EndTransactionResponse {
+    /// The duration in milliseconds for which the request was throttled due to a quota violation,
+    /// or None if the request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Decode for EndTransactionResponse {
+    fn decode(reader: &mut Reader, _version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            throttle_time: reader.throttle_time()?,
+            error_code: reader.error_code()?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
@@ -9,6 +9,7 @@ pub enum Error {
 /// Numeric error codes that indicate what problem occurred on the server.
 ///
 /// See: <https://kafka.apache.org/protocol.html#protocol_error_codes>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ErrorCode {
     /// The server experienced an unexpected error when processing the request.
     UnknownServerError,
@@ -389,9 +390,7 @@ pub enum ErrorCode {
     UnsupportedAssignor,
 
     /// The member epoch is stale. The member must retry after receiving its updated member epoch
-    /// via the ConsumerGroupHeartbeat API.
-    ///
-    /// TODO: `ConsumerGroupHeartbeat`
+    /// via the [`ConsumerGroupHeartbeatApi`](crate::consumer_group_heartbeat::ConsumerGroupHeartbeatApi).
     StaleMemberEpoch,
 
     /// The request was sent to an endpoint of the wrong type.
@@ -542,6 +541,7 @@ impl ErrorCode {
     }
 
     /// Returns whether the operation this error code is associated with is retriable.
+    #[allow(clippy::match_like_matches_macro)]
     pub fn is_retriable(&self) -> bool {
         match self {
             Self::CorruptMessage
@@ -576,4 +576,23 @@ impl ErrorCode {
             _ => false,
         }
     }
+
+    /// Returns whether this error code means the client's cached metadata is stale, such that
+    /// it should refresh its metadata before retrying.
+    pub fn invalidates_metadata(&self) -> bool {
+        matches!(
+            self,
+            Self::UnknownTopicOrPartition
+                | Self::LeaderNotAvailable
+                | Self::NotLeaderOrFollower
+                | Self::BrokerNotAvailable
+                | Self::ReplicaNotAvailable
+                | Self::KafkaStorageError
+                | Self::ListenerNotFound
+                | Self::FencedLeaderEpoch
+                | Self::UnknownLeaderEpoch
+                | Self::UnknownTopicId
+                | Self::InconsistentTopicId
+        )
+    }
 }
@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::ExpireDelegationTokenRequest, response::ExpireDelegationTokenResponse,
+        ExpireDelegationTokenApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct ExpireDelegationTokenApi;
+
+impl Api for ExpireDelegationTokenApi {
+    const KEY: ApiKey = ApiKey::ExpireDelegationToken;
+
+    type Request = ExpireDelegationTokenRequest;
+    type Response = ExpireDelegationTokenResponse;
+}
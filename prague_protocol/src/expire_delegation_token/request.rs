@@ -0,0 +1,33 @@
+//! Includes definitions for requests to
+//! [ExpireDelegationTokenApi](super::ExpireDelegationTokenApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ExpireDelegationTokenRequest.json>
+
+use crate::{delegation_token::DelegationTokenHmac, types::prelude::*};
+
+/// A request to expire a delegation token, or to change when it expires.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ExpireDelegationTokenRequest.json>
+pub struct ExpireDelegationTokenRequest {
+    /// The HMAC of the token.
+    ///
+    /// Versions: 0+
+    pub hmac: DelegationTokenHmac,
+
+    /// How long in milliseconds from now the token should expire. A negative period expires the
+    /// token immediately.
+    ///
+    /// Versions: 0+
+    pub expiry_time_period: i64,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
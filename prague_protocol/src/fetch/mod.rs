@@ -3,9 +3,12 @@ use crate::api::prelude::*;
 
 pub mod request;
 pub mod response;
+pub mod session;
 
 pub mod prelude {
-    pub use super::{request::FetchRequest, response::FetchResponse, FetchApi};
+    pub use super::{
+        request::FetchRequest, response::FetchResponse, session::FetchSessionHandler, FetchApi,
+    };
 }
 
 /// See: <https://kafka.apache.org/protocol.html#protocol_messages>
@@ -17,3 +20,9 @@ impl Api for FetchApi {
     type Request = FetchRequest;
     type Response = FetchResponse;
 }
+
+impl VersionedApi for FetchApi {
+    const MIN_VERSION: i16 = FetchRequest::MIN_VERSION;
+    const MAX_VERSION: i16 = FetchRequest::MAX_VERSION;
+    const FLEXIBLE_VERSION: Option<i16> = Some(12);
+}
@@ -1,86 +1,440 @@
-use crate::{isolation_level::IsolationLevel, types::prelude::*};
+use crate::{
+    codec::{self, Encode, Writer},
+    isolation_level::IsolationLevel,
+    types::prelude::*,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Fetch version `{0}` is not supported")]
+    UnsupportedVersion(i16),
+
+    #[error("Fetch version `{version}` identifies topics by name, but `{topic:?}` was given")]
+    TopicNameRequired { version: i16, topic: TopicRef },
+
+    #[error("Fetch version `{version}` identifies topics by ID, but `{topic:?}` was given")]
+    TopicIdRequired { version: i16, topic: TopicRef },
+
+    #[error("Fetch version `{0}` does not support the read committed isolation level")]
+    IsolationLevelUnsupported(i16),
+
+    #[error("Fetch version `{0}` does not support fetch sessions")]
+    FetchSessionsUnsupported(i16),
+}
 
 /// See: <https://kafka.apache.org/protocol.html#protocol_messages>
 pub struct FetchRequest {
+    /// The ID of the cluster, or None if not known.
+    ///
+    /// Versions: 12+
+    pub cluster_id: Option<String>,
+
+    /// The ID of the follower replica making this request, or None if this request is made by a
+    /// consumer.
+    ///
+    /// Versions: 0+
+    pub replica_id: Option<BrokerId>,
+
     /// The maximum time in milliseconds to wait for the response.
+    ///
+    /// Versions: 0+
     pub max_wait: Duration,
 
     /// The minimum number of bytes to accumulate in the response.
+    ///
+    /// Versions: 0+
     pub min_bytes: i32,
 
     /// The maximum number of bytes to fetch.
     ///
     /// See KIP-74 for cases where this limit may not be honored.
+    ///
+    /// Versions: 3+
     pub max_bytes: i32,
 
     /// Controls the visibility of transactional records.
+    ///
+    /// Versions: 4+
     pub isolation_level: IsolationLevel,
 
-    /// The fetch session's ID.
+    /// The fetch session's ID, or 0 if this request is not part of a fetch session.
+    ///
+    /// Versions: 7+
     pub session_id: i32,
 
     /// The fetch session's epoch, which is used for ordering requests in a session.
+    ///
+    /// Versions: 7+
     pub session_epoch: Epoch,
 
     /// The list of topics to fetch.
+    ///
+    /// Versions: 0+
     pub topics: Vec<Topic>,
 
     /// The list of topics to forget.
+    ///
+    /// Versions: 7+
     pub topics_to_forget: Vec<TopicToForget>,
 
     /// The rack ID of the consumer making this request.
+    ///
+    /// Versions: 11+
     pub rack_id: String,
 
     /// This fetch request's tagged fields.
+    ///
+    /// Versions: 12+
     pub tagged_fields: TaggedFields,
 }
 
+impl FetchRequest {
+    /// The oldest supported version.
+    pub const MIN_VERSION: i16 = 0;
+
+    /// The newest supported version.
+    pub const MAX_VERSION: i16 = 17;
+
+    /// The first version which identifies topics by ID rather than by name.
+    pub const TOPIC_ID_VERSION: i16 = 13;
+
+    /// The session ID sent when a request is not part of a fetch session.
+    pub const INVALID_SESSION_ID: i32 = 0;
+
+    /// The session epoch which creates a new fetch session.
+    pub const INITIAL_EPOCH: Epoch = Epoch(0);
+
+    /// The session epoch which closes a fetch session, or performs a full fetch without one.
+    pub const FINAL_EPOCH: Epoch = Epoch(-1);
+
+    /// Returns whether topics are identified by ID in the given version, rather than by name.
+    pub fn uses_topic_ids(version: i16) -> bool {
+        version >= Self::TOPIC_ID_VERSION
+    }
+
+    /// Checks that this request can be sent with the given version.
+    ///
+    /// Fields which older versions cannot represent are only rejected if dropping them would
+    /// change the meaning of the request. For example, a rack ID is silently omitted before
+    /// version 11, but topics identified by ID cannot be fetched before version 13.
+    pub fn validate(&self, version: i16) -> Result<(), Error> {
+        if !(Self::MIN_VERSION..=Self::MAX_VERSION).contains(&version) {
+            return Err(Error::UnsupportedVersion(version));
+        }
+
+        if version < 4 && self.isolation_level == IsolationLevel::ReadCommitted {
+            return Err(Error::IsolationLevelUnsupported(version));
+        }
+
+        let uses_session = self.session_id != Self::INVALID_SESSION_ID
+            || self.session_epoch != Self::FINAL_EPOCH
+            || !self.topics_to_forget.is_empty();
+        if version < 7 && uses_session {
+            return Err(Error::FetchSessionsUnsupported(version));
+        }
+
+        self.topics
+            .iter()
+            .map(|topic| &topic.topic)
+            .chain(self.topics_to_forget.iter().map(|topic| &topic.topic))
+            .try_for_each(|topic| validate_topic(topic, version))
+    }
+}
+
+impl Encode for FetchRequest {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), codec::Error> {
+        self.validate(version)?;
+
+        let replica_id = self.replica_id.map_or(-1, |replica_id| replica_id.0);
+        if version < 15 {
+            writer.i32(replica_id);
+        }
+
+        writer.i32(self.max_wait.0);
+        writer.i32(self.min_bytes);
+
+        if version >= 3 {
+            writer.i32(self.max_bytes);
+        }
+
+        if version >= 4 {
+            writer.i8(self.isolation_level.into());
+        }
+
+        if version >= 7 {
+            writer.i32(self.session_id);
+            writer.i32(self.session_epoch.0);
+        }
+
+        writer.array(&self.topics, |writer, topic| topic.encode(writer, version))?;
+
+        if version >= 7 {
+            writer.array(&self.topics_to_forget, |writer, topic| {
+                topic.encode(writer, version)
+            })?;
+        }
+
+        if version >= 11 {
+            writer.string(&self.rack_id);
+        }
+
+        let mut tagged = vec![];
+        if let Some(cluster_id) = &self.cluster_id {
+            tagged.push(Writer::tagged(0, |writer| {
+                writer.nullable_string(Some(cluster_id))
+            }));
+        }
+        // From version 15, the replica is described by a tagged field, which consumers omit.
+        if version >= 15 && self.replica_id.is_some() {
+            tagged.push(Writer::tagged(1, |writer| {
+                writer.i32(replica_id);
+                writer.i64(-1);
+                writer.tagged_fields(&Default::default(), vec![]);
+            }));
+        }
+        writer.tagged_fields(&self.tagged_fields, tagged);
+
+        Ok(())
+    }
+}
+
+/// Writes a topic's name before version 13, or its ID from version 13.
+fn encode_topic(writer: &mut Writer, topic: &TopicRef) {
+    match topic {
+        TopicRef::Name(name) => writer.string(name),
+        TopicRef::Id(id) => writer.uuid(*id),
+    }
+}
+
+/// Checks that a topic is identified by name or ID as appropriate for the given version.
+fn validate_topic(topic: &TopicRef, version: i16) -> Result<(), Error> {
+    match (topic, FetchRequest::uses_topic_ids(version)) {
+        (TopicRef::Name(_), false) | (TopicRef::Id(_), true) => Ok(()),
+        (TopicRef::Id(_), false) => Err(Error::TopicNameRequired {
+            version,
+            topic: topic.clone(),
+        }),
+        (TopicRef::Name(_), true) => Err(Error::TopicIdRequired {
+            version,
+            topic: topic.clone(),
+        }),
+    }
+}
+
 /// See: <https://kafka.apache.org/protocol.html#protocol_messages>
 pub struct Topic {
-    /// This topic's unique ID.
-    pub id: Uuid,
+    /// This topic's name before version 13, or its unique ID from version 13.
+    ///
+    /// Versions: 0+
+    pub topic: TopicRef,
 
     /// The list of partitions to fetch.
+    ///
+    /// Versions: 0+
     pub partitions: Vec<Partition>,
 
     /// This topic's tagged fields.
+    ///
+    /// Versions: 12+
     pub tagged_fields: TaggedFields,
 }
 
 /// See: <https://kafka.apache.org/protocol.html#protocol_messages>
 pub struct Partition {
     /// This partition's index.
+    ///
+    /// Versions: 0+
     pub index: PartitionIndex,
 
-    /// The epoch of the current leader for this partition.
-    pub current_leader_epoch: Epoch,
+    /// The epoch of the current leader for this partition, or None if not known.
+    ///
+    /// Versions: 9+
+    pub current_leader_epoch: Option<Epoch>,
 
     /// The message offset.
+    ///
+    /// Versions: 0+
     pub fetch_offset: Offset,
 
     /// If previously fetched, the epoch of the last fetched record, otherwise None.
+    ///
+    /// Versions: 12+
     pub last_fetched_epoch: Option<Epoch>,
 
-    /// The earliest available offset of the follower replica.
-    pub log_start_offset: Offset,
+    /// The earliest available offset of the follower replica, or None if this request is made by
+    /// a consumer.
+    ///
+    /// Versions: 5+
+    pub log_start_offset: Option<Offset>,
 
     /// The maximum number of bytes to fetch from this partition.
     ///
     /// See KIP-74 for cases where this limit may not be honored.
+    ///
+    /// Versions: 0+
     pub max_bytes: i32,
 
+    /// The directory ID of the follower replica making this request, or [Uuid::ZERO] if this
+    /// request is made by a consumer.
+    ///
+    /// Versions: 17+
+    pub replica_directory_id: Uuid,
+
     /// This partition's tagged fields.
+    ///
+    /// Versions: 12+
     pub tagged_fields: TaggedFields,
 }
 
 /// See: <https://kafka.apache.org/protocol.html#protocol_messages>
 pub struct TopicToForget {
-    /// This topic's unique ID.
-    pub id: Uuid,
+    /// This topic's name before version 13, or its unique ID from version 13.
+    ///
+    /// Versions: 7+
+    pub topic: TopicRef,
 
     /// The indices of the partitions to forget.
+    ///
+    /// Versions: 7+
     pub partition_indices: Vec<PartitionIndex>,
 
     /// This forgotten topic's tagged fields.
+    ///
+    /// Versions: 12+
     pub tagged_fields: TaggedFields,
 }
+
+impl Encode for Topic {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), codec::Error> {
+        encode_topic(writer, &self.topic);
+        writer.array(&self.partitions, |writer, partition| {
+            partition.encode(writer, version)
+        })?;
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
+
+impl Encode for Partition {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), codec::Error> {
+        writer.i32(self.index.0);
+
+        if version >= 9 {
+            writer.i32(self.current_leader_epoch.map_or(-1, |epoch| epoch.0));
+        }
+
+        writer.i64(self.fetch_offset.0);
+
+        if version >= 12 {
+            writer.i32(self.last_fetched_epoch.map_or(-1, |epoch| epoch.0));
+        }
+
+        if version >= 5 {
+            writer.i64(self.log_start_offset.map_or(-1, |offset| offset.0));
+        }
+
+        writer.i32(self.max_bytes);
+
+        let mut tagged = vec![];
+        if version >= 17 && self.replica_directory_id != Uuid::ZERO {
+            tagged.push(Writer::tagged(0, |writer| {
+                writer.uuid(self.replica_directory_id)
+            }));
+        }
+        writer.tagged_fields(&self.tagged_fields, tagged);
+
+        Ok(())
+    }
+}
+
+impl Encode for TopicToForget {
+    fn encode(&self, writer: &mut Writer, _version: i16) -> Result<(), codec::Error> {
+        encode_topic(writer, &self.topic);
+        writer.array(&self.partition_indices, |writer, index| {
+            writer.i32(index.0);
+            Ok(())
+        })?;
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(topic: TopicRef) -> FetchRequest {
+        FetchRequest {
+            cluster_id: None,
+            replica_id: None,
+            max_wait: Duration(500),
+            min_bytes: 1,
+            max_bytes: 1024,
+            isolation_level: IsolationLevel::ReadCommitted,
+            session_id: FetchRequest::INVALID_SESSION_ID,
+            session_epoch: FetchRequest::FINAL_EPOCH,
+            topics: vec![Topic {
+                topic,
+                partitions: vec![Partition {
+                    index: PartitionIndex(0),
+                    current_leader_epoch: None,
+                    fetch_offset: Offset(5),
+                    last_fetched_epoch: None,
+                    log_start_offset: None,
+                    max_bytes: 100,
+                    replica_directory_id: Uuid::ZERO,
+                    tagged_fields: Default::default(),
+                }],
+                tagged_fields: Default::default(),
+            }],
+            topics_to_forget: vec![],
+            rack_id: String::new(),
+            tagged_fields: Default::default(),
+        }
+    }
+
+    #[test]
+    fn encode() {
+        let mut writer = Writer::new(false);
+        request(TopicRef::Name("t".to_owned()))
+            .encode(&mut writer, 7)
+            .unwrap();
+
+        let expected: &[u8] = &[
+            0xff, 0xff, 0xff, 0xff, // replica ID
+            0x00, 0x00, 0x01, 0xf4, // max wait
+            0x00, 0x00, 0x00, 0x01, // min bytes
+            0x00, 0x00, 0x04, 0x00, // max bytes
+            0x01, // isolation level
+            0x00, 0x00, 0x00, 0x00, // session ID
+            0xff, 0xff, 0xff, 0xff, // session epoch
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x74, // topics
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, // partitions
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, // fetch offset
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // log start offset
+            0x00, 0x00, 0x00, 0x64, // partition max bytes
+            0x00, 0x00, 0x00, 0x00, // topics to forget
+        ];
+        assert_eq!(writer.into_inner(), expected);
+    }
+
+    #[test]
+    fn encode_validates() {
+        let mut writer = Writer::new(true);
+        let error = request(TopicRef::Name("t".to_owned()))
+            .encode(&mut writer, 13)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            codec::Error::Fetch(Error::TopicIdRequired { version: 13, .. })
+        ));
+
+        let error = request(TopicRef::Name("t".to_owned()))
+            .encode(&mut Writer::new(false), 3)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            codec::Error::Fetch(Error::IsolationLevelUnsupported(3))
+        ));
+    }
+}
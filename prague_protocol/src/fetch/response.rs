@@ -1,4 +1,9 @@
-use crate::{error_code::ErrorCode, records::Records, types::prelude::*};
+use crate::{
+    codec::{Decode, Error, Reader},
+    error_code::ErrorCode,
+    records::Records,
+    types::prelude::*,
+};
 
 /// See: <https://kafka.apache.org/protocol.html#protocol_messages>
 pub struct FetchResponse {
@@ -15,14 +20,18 @@ pub struct FetchResponse {
     /// The list of responses from each topic.
     pub topic_responses: Vec<Topic>,
 
+    /// The brokers named as the new leader of a partition in
+    /// [current_leader](Partition::current_leader), from version 16.
+    pub node_endpoints: Vec<NodeEndpoint>,
+
     /// This fetch response's tagged fields.
     pub tagged_fields: TaggedFields,
 }
 
 /// See: <https://kafka.apache.org/protocol.html#protocol_messages>
 pub struct Topic {
-    /// This topic's unique ID.
-    pub id: Uuid,
+    /// This topic's name before version 13, or its unique ID from version 13.
+    pub topic: TopicRef,
 
     /// The responses from this topic's partitions.
     pub partitions: Vec<Partition>,
@@ -56,8 +65,14 @@ pub struct Partition {
     /// The preferred read replica for the consumer to use on its next fetch request.
     pub preferred_read_replica: BrokerId,
 
-    /// The fetched record data.
-    pub records: Records,
+    /// The fetched record batches. A trailing batch which the broker cut short is dropped.
+    pub records: Vec<Records>,
+
+    /// If this partition failed with
+    /// [NotLeaderOrFollower](ErrorCode::NotLeaderOrFollower) or
+    /// [FencedLeaderEpoch](ErrorCode::FencedLeaderEpoch), the leader the broker knows of, from
+    /// version 12. Otherwise, None.
+    pub current_leader: Option<LeaderIdAndEpoch>,
 
     /// This partition response's tagged fields.
     pub tagged_fields: TaggedFields,
@@ -74,3 +89,216 @@ pub struct AbortedTransaction {
     /// This aborted transaction's tagged fields.
     pub tagged_fields: TaggedFields,
 }
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct LeaderIdAndEpoch {
+    /// The ID of the current leader, or None if it is not known.
+    pub leader_id: Option<BrokerId>,
+
+    /// The latest known leader epoch, or None if it is not known.
+    pub leader_epoch: Option<Epoch>,
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct NodeEndpoint {
+    /// This broker's ID.
+    pub node_id: BrokerId,
+
+    /// This broker's hostname.
+    pub host: String,
+
+    /// This broker's port.
+    pub port: i32,
+
+    /// This broker's rack, if it has been assigned to one. Otherwise, None.
+    pub rack: Option<String>,
+}
+
+impl Decode for FetchResponse {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        let throttle_time = match version >= 1 {
+            true => reader.throttle_time()?,
+            false => None,
+        };
+        let (error_code, session_id) = match version >= 7 {
+            true => (
+                reader.error_code()?,
+                Some(reader.i32()?).filter(|session_id| *session_id != 0),
+            ),
+            false => (None, None),
+        };
+        let topic_responses = reader.array("responses", |reader| Topic::decode(reader, version))?;
+
+        let mut tagged_fields = reader.tagged_fields()?;
+        let node_endpoints = Reader::take_tagged(&mut tagged_fields, 0, |reader| {
+            reader.array("node_endpoints", NodeEndpoint::decode_one)
+        })?
+        .unwrap_or_default();
+
+        Ok(Self {
+            throttle_time,
+            error_code,
+            session_id,
+            topic_responses,
+            node_endpoints,
+            tagged_fields,
+        })
+    }
+}
+
+impl Decode for Topic {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        let topic = match version >= 13 {
+            true => TopicRef::Id(reader.uuid()?),
+            false => TopicRef::Name(reader.string("topic")?),
+        };
+
+        Ok(Self {
+            topic,
+            partitions: reader.array("partitions", |reader| Partition::decode(reader, version))?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+impl Decode for Partition {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        let index = PartitionIndex(reader.i32()?);
+        let error_code = reader.error_code()?;
+        let high_watermark = Offset(reader.i64()?);
+        let last_stable_offset = match version >= 4 {
+            true => Offset(reader.i64()?),
+            false => Offset(-1),
+        };
+        let log_start_offset = match version >= 5 {
+            true => Offset(reader.i64()?),
+            false => Offset(-1),
+        };
+        let aborted_transactions = match version >= 4 {
+            true => reader
+                .nullable_array(AbortedTransaction::decode_one)?
+                .unwrap_or_default(),
+            false => vec![],
+        };
+        let preferred_read_replica = match version >= 11 {
+            true => BrokerId(reader.i32()?),
+            false => BrokerId(-1),
+        };
+        let records = match reader.nullable_bytes()? {
+            Some(records) => Records::from_bytes(&records)?,
+            None => vec![],
+        };
+
+        let mut tagged_fields = reader.tagged_fields()?;
+        let current_leader = Reader::take_tagged(&mut tagged_fields, 1, |reader| {
+            let leader = LeaderIdAndEpoch {
+                leader_id: Some(BrokerId(reader.i32()?)).filter(|id| id.0 >= 0),
+                leader_epoch: Some(Epoch(reader.i32()?)).filter(|epoch| epoch.0 >= 0),
+            };
+            reader.tagged_fields()?;
+            Ok(leader)
+        })?;
+
+        Ok(Self {
+            index,
+            error_code,
+            high_watermark,
+            last_stable_offset,
+            log_start_offset,
+            aborted_transactions,
+            preferred_read_replica,
+            records,
+            current_leader,
+            tagged_fields,
+        })
+    }
+}
+
+impl AbortedTransaction {
+    fn decode_one(reader: &mut Reader) -> Result<Self, Error> {
+        Ok(Self {
+            producer_id: ProducerId(reader.i64()?),
+            first_offset: Offset(reader.i64()?),
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+impl NodeEndpoint {
+    fn decode_one(reader: &mut Reader) -> Result<Self, Error> {
+        let node = Self {
+            node_id: BrokerId(reader.i32()?),
+            host: reader.string("host")?,
+            port: reader.i32()?,
+            rack: reader.nullable_string()?,
+        };
+        reader.tagged_fields()?;
+        Ok(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::Writer;
+
+    #[test]
+    fn decode_leader_hints() {
+        let topic_id = Uuid(42);
+        let mut writer = Writer::new(true);
+        writer.i32(0);
+        writer.i16(0);
+        writer.i32(5);
+
+        writer.unsigned_varint(2);
+        writer.uuid(topic_id);
+        writer.unsigned_varint(2);
+        writer.i32(0);
+        writer.i16(6);
+        writer.i64(-1);
+        writer.i64(-1);
+        writer.i64(-1);
+        writer.unsigned_varint(0);
+        writer.i32(-1);
+        writer.nullable_bytes(None);
+        let current_leader = Writer::tagged(1, |writer| {
+            writer.i32(2);
+            writer.i32(6);
+            writer.tagged_fields(&Default::default(), vec![]);
+        });
+        writer.tagged_fields(&Default::default(), vec![current_leader]);
+        writer.tagged_fields(&Default::default(), vec![]);
+
+        let node_endpoints = Writer::tagged(0, |writer| {
+            writer.unsigned_varint(2);
+            writer.i32(2);
+            writer.string("broker-2");
+            writer.i32(9093);
+            writer.nullable_string(None);
+            writer.tagged_fields(&Default::default(), vec![]);
+        });
+        writer.tagged_fields(&Default::default(), vec![node_endpoints]);
+
+        let bytes = writer.into_inner();
+        let response = FetchResponse::decode(&mut Reader::new(&bytes, true), 16).unwrap();
+
+        assert_eq!(response.session_id, Some(5));
+        assert_eq!(response.topic_responses[0].topic, TopicRef::Id(topic_id));
+
+        let partition = &response.topic_responses[0].partitions[0];
+        assert_eq!(partition.error_code, Some(ErrorCode::NotLeaderOrFollower));
+        assert!(partition.records.is_empty());
+        assert!(partition.tagged_fields.fields.is_empty());
+
+        let leader = partition.current_leader.as_ref().unwrap();
+        assert_eq!(leader.leader_id, Some(BrokerId(2)));
+        assert_eq!(leader.leader_epoch, Some(Epoch(6)));
+
+        let node = &response.node_endpoints[0];
+        assert_eq!(
+            (node.node_id, node.host.as_str(), node.port),
+            (BrokerId(2), "broker-2", 9093)
+        );
+        assert!(response.tagged_fields.fields.is_empty());
+    }
+}
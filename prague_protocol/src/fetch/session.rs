@@ -0,0 +1,589 @@
+//! Includes a handler for incremental fetch sessions, as used by [FetchApi](super::FetchApi).
+//!
+//! Without a session, every fetch request lists every partition being fetched. With a session,
+//! the broker remembers the partitions from previous requests, so that each request only lists the
+//! partitions which were added or changed and the partitions to forget. Likewise, the broker only
+//! returns partitions which have new data or metadata.
+//!
+//! ## See also
+//!
+//! - <https://cwiki.apache.org/confluence/display/KAFKA/KIP-227%3A+Introduce+Incremental+FetchRequests+to+Increase+Partition+Scalability>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/java/org/apache/kafka/clients/FetchSessionHandler.java>
+
+use std::collections::HashMap;
+
+use super::{
+    request::{FetchRequest, Partition, Topic, TopicToForget},
+    response::FetchResponse,
+};
+use crate::{error_code::ErrorCode, types::prelude::*};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Fetch session `{session_id}` failed with `{error_code:?}`")]
+    SessionFailed {
+        session_id: i32,
+        error_code: ErrorCode,
+    },
+
+    #[error("Fetch response included partitions which are not part of the session: {0:?}")]
+    UnexpectedPartitions(Vec<(TopicRef, PartitionIndex)>),
+}
+
+/// What the client requests for a partition in a fetch session. A partition is resent in an
+/// incremental request whenever any of these change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionPartition {
+    /// The offset to fetch from.
+    pub fetch_offset: Offset,
+
+    /// The epoch of the current leader, or None if not known.
+    pub current_leader_epoch: Option<Epoch>,
+
+    /// The epoch of the last fetched record, or None if nothing has been fetched.
+    pub last_fetched_epoch: Option<Epoch>,
+
+    /// The earliest available offset of the follower replica, or None for consumers.
+    pub log_start_offset: Option<Offset>,
+
+    /// The maximum number of bytes to fetch from this partition.
+    pub max_bytes: i32,
+}
+
+/// The latest state of a partition, as merged from every response in the session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartitionState {
+    /// The current high watermark.
+    pub high_watermark: Offset,
+
+    /// The last stable offset.
+    pub last_stable_offset: Offset,
+
+    /// The current log start offset.
+    pub log_start_offset: Offset,
+
+    /// The replica the broker would prefer the client to fetch from, or None if it should keep
+    /// fetching from the leader.
+    pub preferred_read_replica: Option<BrokerId>,
+}
+
+/// Identifies a partition within a session.
+type PartitionKey = (TopicRef, PartitionIndex);
+
+/// Tracks a single fetch session with a single broker.
+///
+/// Before each fetch, [build](FetchSessionHandler::build) fills in the session fields of a
+/// [FetchRequest] from the partitions the client wants. Each response is then passed to
+/// [handle_response](FetchSessionHandler::handle_response), which advances the session and merges
+/// the returned partitions into [partition_states](FetchSessionHandler::partition_states). Only
+/// one request per session may be in flight at a time.
+///
+/// If the broker loses the session, rejects the epoch, or returns partitions it should not have,
+/// the handler falls back to a full fetch which creates a new session, like the Java client.
+#[derive(Debug)]
+pub struct FetchSessionHandler {
+    session_id: i32,
+    epoch: Epoch,
+    partitions: HashMap<PartitionKey, SessionPartition>,
+    states: HashMap<PartitionKey, PartitionState>,
+}
+
+impl Default for FetchSessionHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FetchSessionHandler {
+    /// The first version which supports fetch sessions.
+    pub const MIN_SESSION_VERSION: i16 = 7;
+
+    /// Creates a handler which will create a new session with its first fetch.
+    pub fn new() -> Self {
+        Self {
+            session_id: FetchRequest::INVALID_SESSION_ID,
+            epoch: FetchRequest::INITIAL_EPOCH,
+            partitions: HashMap::new(),
+            states: HashMap::new(),
+        }
+    }
+
+    /// Returns the ID of the current session, or None if there is no session.
+    pub fn session_id(&self) -> Option<i32> {
+        Some(self.session_id).filter(|id| *id != FetchRequest::INVALID_SESSION_ID)
+    }
+
+    /// Returns the epoch the next request will be sent with.
+    pub fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Returns the partitions the broker believes are in the session.
+    pub fn partitions(&self) -> &HashMap<(TopicRef, PartitionIndex), SessionPartition> {
+        &self.partitions
+    }
+
+    /// Returns the latest state of each partition in the session which the broker has returned.
+    pub fn partition_states(&self) -> &HashMap<(TopicRef, PartitionIndex), PartitionState> {
+        &self.states
+    }
+
+    /// Closes the session with the next request, which will then be a full fetch. The session
+    /// after that is created anew.
+    pub fn close(&mut self) {
+        self.epoch = FetchRequest::FINAL_EPOCH;
+    }
+
+    /// Fills in the session ID, epoch, topics and topics to forget of a request for the given
+    /// partitions.
+    ///
+    /// A full request lists every partition. An incremental request lists only partitions which
+    /// were not in the session or whose [SessionPartition] changed, and forgets partitions which
+    /// are no longer wanted. Topics must be identified as required by the given version.
+    pub fn build(
+        &mut self,
+        request: &mut FetchRequest,
+        wanted: impl IntoIterator<Item = (TopicRef, PartitionIndex, SessionPartition)>,
+        version: i16,
+    ) {
+        let wanted: Vec<_> = wanted.into_iter().collect();
+        let is_full = self.is_full();
+
+        let to_send = wanted
+            .iter()
+            .filter(|(topic, index, partition)| {
+                is_full || self.partitions.get(&(topic.clone(), *index)) != Some(partition)
+            })
+            .map(|(topic, index, partition)| {
+                let partition = Partition {
+                    index: *index,
+                    current_leader_epoch: partition.current_leader_epoch,
+                    fetch_offset: partition.fetch_offset,
+                    last_fetched_epoch: partition.last_fetched_epoch,
+                    log_start_offset: partition.log_start_offset,
+                    max_bytes: partition.max_bytes,
+                    replica_directory_id: Uuid::ZERO,
+                    tagged_fields: Default::default(),
+                };
+                (topic.clone(), partition)
+            });
+
+        request.topics = group_by_topic(to_send)
+            .into_iter()
+            .map(|(topic, partitions)| Topic {
+                topic,
+                partitions,
+                tagged_fields: Default::default(),
+            })
+            .collect();
+
+        let next: HashMap<_, _> = wanted
+            .into_iter()
+            .map(|(topic, index, partition)| ((topic, index), partition))
+            .collect();
+
+        let to_forget = self
+            .partitions
+            .keys()
+            .filter(|key| !is_full && !next.contains_key(key))
+            .cloned();
+
+        request.topics_to_forget = group_by_topic(to_forget)
+            .into_iter()
+            .map(|(topic, partition_indices)| TopicToForget {
+                topic,
+                partition_indices,
+                tagged_fields: Default::default(),
+            })
+            .collect();
+
+        self.partitions = next;
+        self.states
+            .retain(|key, _| self.partitions.contains_key(key));
+
+        (request.session_id, request.session_epoch) = match version {
+            v if v < Self::MIN_SESSION_VERSION => {
+                (FetchRequest::INVALID_SESSION_ID, FetchRequest::FINAL_EPOCH)
+            }
+            _ => (self.session_id, self.epoch),
+        };
+    }
+
+    /// Advances the session from the response to the last request, and merges the returned
+    /// partitions into [partition_states](Self::partition_states).
+    ///
+    /// If the response reports a top-level error, such as
+    /// [FetchSessionIdNotFound](ErrorCode::FetchSessionIdNotFound) or
+    /// [InvalidFetchSessionEpoch](ErrorCode::InvalidFetchSessionEpoch), or returns partitions
+    /// which are not in the session, nothing is merged and the next request will be a full fetch
+    /// which creates a new session.
+    pub fn handle_response(&mut self, response: &FetchResponse) -> Result<(), Error> {
+        if let Some(error_code) = response.error_code {
+            let session_id = self.session_id;
+
+            // If the broker no longer knows the session, there is nothing to close.
+            if error_code == ErrorCode::FetchSessionIdNotFound {
+                self.session_id = FetchRequest::INVALID_SESSION_ID;
+            }
+            self.epoch = FetchRequest::INITIAL_EPOCH;
+
+            return Err(Error::SessionFailed {
+                session_id,
+                error_code,
+            });
+        }
+
+        let unexpected: Vec<_> = response
+            .topic_responses
+            .iter()
+            .flat_map(|topic| {
+                topic
+                    .partitions
+                    .iter()
+                    .map(|partition| (topic.topic.clone(), partition.index))
+            })
+            .filter(|key| !self.partitions.contains_key(key))
+            .collect();
+
+        if !unexpected.is_empty() {
+            self.epoch = FetchRequest::INITIAL_EPOCH;
+            return Err(Error::UnexpectedPartitions(unexpected));
+        }
+
+        for topic in &response.topic_responses {
+            for partition in &topic.partitions {
+                self.states.insert(
+                    (topic.topic.clone(), partition.index),
+                    PartitionState {
+                        high_watermark: partition.high_watermark,
+                        last_stable_offset: partition.last_stable_offset,
+                        log_start_offset: partition.log_start_offset,
+                        preferred_read_replica: Some(partition.preferred_read_replica)
+                            .filter(|replica| replica.0 >= 0),
+                    },
+                );
+            }
+        }
+
+        (self.session_id, self.epoch) = match (self.is_full(), response.session_id) {
+            (_, None) => (
+                FetchRequest::INVALID_SESSION_ID,
+                FetchRequest::INITIAL_EPOCH,
+            ),
+            (true, Some(session_id)) => (session_id, next_epoch(FetchRequest::INITIAL_EPOCH)),
+            (false, Some(session_id)) => (session_id, next_epoch(self.epoch)),
+        };
+
+        Ok(())
+    }
+
+    /// Returns whether the next request will be a full fetch.
+    fn is_full(&self) -> bool {
+        self.epoch == FetchRequest::INITIAL_EPOCH || self.epoch == FetchRequest::FINAL_EPOCH
+    }
+}
+
+/// Returns the epoch which follows the given one, skipping the initial epoch on overflow.
+fn next_epoch(epoch: Epoch) -> Epoch {
+    match epoch.0 {
+        i32::MAX => Epoch(1),
+        epoch => Epoch(epoch + 1),
+    }
+}
+
+/// Groups items by topic, preserving the order in which topics first appear.
+fn group_by_topic<T>(items: impl IntoIterator<Item = (TopicRef, T)>) -> Vec<(TopicRef, Vec<T>)> {
+    let mut groups: Vec<(TopicRef, Vec<T>)> = Vec::new();
+    let mut indices: HashMap<TopicRef, usize> = HashMap::new();
+
+    for (topic, item) in items {
+        match indices.get(&topic) {
+            Some(&index) => groups[index].1.push(item),
+            None => {
+                indices.insert(topic.clone(), groups.len());
+                groups.push((topic, vec![item]));
+            }
+        }
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fetch::response, isolation_level::IsolationLevel};
+
+    fn request() -> FetchRequest {
+        FetchRequest {
+            cluster_id: None,
+            replica_id: None,
+            max_wait: Duration(500),
+            min_bytes: 1,
+            max_bytes: i32::MAX,
+            isolation_level: IsolationLevel::ReadUncommitted,
+            session_id: FetchRequest::INVALID_SESSION_ID,
+            session_epoch: FetchRequest::FINAL_EPOCH,
+            topics: vec![],
+            topics_to_forget: vec![],
+            rack_id: String::new(),
+            tagged_fields: Default::default(),
+        }
+    }
+
+    fn topic(name: &str) -> TopicRef {
+        TopicRef::Name(name.to_owned())
+    }
+
+    fn wanted(
+        name: &str,
+        index: i32,
+        fetch_offset: i64,
+    ) -> (TopicRef, PartitionIndex, SessionPartition) {
+        let partition = SessionPartition {
+            fetch_offset: Offset(fetch_offset),
+            current_leader_epoch: None,
+            last_fetched_epoch: None,
+            log_start_offset: None,
+            max_bytes: 1024,
+        };
+        (topic(name), PartitionIndex(index), partition)
+    }
+
+    fn response(
+        error_code: Option<ErrorCode>,
+        session_id: Option<i32>,
+        partitions: &[(&str, i32)],
+    ) -> FetchResponse {
+        let topic_responses = partitions
+            .iter()
+            .map(|(name, index)| response::Topic {
+                topic: topic(name),
+                partitions: vec![response::Partition {
+                    index: PartitionIndex(*index),
+                    error_code: None,
+                    high_watermark: Offset(100),
+                    last_stable_offset: Offset(90),
+                    log_start_offset: Offset(0),
+                    aborted_transactions: vec![],
+                    preferred_read_replica: BrokerId(-1),
+                    records: vec![],
+                    current_leader: None,
+                    tagged_fields: Default::default(),
+                }],
+                tagged_fields: Default::default(),
+            })
+            .collect();
+
+        FetchResponse {
+            throttle_time: None,
+            error_code,
+            session_id,
+            topic_responses,
+            node_endpoints: vec![],
+            tagged_fields: Default::default(),
+        }
+    }
+
+    /// Returns the partitions listed by a request, with their fetch offsets.
+    fn sent(request: &FetchRequest) -> Vec<(TopicRef, i32, i64)> {
+        request
+            .topics
+            .iter()
+            .flat_map(|topic| {
+                topic.partitions.iter().map(|partition| {
+                    (
+                        topic.topic.clone(),
+                        partition.index.0,
+                        partition.fetch_offset.0,
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the partitions a request forgets.
+    fn forgotten(request: &FetchRequest) -> Vec<(TopicRef, i32)> {
+        request
+            .topics_to_forget
+            .iter()
+            .flat_map(|topic| {
+                topic
+                    .partition_indices
+                    .iter()
+                    .map(|index| (topic.topic.clone(), index.0))
+            })
+            .collect()
+    }
+
+    /// Returns a handler with an established session 5, containing partitions a-0, a-1 and b-0.
+    fn established() -> FetchSessionHandler {
+        let mut handler = FetchSessionHandler::new();
+        let wanted = [wanted("a", 0, 10), wanted("a", 1, 20), wanted("b", 0, 30)];
+        handler.build(&mut request(), wanted, 12);
+        handler
+            .handle_response(&response(None, Some(5), &[("a", 0), ("b", 0)]))
+            .unwrap();
+        handler
+    }
+
+    #[test]
+    fn full_request() {
+        let mut handler = FetchSessionHandler::new();
+        let mut request = request();
+        let wanted = [wanted("a", 0, 10), wanted("a", 1, 20), wanted("b", 0, 30)];
+        handler.build(&mut request, wanted, 12);
+
+        assert_eq!(request.session_id, FetchRequest::INVALID_SESSION_ID);
+        assert_eq!(request.session_epoch, FetchRequest::INITIAL_EPOCH);
+        assert_eq!(request.topics.len(), 2);
+        assert_eq!(
+            sent(&request),
+            vec![
+                (topic("a"), 0, 10),
+                (topic("a"), 1, 20),
+                (topic("b"), 0, 30)
+            ]
+        );
+        assert!(forgotten(&request).is_empty());
+
+        handler
+            .handle_response(&response(None, Some(5), &[("a", 0), ("b", 0)]))
+            .unwrap();
+        assert_eq!(handler.session_id(), Some(5));
+        assert_eq!(handler.epoch(), Epoch(1));
+        assert_eq!(handler.partitions().len(), 3);
+        assert_eq!(handler.partition_states().len(), 2);
+        assert_eq!(
+            handler.partition_states()[&(topic("a"), PartitionIndex(0))].high_watermark,
+            Offset(100)
+        );
+    }
+
+    #[test]
+    fn incremental_request() {
+        let mut handler = established();
+        let mut request = request();
+
+        // a-0 is unchanged, a-1 moved on, b-0 is no longer wanted and c-0 is new.
+        let wanted = [wanted("a", 0, 10), wanted("a", 1, 25), wanted("c", 0, 0)];
+        handler.build(&mut request, wanted, 12);
+
+        assert_eq!(request.session_id, 5);
+        assert_eq!(request.session_epoch, Epoch(1));
+        assert_eq!(
+            sent(&request),
+            vec![(topic("a"), 1, 25), (topic("c"), 0, 0)]
+        );
+        assert_eq!(forgotten(&request), vec![(topic("b"), 0)]);
+
+        // The state of a forgotten partition is dropped.
+        assert!(!handler
+            .partition_states()
+            .contains_key(&(topic("b"), PartitionIndex(0))));
+
+        handler
+            .handle_response(&response(None, Some(5), &[("c", 0)]))
+            .unwrap();
+        assert_eq!(handler.epoch(), Epoch(2));
+        assert_eq!(handler.partition_states().len(), 2);
+    }
+
+    #[test]
+    fn no_session_before_version_7() {
+        let mut handler = established();
+        let mut request = request();
+        handler.build(&mut request, [wanted("a", 0, 10)], 6);
+
+        assert_eq!(request.session_id, FetchRequest::INVALID_SESSION_ID);
+        assert_eq!(request.session_epoch, FetchRequest::FINAL_EPOCH);
+    }
+
+    #[test]
+    fn next_epoch_wraps() {
+        assert_eq!(next_epoch(FetchRequest::INITIAL_EPOCH), Epoch(1));
+        assert_eq!(next_epoch(Epoch(41)), Epoch(42));
+        assert_eq!(next_epoch(Epoch(i32::MAX)), Epoch(1));
+    }
+
+    #[test]
+    fn session_not_found_resets_session() {
+        let mut handler = established();
+        handler.build(&mut request(), [wanted("a", 0, 10)], 12);
+
+        let error = handler
+            .handle_response(&response(
+                Some(ErrorCode::FetchSessionIdNotFound),
+                Some(5),
+                &[],
+            ))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::SessionFailed {
+                session_id: 5,
+                error_code: ErrorCode::FetchSessionIdNotFound
+            }
+        ));
+        assert_eq!(handler.session_id(), None);
+        assert_eq!(handler.epoch(), FetchRequest::INITIAL_EPOCH);
+
+        let mut request = request();
+        handler.build(&mut request, [wanted("a", 0, 10)], 12);
+        assert_eq!(request.session_id, FetchRequest::INVALID_SESSION_ID);
+        assert_eq!(request.session_epoch, FetchRequest::INITIAL_EPOCH);
+        assert_eq!(sent(&request), vec![(topic("a"), 0, 10)]);
+        assert!(forgotten(&request).is_empty());
+    }
+
+    #[test]
+    fn invalid_epoch_keeps_session_id() {
+        let mut handler = established();
+        handler.build(&mut request(), [wanted("a", 0, 10)], 12);
+
+        let error = handler
+            .handle_response(&response(
+                Some(ErrorCode::InvalidFetchSessionEpoch),
+                Some(5),
+                &[],
+            ))
+            .unwrap_err();
+        assert!(matches!(error, Error::SessionFailed { session_id: 5, .. }));
+
+        // The next request is a full fetch which closes the session and creates a new one.
+        assert_eq!(handler.session_id(), Some(5));
+        assert_eq!(handler.epoch(), FetchRequest::INITIAL_EPOCH);
+
+        let mut request = request();
+        handler.build(&mut request, [wanted("a", 0, 10)], 12);
+        assert_eq!(request.session_id, 5);
+        assert_eq!(request.session_epoch, FetchRequest::INITIAL_EPOCH);
+        assert_eq!(sent(&request), vec![(topic("a"), 0, 10)]);
+        assert!(forgotten(&request).is_empty());
+    }
+
+    #[test]
+    fn unexpected_partitions_fall_back_to_full_fetch() {
+        let mut handler = established();
+        handler.build(&mut request(), [wanted("a", 0, 10)], 12);
+
+        let error = handler
+            .handle_response(&response(None, Some(5), &[("a", 0), ("b", 0)]))
+            .unwrap_err();
+        match error {
+            Error::UnexpectedPartitions(partitions) => {
+                assert_eq!(partitions, [(topic("b"), PartitionIndex(0))])
+            }
+            error => panic!("unexpected error: {error:?}"),
+        }
+        assert_eq!(handler.epoch(), FetchRequest::INITIAL_EPOCH);
+
+        let mut request = request();
+        handler.build(&mut request, [wanted("a", 0, 10), wanted("b", 0, 30)], 12);
+        assert_eq!(
+            sent(&request),
+            vec![(topic("a"), 0, 10), (topic("b"), 0, 30)]
+        );
+        assert!(forgotten(&request).is_empty());
+    }
+}
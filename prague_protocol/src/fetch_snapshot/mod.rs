@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::FetchSnapshotRequest, response::FetchSnapshotResponse, FetchSnapshotApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct FetchSnapshotApi;
+
+impl Api for FetchSnapshotApi {
+    const KEY: ApiKey = ApiKey::FetchSnapshot;
+
+    type Request = FetchSnapshotRequest;
+    type Response = FetchSnapshotResponse;
+}
@@ -0,0 +1,104 @@
+//! Includes definitions for requests to [FetchSnapshotApi](super::FetchSnapshotApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FetchSnapshotRequest.json>
+
+use crate::{raft::SnapshotId, types::prelude::*};
+
+/// A request from a replica for part of a snapshot of the metadata log.
+///
+/// This is only sent between controllers. See [raft](crate::raft) for details.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FetchSnapshotRequest.json>
+pub struct FetchSnapshotRequest {
+    /// The ID of the cluster, or None if not known.
+    ///
+    /// Versions: 0+
+    pub cluster_id: Option<String>,
+
+    /// The ID of the replica fetching the snapshot.
+    ///
+    /// Versions: 0+
+    pub replica_id: BrokerId,
+
+    /// The maximum number of bytes to fetch from all of the snapshots.
+    ///
+    /// Versions: 0+
+    pub max_bytes: i32,
+
+    /// The topics to fetch snapshots of.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<FetchSnapshotTopic>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A topic to fetch snapshots of.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FetchSnapshotRequest.json>
+pub struct FetchSnapshotTopic {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The partitions to fetch snapshots of.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<FetchSnapshotPartition>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A partition to fetch a snapshot of.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FetchSnapshotRequest.json>
+pub struct FetchSnapshotPartition {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub partition: PartitionIndex,
+
+    /// The current leader epoch of the partition.
+    ///
+    /// Versions: 0+
+    pub current_leader_epoch: Epoch,
+
+    /// The snapshot to fetch.
+    ///
+    /// Versions: 0+
+    pub snapshot_id: SnapshotId,
+
+    /// The byte position within the snapshot to fetch from.
+    ///
+    /// Versions: 0+
+    pub position: i64,
+
+    /// The directory ID of the replica fetching the snapshot.
+    ///
+    /// Versions: 1+
+    pub replica_directory_id: Uuid,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
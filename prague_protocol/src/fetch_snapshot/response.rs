@@ -0,0 +1,141 @@
+//! Includes definitions for responses from [FetchSnapshotApi](super::FetchSnapshotApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FetchSnapshotResponse.json>
+
+use crate::{
+    error_code::ErrorCode,
+    raft::{NodeEndpoint, SnapshotId},
+    types::prelude::*,
+};
+
+/// A response message associated with [FetchSnapshotRequest](super::request::FetchSnapshotRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FetchSnapshotResponse.json>
+pub struct FetchSnapshotResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The top-level error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The snapshot data of each topic.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<FetchSnapshotTopicResponse>,
+
+    /// The endpoints of the leaders reported in [topics](Self::topics).
+    ///
+    /// Versions: 1+
+    pub node_endpoints: Vec<NodeEndpoint>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The snapshot data of a single topic.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FetchSnapshotResponse.json>
+pub struct FetchSnapshotTopicResponse {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The snapshot data of each partition.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<FetchSnapshotPartitionResponse>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The snapshot data of a single partition.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FetchSnapshotResponse.json>
+pub struct FetchSnapshotPartitionResponse {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub index: PartitionIndex,
+
+    /// The partition-level error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The snapshot the data was fetched from.
+    ///
+    /// Versions: 0+
+    pub snapshot_id: SnapshotId,
+
+    /// The current leader, or None if not sent.
+    ///
+    /// Versions: 0+
+    pub current_leader: Option<LeaderIdAndEpoch>,
+
+    /// The total size of the snapshot in bytes.
+    ///
+    /// Versions: 0+
+    pub size: i64,
+
+    /// The starting byte position within the snapshot of [unaligned_records](Self::unaligned_records).
+    ///
+    /// Versions: 0+
+    pub position: i64,
+
+    /// The snapshot data. This is not aligned to record batch boundaries, so it must be
+    /// reassembled before it can be decoded.
+    ///
+    /// Versions: 0+
+    pub unaligned_records: Vec<u8>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The current leader of a partition.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FetchSnapshotResponse.json>
+pub struct LeaderIdAndEpoch {
+    /// The ID of the current leader, or None if not known.
+    ///
+    /// Versions: 0+
+    pub leader_id: Option<BrokerId>,
+
+    /// The latest known leader epoch.
+    ///
+    /// Versions: 0+
+    pub leader_epoch: Epoch,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,28 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::FindCoordinatorRequest, response::FindCoordinatorResponse, FindCoordinatorApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct FindCoordinatorApi;
+
+impl Api for FindCoordinatorApi {
+    const KEY: ApiKey = ApiKey::FindCoordinator;
+
+    type Request = FindCoordinatorRequest;
+    type Response = FindCoordinatorResponse;
+}
+
+impl VersionedApi for FindCoordinatorApi {
+    /// Version 5 only adds an error code, and version 6 a key type for share groups.
+    const MIN_VERSION: i16 = 0;
+    const MAX_VERSION: i16 = 4;
+    const FLEXIBLE_VERSION: Option<i16> = Some(3);
+}
@@ -0,0 +1,78 @@
+//! Includes definitions for requests to [FindCoordinatorApi](super::FindCoordinatorApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FindCoordinatorRequest.json>
+
+use crate::{
+    codec::{Encode, Error, Writer},
+    types::prelude::*,
+};
+
+/// A request for the broker which coordinates a consumer group or a transactional ID.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FindCoordinatorRequest.json>
+pub struct FindCoordinatorRequest {
+    /// The coordinator key, which is a group ID or a transactional ID.
+    ///
+    /// Versions: 0-3
+    pub key: String,
+
+    /// The type of the coordinator key.
+    ///
+    /// Versions: 1+
+    pub key_type: CoordinatorType,
+
+    /// The coordinator keys.
+    ///
+    /// Versions: 4+
+    pub coordinator_keys: Vec<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The type of a coordinator key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoordinatorType {
+    /// The key is a consumer group ID.
+    Group,
+
+    /// The key is a transactional ID.
+    Transaction,
+}
+
+impl From<CoordinatorType> for i8 {
+    fn from(value: CoordinatorType) -> Self {
+        match value {
+            CoordinatorType::Group => 0,
+            CoordinatorType::Transaction => 1,
+        }
+    }
+}
+
+impl Encode for FindCoordinatorRequest {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), Error> {
+        if version <= 3 {
+            writer.string(&self.key);
+        }
+        if version >= 1 {
+            writer.i8(self.key_type.into());
+        }
+        if version >= 4 {
+            writer.array(&self.coordinator_keys, |writer, key| {
+                writer.string(key);
+                Ok(())
+            })?;
+        }
+
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+//! Includes definitions for responses from [FindCoordinatorApi](super::FindCoordinatorApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FindCoordinatorResponse.json>
+
+use crate::{
+    codec::{Decode, Error, Reader},
+    error_code::ErrorCode,
+    types::prelude::*,
+};
+
+/// A response message associated with
+/// [FindCoordinatorRequest](super::request::FindCoordinatorRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FindCoordinatorResponse.json>
+pub struct FindCoordinatorResponse {
+    /// The duration in milliseconds for which the request was throttled due to a quota violation,
+    /// or None if the request did not violate any quota.
+    ///
+    /// Versions: 1+
+    pub throttle_time: Option<Duration>,
+
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0-3
+    pub error_code: Option<ErrorCode>,
+
+    /// The error message, or None if there was no error.
+    ///
+    /// Versions: 1-3
+    pub error_message: Option<String>,
+
+    /// The node ID of the coordinator, or None if there was an error.
+    ///
+    /// Versions: 0-3
+    pub node_id: Option<BrokerId>,
+
+    /// The host name of the coordinator.
+    ///
+    /// Versions: 0-3
+    pub host: String,
+
+    /// The port of the coordinator.
+    ///
+    /// Versions: 0-3
+    pub port: i32,
+
+    /// Each coordinator which was requested.
+    ///
+    /// Versions: 4+
+    pub coordinators: Vec<Coordinator>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl FindCoordinatorResponse {
+    /// Returns the coordinators in this response, whichever version it is.
+    pub fn into_coordinators(self, key: &str) -> Vec<Coordinator> {
+        if !self.coordinators.is_empty() {
+            return self.coordinators;
+        }
+
+        vec![Coordinator {
+            key: key.to_owned(),
+            node_id: self.node_id,
+            host: self.host,
+            port: self.port,
+            error_code: self.error_code,
+            error_message: self.error_message,
+            tagged_fields: TaggedFields::default(),
+        }]
+    }
+}
+
+/// Describes a coordinator of a key.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/FindCoordinatorResponse.json>
+pub struct Coordinator {
+    /// The coordinator key.
+    ///
+    /// Versions: 4+
+    pub key: String,
+
+    /// The node ID of the coordinator, or None if there was an error.
+    ///
+    /// Versions: 4+
+    pub node_id: Option<BrokerId>,
+
+    /// The host name of the coordinator.
+    ///
+    /// Versions: 4+
+    pub host: String,
+
+    /// The port of the coordinator.
+    ///
+    /// Versions: 4+
+    pub port: i32,
+
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 4+
+    pub error_code: Option<ErrorCode>,
+
+    /// The error message, or None if there was no error.
+    ///
+    /// Versions: 4+
+    pub error_message: Option<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Decode for FindCoordinatorResponse {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        let throttle_time = match version >= 1 {
+            true => reader.throttle_time()?,
+            false => None,
+        };
+
+        if version >= 4 {
+            return Ok(Self {
+                throttle_time,
+                error_code: None,
+                error_message: None,
+                node_id: None,
+                host: String::new(),
+                port: -1,
+                coordinators: reader.array("coordinators", |reader| {
+                    Coordinator::decode(reader, version)
+                })?,
+                tagged_fields: reader.tagged_fields()?,
+            });
+        }
+
+        let error_code = reader.error_code()?;
+        let error_message = match version >= 1 {
+            true => reader.nullable_string()?,
+            false => None,
+        };
+
+        Ok(Self {
+            throttle_time,
+            error_code,
+            error_message,
+            node_id: Some(BrokerId(reader.i32()?)).filter(|id| id.0 >= 0),
+            host: reader.string("host")?,
+            port: reader.i32()?,
+            coordinators: vec![],
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+impl Decode for Coordinator {
+    fn decode(reader: &mut Reader, _version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            key: reader.string("key")?,
+            node_id: Some(BrokerId(reader.i32()?)).filter(|id| id.0 >= 0),
+            host: reader.string("host")?,
+            port: reader.i32()?,
+            error_code: reader.error_code()?,
+            error_message: reader.nullable_string()?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
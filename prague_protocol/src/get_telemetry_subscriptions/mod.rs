@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::GetTelemetrySubscriptionsRequest, response::GetTelemetrySubscriptionsResponse,
+        GetTelemetrySubscriptionsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct GetTelemetrySubscriptionsApi;
+
+impl Api for GetTelemetrySubscriptionsApi {
+    const KEY: ApiKey = ApiKey::GetTelemetrySubscriptions;
+
+    type Request = GetTelemetrySubscriptionsRequest;
+    type Response = GetTelemetrySubscriptionsResponse;
+}
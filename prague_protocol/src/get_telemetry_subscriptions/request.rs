@@ -0,0 +1,28 @@
+//! Includes definitions for requests to
+//! [GetTelemetrySubscriptionsApi](super::GetTelemetrySubscriptionsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/GetTelemetrySubscriptionsRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request for the metrics the broker would like this client to push.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/GetTelemetrySubscriptionsRequest.json>
+pub struct GetTelemetrySubscriptionsRequest {
+    /// The unique ID of this client instance, or [Uuid::ZERO] on the first request so that the
+    /// broker generates one.
+    ///
+    /// Versions: 0+
+    pub client_instance_id: Uuid,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,74 @@
+//! Includes definitions for responses from
+//! [GetTelemetrySubscriptionsApi](super::GetTelemetrySubscriptionsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/GetTelemetrySubscriptionsResponse.json>
+
+use crate::{error_code::ErrorCode, records::RecordBatchCompression, types::prelude::*};
+
+/// A response message associated with
+/// [GetTelemetrySubscriptionsRequest](super::request::GetTelemetrySubscriptionsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/GetTelemetrySubscriptionsResponse.json>
+pub struct GetTelemetrySubscriptionsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The unique ID assigned to this client instance. This is generated by the broker if the
+    /// request did not include one.
+    ///
+    /// Versions: 0+
+    pub client_instance_id: Uuid,
+
+    /// The ID of the subscription, which changes whenever the set of subscriptions matching this
+    /// client changes.
+    ///
+    /// Versions: 0+
+    pub subscription_id: i32,
+
+    /// The compression types the broker accepts for pushed metrics, in order of preference. The
+    /// client may always push uncompressed metrics.
+    ///
+    /// Versions: 0+
+    pub accepted_compression_types: Vec<RecordBatchCompression>,
+
+    /// The interval at which the client should push metrics.
+    ///
+    /// Versions: 0+
+    pub push_interval: Duration,
+
+    /// The largest serialized metrics payload the broker will accept.
+    ///
+    /// Versions: 0+
+    pub telemetry_max_bytes: i32,
+
+    /// If true, monotonic metrics should be pushed with delta temporality, i.e. as the change
+    /// since the previous push. Otherwise, cumulative temporality is used.
+    ///
+    /// Versions: 0+
+    pub delta_temporality: bool,
+
+    /// The prefixes of the metrics the broker is requesting. If empty, no metrics are requested,
+    /// and if it contains a single empty prefix, all metrics are requested.
+    ///
+    /// Versions: 0+
+    pub requested_metrics: Vec<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,120 @@
+//! Includes types shared by the group administration APIs.
+//!
+//! Unlike most enumerations in the protocol, group states and types are transmitted as strings.
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Group state `{0}` is not valid")]
+    InvalidGroupState(String),
+
+    #[error("Group type `{0}` is not valid")]
+    InvalidGroupType(String),
+}
+
+/// The state of a group.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/java/org/apache/kafka/common/GroupState.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupState {
+    /// The state of the group is not known.
+    Unknown,
+
+    /// A classic group is waiting for its members to rejoin.
+    PreparingRebalance,
+
+    /// A classic group is waiting for its leader to send the new assignment.
+    CompletingRebalance,
+
+    /// The group is stable and all of its members have their assignments.
+    Stable,
+
+    /// The group has no members and its metadata is being removed.
+    Dead,
+
+    /// The group has no members, but may still have committed offsets.
+    Empty,
+
+    /// A consumer group is computing a new target assignment.
+    Assigning,
+
+    /// A consumer group is waiting for its members to converge to the target assignment.
+    Reconciling,
+}
+
+impl GroupState {
+    /// Returns the name of this state as transmitted over the wire.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown",
+            Self::PreparingRebalance => "PreparingRebalance",
+            Self::CompletingRebalance => "CompletingRebalance",
+            Self::Stable => "Stable",
+            Self::Dead => "Dead",
+            Self::Empty => "Empty",
+            Self::Assigning => "Assigning",
+            Self::Reconciling => "Reconciling",
+        }
+    }
+}
+
+impl TryFrom<&str> for GroupState {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "unknown" => Ok(Self::Unknown),
+            "preparingrebalance" => Ok(Self::PreparingRebalance),
+            "completingrebalance" => Ok(Self::CompletingRebalance),
+            "stable" => Ok(Self::Stable),
+            "dead" => Ok(Self::Dead),
+            "empty" => Ok(Self::Empty),
+            "assigning" => Ok(Self::Assigning),
+            "reconciling" => Ok(Self::Reconciling),
+            _ => Err(Error::InvalidGroupState(value.to_owned())),
+        }
+    }
+}
+
+/// The type of a group, which determines the rebalance protocol it uses.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/java/org/apache/kafka/common/GroupType.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupType {
+    /// The type of the group is not known.
+    Unknown,
+
+    /// A group using the next-generation consumer rebalance protocol (KIP-848).
+    Consumer,
+
+    /// A group using the classic JoinGroup/SyncGroup rebalance protocol.
+    Classic,
+
+    /// A share group (KIP-932).
+    Share,
+}
+
+impl GroupType {
+    /// Returns the name of this type as transmitted over the wire.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unknown => "unknown",
+            Self::Consumer => "consumer",
+            Self::Classic => "classic",
+            Self::Share => "share",
+        }
+    }
+}
+
+impl TryFrom<&str> for GroupType {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "unknown" => Ok(Self::Unknown),
+            "consumer" => Ok(Self::Consumer),
+            "classic" => Ok(Self::Classic),
+            "share" => Ok(Self::Share),
+            _ => Err(Error::InvalidGroupType(value.to_owned())),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::IncrementalAlterConfigsRequest, response::IncrementalAlterConfigsResponse,
+        IncrementalAlterConfigsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct IncrementalAlterConfigsApi;
+
+impl Api for IncrementalAlterConfigsApi {
+    const KEY: ApiKey = ApiKey::IncrementalAlterConfigs;
+
+    type Request = IncrementalAlterConfigsRequest;
+    type Response = IncrementalAlterConfigsResponse;
+}
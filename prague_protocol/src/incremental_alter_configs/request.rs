@@ -0,0 +1,119 @@
+//! Includes definitions for requests to
+//! [IncrementalAlterConfigsApi](super::IncrementalAlterConfigsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/IncrementalAlterConfigsRequest.json>
+
+use crate::{config::ConfigResource, types::prelude::*};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Config operation `{0}` is not valid")]
+    InvalidConfigOperation(i8),
+}
+
+/// A request to change individual configuration entries of one or more resources.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/IncrementalAlterConfigsRequest.json>
+pub struct IncrementalAlterConfigsRequest {
+    /// The incremental updates for each resource.
+    ///
+    /// Versions: 0+
+    pub resources: Vec<AlterConfigsResource>,
+
+    /// True if we should validate the request, but not change the configurations.
+    ///
+    /// Versions: 0+
+    pub validate_only: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A resource whose configuration we want to change.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/IncrementalAlterConfigsRequest.json>
+pub struct AlterConfigsResource {
+    /// The resource type and name.
+    ///
+    /// Versions: 0+
+    pub resource: ConfigResource,
+
+    /// The configurations.
+    ///
+    /// Versions: 0+
+    pub configs: Vec<AlterableConfig>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A change to a single configuration entry.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/IncrementalAlterConfigsRequest.json>
+pub struct AlterableConfig {
+    /// The configuration key name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The type of operation to apply to the configuration.
+    ///
+    /// Versions: 0+
+    pub operation: ConfigOperation,
+
+    /// The value to set for the configuration key, or None when deleting it.
+    ///
+    /// Versions: 0+
+    pub value: Option<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 1+
+    pub tagged_fields: TaggedFields,
+}
+
+/// How an [AlterableConfig] changes the current value of a configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigOperation {
+    /// Sets the value of the configuration.
+    Set,
+
+    /// Reverts the configuration to its default value.
+    Delete,
+
+    /// Appends the value to the current value of a list configuration.
+    Append,
+
+    /// Removes the value from the current value of a list configuration.
+    Subtract,
+}
+
+impl TryFrom<i8> for ConfigOperation {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Set),
+            1 => Ok(Self::Delete),
+            2 => Ok(Self::Append),
+            3 => Ok(Self::Subtract),
+            code => Err(Error::InvalidConfigOperation(code)),
+        }
+    }
+}
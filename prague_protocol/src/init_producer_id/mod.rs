@@ -0,0 +1,27 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::InitProducerIdRequest, response::InitProducerIdResponse, InitProducerIdApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct InitProducerIdApi;
+
+impl Api for InitProducerIdApi {
+    const KEY: ApiKey = ApiKey::InitProducerId;
+
+    type Request = InitProducerIdRequest;
+    type Response = InitProducerIdResponse;
+}
+
+impl VersionedApi for InitProducerIdApi {
+    const MIN_VERSION: i16 = 0;
+    const MAX_VERSION: i16 = 5;
+    const FLEXIBLE_VERSION: Option<i16> = Some(2);
+}
@@ -0,0 +1,61 @@
+//! Includes definitions for requests to [InitProducerIdApi](super::InitProducerIdApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/InitProducerIdRequest.json>
+
+use crate::{
+    codec::{Encode, Error, Writer},
+    types::prelude::*,
+};
+
+/// A request for a producer ID and epoch, which the broker uses to deduplicate the batches of an
+/// idempotent or transactional producer.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/InitProducerIdRequest.json>
+pub struct InitProducerIdRequest {
+    /// The transactional ID, or None if the producer is not transactional.
+    ///
+    /// Versions: 0+
+    pub transactional_id: Option<String>,
+
+    /// The time in milliseconds to wait before aborting idle transactions sent by this producer.
+    /// This is only relevant if a transactional ID is set.
+    ///
+    /// Versions: 0+
+    pub transaction_timeout: Duration,
+
+    /// The producer ID to bump the epoch of, or None to get a new producer ID.
+    ///
+    /// Versions: 3+
+    pub producer_id: Option<ProducerId>,
+
+    /// The epoch of the [producer_id](Self::producer_id), or None to get a new producer ID.
+    ///
+    /// Versions: 3+
+    pub producer_epoch: Option<i16>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Encode for InitProducerIdRequest {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), Error> {
+        writer.nullable_string(self.transactional_id.as_deref());
+        writer.i32(self.transaction_timeout.0);
+
+        if version >= 3 {
+            writer.i64(self.producer_id.map_or(-1, |id| id.0));
+            writer.i16(self.producer_epoch.unwrap_or(-1));
+        }
+
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+//! Includes definitions for responses from [InitProducerIdApi](super::InitProducerIdApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/InitProducerIdResponse.json>
+
+use crate::{
+    codec::{Decode, Error, Reader},
+    error_code::ErrorCode,
+    types::prelude::*,
+};
+
+/// A response message associated with
+/// [InitProducerIdRequest](super::request::InitProducerIdRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/InitProducerIdResponse.json>
+pub struct InitProducerIdResponse {
+    /// The duration in milliseconds for which the request was throttled due to a quota violation,
+    /// or None if the request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The current producer ID.
+    ///
+    /// Versions: 0+
+    pub producer_id: ProducerId,
+
+    /// The current epoch associated with the producer ID.
+    ///
+    /// Versions: 0+
+    pub producer_epoch: i16,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Decode for InitProducerIdResponse {
+    fn decode(reader: &mut Reader, _version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            throttle_time: reader.throttle_time()?,
+            error_code: reader.error_code()?,
+            producer_id: ProducerId(reader.i64()?),
+            producer_epoch: reader.i16()?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
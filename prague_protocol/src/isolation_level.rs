@@ -5,6 +5,7 @@ pub enum Error {
 }
 
 /// Controls the visibility of transactional records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IsolationLevel {
     /// Makes all records visible.
     ReadUncommitted,
@@ -28,3 +29,12 @@ impl TryFrom<i8> for IsolationLevel {
         }
     }
 }
+
+impl From<IsolationLevel> for i8 {
+    fn from(value: IsolationLevel) -> Self {
+        match value {
+            IsolationLevel::ReadUncommitted => 0,
+            IsolationLevel::ReadCommitted => 1,
+        }
+    }
+}
@@ -1,20 +1,126 @@
 //! Type definitions for the [Kafka protocol](https://kafka.apache.org/protocol.html).
 
 pub mod acks;
+pub mod acl;
+pub mod add_offsets_to_transaction;
+pub mod add_partitions_to_transaction;
+pub mod alter_client_quotas;
+pub mod alter_configs;
+pub mod alter_partition_reassignments;
+pub mod alter_replica_log_dirs;
+pub mod alter_user_scram_credentials;
 pub mod api;
+pub mod api_versions;
+pub mod begin_quorum_epoch;
+pub mod codec;
+pub mod config;
+pub mod consumer_group_describe;
+pub mod consumer_group_heartbeat;
+pub mod consumer_protocol;
+pub mod create_acls;
+pub mod create_delegation_token;
+pub mod delegation_token;
+pub mod delete_acls;
+pub mod delete_groups;
+pub mod describe_acls;
+pub mod describe_client_quotas;
+pub mod describe_cluster;
+pub mod describe_configs;
+pub mod describe_delegation_token;
+pub mod describe_groups;
+pub mod describe_log_dirs;
+pub mod describe_producers;
+pub mod describe_quorum;
+pub mod describe_transactions;
+pub mod describe_user_scram_credentials;
+pub mod elect_leaders;
+pub mod end_quorum_epoch;
+pub mod end_transaction;
 pub mod error_code;
+pub mod expire_delegation_token;
 pub mod fetch;
+pub mod fetch_snapshot;
+pub mod find_coordinator;
+pub mod get_telemetry_subscriptions;
+pub mod group;
 pub mod headers;
+pub mod incremental_alter_configs;
+pub mod init_producer_id;
 pub mod isolation_level;
+pub mod list_client_metrics_resources;
+pub mod list_groups;
 pub mod list_offsets;
+pub mod list_partition_reassignments;
+pub mod list_transactions;
 pub mod metadata;
 pub mod network;
-pub mod types;
+pub mod offset_for_leader_epoch;
 pub mod produce;
+pub mod push_telemetry;
+pub mod quota;
+pub mod raft;
 pub mod records;
+pub mod renew_delegation_token;
+pub mod sasl;
+pub mod sasl_authenticate;
+pub mod sasl_handshake;
+pub mod scram;
+pub mod telemetry;
+pub mod transaction;
+pub mod transaction_offset_commit;
+pub mod types;
+pub mod update_features;
+pub mod vote;
 
 pub mod prelude {
+    pub use crate::add_offsets_to_transaction::AddOffsetsToTransactionApi;
+    pub use crate::add_partitions_to_transaction::AddPartitionsToTransactionApi;
+    pub use crate::alter_client_quotas::AlterClientQuotasApi;
+    pub use crate::alter_configs::AlterConfigsApi;
+    pub use crate::alter_partition_reassignments::AlterPartitionReassignmentsApi;
+    pub use crate::alter_replica_log_dirs::AlterReplicaLogDirsApi;
+    pub use crate::alter_user_scram_credentials::AlterUserScramCredentialsApi;
+    pub use crate::api_versions::ApiVersionsApi;
+    pub use crate::begin_quorum_epoch::BeginQuorumEpochApi;
+    pub use crate::consumer_group_describe::ConsumerGroupDescribeApi;
+    pub use crate::consumer_group_heartbeat::ConsumerGroupHeartbeatApi;
+    pub use crate::create_acls::CreateAclsApi;
+    pub use crate::create_delegation_token::CreateDelegationTokenApi;
+    pub use crate::delete_acls::DeleteAclsApi;
+    pub use crate::delete_groups::DeleteGroupsApi;
+    pub use crate::describe_acls::DescribeAclsApi;
+    pub use crate::describe_client_quotas::DescribeClientQuotasApi;
+    pub use crate::describe_cluster::DescribeClusterApi;
+    pub use crate::describe_configs::DescribeConfigsApi;
+    pub use crate::describe_delegation_token::DescribeDelegationTokenApi;
+    pub use crate::describe_groups::DescribeGroupsApi;
+    pub use crate::describe_log_dirs::DescribeLogDirsApi;
+    pub use crate::describe_producers::DescribeProducersApi;
+    pub use crate::describe_quorum::DescribeQuorumApi;
+    pub use crate::describe_transactions::DescribeTransactionsApi;
+    pub use crate::describe_user_scram_credentials::DescribeUserScramCredentialsApi;
+    pub use crate::elect_leaders::ElectLeadersApi;
+    pub use crate::end_quorum_epoch::EndQuorumEpochApi;
+    pub use crate::end_transaction::EndTransactionApi;
+    pub use crate::expire_delegation_token::ExpireDelegationTokenApi;
     pub use crate::fetch::FetchApi;
+    pub use crate::fetch_snapshot::FetchSnapshotApi;
+    pub use crate::find_coordinator::FindCoordinatorApi;
+    pub use crate::get_telemetry_subscriptions::GetTelemetrySubscriptionsApi;
+    pub use crate::incremental_alter_configs::IncrementalAlterConfigsApi;
+    pub use crate::init_producer_id::InitProducerIdApi;
+    pub use crate::list_client_metrics_resources::ListClientMetricsResourcesApi;
+    pub use crate::list_groups::ListGroupsApi;
     pub use crate::list_offsets::ListOffsetsApi;
+    pub use crate::list_partition_reassignments::ListPartitionReassignmentsApi;
+    pub use crate::list_transactions::ListTransactionsApi;
+    pub use crate::offset_for_leader_epoch::OffsetForLeaderEpochApi;
     pub use crate::produce::ProduceApi;
+    pub use crate::push_telemetry::PushTelemetryApi;
+    pub use crate::renew_delegation_token::RenewDelegationTokenApi;
+    pub use crate::sasl_authenticate::SaslAuthenticateApi;
+    pub use crate::sasl_handshake::SaslHandshakeApi;
+    pub use crate::transaction_offset_commit::TransactionOffsetCommitApi;
+    pub use crate::update_features::UpdateFeaturesApi;
+    pub use crate::vote::VoteApi;
 }
@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::ListClientMetricsResourcesRequest, response::ListClientMetricsResourcesResponse,
+        ListClientMetricsResourcesApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct ListClientMetricsResourcesApi;
+
+impl Api for ListClientMetricsResourcesApi {
+    const KEY: ApiKey = ApiKey::ListClientMetricsResources;
+
+    type Request = ListClientMetricsResourcesRequest;
+    type Response = ListClientMetricsResourcesResponse;
+}
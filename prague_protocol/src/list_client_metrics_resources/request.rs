@@ -0,0 +1,22 @@
+//! Includes definitions for requests to
+//! [ListClientMetricsResourcesApi](super::ListClientMetricsResourcesApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListClientMetricsResourcesRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request to list the client metrics configuration resources in the cluster.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListClientMetricsResourcesRequest.json>
+pub struct ListClientMetricsResourcesRequest {
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,19 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{request::ListGroupsRequest, response::ListGroupsResponse, ListGroupsApi};
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct ListGroupsApi;
+
+impl Api for ListGroupsApi {
+    const KEY: ApiKey = ApiKey::ListGroups;
+
+    type Request = ListGroupsRequest;
+    type Response = ListGroupsResponse;
+}
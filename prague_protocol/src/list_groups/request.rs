@@ -0,0 +1,35 @@
+//! Includes definitions for requests to [ListGroupsApi](super::ListGroupsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListGroupsRequest.json>
+
+use crate::{
+    group::{GroupState, GroupType},
+    types::prelude::*,
+};
+
+/// A request to list the groups on a broker.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListGroupsRequest.json>
+pub struct ListGroupsRequest {
+    /// The states of the groups we want to list. If empty, all groups are returned with their
+    /// state.
+    ///
+    /// Versions: 4+
+    pub states_filter: Vec<GroupState>,
+
+    /// The types of the groups we want to list. If empty, all groups are returned with their type.
+    ///
+    /// Versions: 5+
+    pub types_filter: Vec<GroupType>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,74 @@
+//! Includes definitions for responses from [ListGroupsApi](super::ListGroupsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListGroupsResponse.json>
+
+use crate::{
+    error_code::ErrorCode,
+    group::{GroupState, GroupType},
+    types::prelude::*,
+};
+
+/// A response message associated with [ListGroupsRequest](super::request::ListGroupsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListGroupsResponse.json>
+pub struct ListGroupsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 1+
+    pub throttle_time: Option<Duration>,
+
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// Each group in the response.
+    ///
+    /// Versions: 0+
+    pub groups: Vec<ListedGroup>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes a group listed by a broker.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListGroupsResponse.json>
+pub struct ListedGroup {
+    /// The group ID.
+    ///
+    /// Versions: 0+
+    pub group_id: String,
+
+    /// The group protocol type, e.g. `consumer`, or an empty string for KIP-848 consumer groups.
+    ///
+    /// Versions: 0+
+    pub protocol_type: String,
+
+    /// The group state, or None if the broker does not report it.
+    ///
+    /// Versions: 4+
+    pub group_state: Option<GroupState>,
+
+    /// The group type, or None if the broker does not report it.
+    ///
+    /// Versions: 5+
+    pub group_type: Option<GroupType>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::ListPartitionReassignmentsRequest, response::ListPartitionReassignmentsResponse,
+        ListPartitionReassignmentsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct ListPartitionReassignmentsApi;
+
+impl Api for ListPartitionReassignmentsApi {
+    const KEY: ApiKey = ApiKey::ListPartitionReassignments;
+
+    type Request = ListPartitionReassignmentsRequest;
+    type Response = ListPartitionReassignmentsResponse;
+}
@@ -0,0 +1,55 @@
+//! Includes definitions for requests to
+//! [ListPartitionReassignmentsApi](super::ListPartitionReassignmentsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListPartitionReassignmentsRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request to list the ongoing partition reassignments.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListPartitionReassignmentsRequest.json>
+pub struct ListPartitionReassignmentsRequest {
+    /// The time in milliseconds to wait for the request to complete.
+    ///
+    /// Versions: 0+
+    pub timeout: Duration,
+
+    /// The topics to list partition reassignments for, or None to list everything.
+    ///
+    /// Versions: 0+
+    pub topics: Option<Vec<ListPartitionReassignmentsTopic>>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A topic to list partition reassignments for.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListPartitionReassignmentsRequest.json>
+pub struct ListPartitionReassignmentsTopic {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The partitions to list partition reassignments for.
+    ///
+    /// Versions: 0+
+    pub partition_indexes: Vec<PartitionIndex>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
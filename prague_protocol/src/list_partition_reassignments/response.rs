@@ -0,0 +1,100 @@
+//! Includes definitions for responses from
+//! [ListPartitionReassignmentsApi](super::ListPartitionReassignmentsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListPartitionReassignmentsResponse.json>
+
+use crate::{error_code::ErrorCode, types::prelude::*};
+
+/// A response message associated with
+/// [ListPartitionReassignmentsRequest](super::request::ListPartitionReassignmentsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListPartitionReassignmentsResponse.json>
+pub struct ListPartitionReassignmentsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The top-level error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The top-level error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The ongoing reassignments for each topic.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<OngoingTopicReassignment>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the ongoing reassignments of a topic's partitions.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListPartitionReassignmentsResponse.json>
+pub struct OngoingTopicReassignment {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The ongoing reassignments for each partition.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<OngoingPartitionReassignment>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the ongoing reassignment of a single partition.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListPartitionReassignmentsResponse.json>
+pub struct OngoingPartitionReassignment {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub index: PartitionIndex,
+
+    /// The current replica set.
+    ///
+    /// Versions: 0+
+    pub replicas: Vec<BrokerId>,
+
+    /// The replicas that are being added to the replica set.
+    ///
+    /// Versions: 0+
+    pub adding_replicas: Vec<BrokerId>,
+
+    /// The replicas that are being removed from the replica set.
+    ///
+    /// Versions: 0+
+    pub removing_replicas: Vec<BrokerId>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::ListTransactionsRequest, response::ListTransactionsResponse, ListTransactionsApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct ListTransactionsApi;
+
+impl Api for ListTransactionsApi {
+    const KEY: ApiKey = ApiKey::ListTransactions;
+
+    type Request = ListTransactionsRequest;
+    type Response = ListTransactionsResponse;
+}
@@ -0,0 +1,38 @@
+//! Includes definitions for requests to [ListTransactionsApi](super::ListTransactionsApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListTransactionsRequest.json>
+
+use crate::{transaction::TransactionState, types::prelude::*};
+
+/// A request to list the transactions known to a transaction coordinator. Must be sent to every
+/// broker to list all transactions in the cluster.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListTransactionsRequest.json>
+pub struct ListTransactionsRequest {
+    /// The transaction states to filter by. If empty, all transactions are returned.
+    ///
+    /// Versions: 0+
+    pub state_filters: Vec<TransactionState>,
+
+    /// The producer IDs to filter by. If empty, all transactions are returned.
+    ///
+    /// Versions: 0+
+    pub producer_id_filters: Vec<ProducerId>,
+
+    /// Only return transactions that have been running for longer than this many milliseconds, or
+    /// None to return transactions regardless of how long they have been running.
+    ///
+    /// Versions: 1+
+    pub duration_filter: Option<i64>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
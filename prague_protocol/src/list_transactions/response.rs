@@ -0,0 +1,72 @@
+//! Includes definitions for responses from [ListTransactionsApi](super::ListTransactionsApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListTransactionsResponse.json>
+
+use crate::{error_code::ErrorCode, transaction::TransactionState, types::prelude::*};
+
+/// A response message associated with
+/// [ListTransactionsRequest](super::request::ListTransactionsRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListTransactionsResponse.json>
+pub struct ListTransactionsResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// Set of state filters provided in the request which were unknown to the transaction
+    /// coordinator.
+    ///
+    /// Versions: 0+
+    pub unknown_state_filters: Vec<String>,
+
+    /// Each listed transaction.
+    ///
+    /// Versions: 0+
+    pub transaction_states: Vec<ListedTransaction>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes a transaction listed by a transaction coordinator.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ListTransactionsResponse.json>
+pub struct ListedTransaction {
+    /// The transactional ID.
+    ///
+    /// Versions: 0+
+    pub transactional_id: String,
+
+    /// The producer ID of the transactional ID.
+    ///
+    /// Versions: 0+
+    pub producer_id: ProducerId,
+
+    /// The current transaction state of the producer.
+    ///
+    /// Versions: 0+
+    pub transaction_state: TransactionState,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
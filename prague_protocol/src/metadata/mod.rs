@@ -17,3 +17,9 @@ impl Api for MetadataApi {
     type Request = MetadataRequest;
     type Response = MetadataResponse;
 }
+
+impl VersionedApi for MetadataApi {
+    const MIN_VERSION: i16 = MetadataRequest::MIN_VERSION;
+    const MAX_VERSION: i16 = MetadataRequest::MAX_VERSION;
+    const FLEXIBLE_VERSION: Option<i16> = Some(9);
+}
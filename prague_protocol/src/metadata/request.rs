@@ -1,16 +1,201 @@
-use crate::types::prelude::*;
+use crate::{
+    codec::{self, Encode, Writer},
+    types::prelude::*,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Metadata version `{0}` is not supported")]
+    UnsupportedVersion(i16),
+
+    #[error(
+        "Metadata version `{0}` cannot request no topics, since an empty list requests all topics"
+    )]
+    NoTopicsUnsupported(i16),
+
+    #[error("Metadata version `{0}` does not support describing topics by ID")]
+    TopicIdsUnsupported(i16),
+
+    #[error("Metadata topics must have a name or an ID")]
+    TopicUnidentified,
+
+    #[error("Metadata version `{0}` does not support disabling automatic topic creation")]
+    AllowAutoTopicCreationUnsupported(i16),
+
+    #[error("Metadata version `{0}` does not support including cluster authorized operations")]
+    IncludeClusterAuthorizedOperationsUnsupported(i16),
+
+    #[error("Metadata version `{0}` does not support including topic authorized operations")]
+    IncludeTopicAuthorizedOperationsUnsupported(i16),
+}
 
 /// See: <https://kafka.apache.org/protocol.html#protocol_messages>
 pub struct MetadataRequest {
-    pub topics: Vec<Topic>,
+    /// The topics to fetch metadata for, or None to fetch metadata for all topics.
+    ///
+    /// Versions: 0+
+    pub topics: Option<Vec<Topic>>,
+
+    /// Whether the broker may create requested topics which do not exist, if it is configured to
+    /// do so.
+    ///
+    /// Versions: 4+
     pub allow_auto_topic_creation: bool,
+
+    /// Whether to include the operations the client is authorized to perform on the cluster.
+    ///
+    /// Versions: 8-10
+    pub include_cluster_authorized_operations: bool,
+
+    /// Whether to include the operations the client is authorized to perform on each topic in
+    /// [TopicMetadata::authorized_operations](super::response::TopicMetadata::authorized_operations).
+    ///
+    /// Versions: 8+
     pub include_topic_authorized_operations: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 9+
     pub tagged_fields: TaggedFields,
 }
 
+impl MetadataRequest {
+    /// The oldest supported version.
+    pub const MIN_VERSION: i16 = 0;
+
+    /// The newest supported version.
+    pub const MAX_VERSION: i16 = 12;
+
+    /// The first version in which brokers can describe topics by ID. Versions 10 and 11 include
+    /// the topic ID field, but brokers never implemented lookups by ID for them.
+    pub const TOPIC_ID_VERSION: i16 = 12;
+
+    /// Checks that this request can be sent with the given version.
+    ///
+    /// Like the Java client, this rejects any field which the version cannot represent but which
+    /// differs from its default, since dropping it would change the meaning of the request.
+    pub fn validate(&self, version: i16) -> Result<(), Error> {
+        if !(Self::MIN_VERSION..=Self::MAX_VERSION).contains(&version) {
+            return Err(Error::UnsupportedVersion(version));
+        }
+
+        if version < 1 && self.topics.as_ref().is_some_and(Vec::is_empty) {
+            return Err(Error::NoTopicsUnsupported(version));
+        }
+
+        if version < 4 && !self.allow_auto_topic_creation {
+            return Err(Error::AllowAutoTopicCreationUnsupported(version));
+        }
+
+        if !(8..=10).contains(&version) && self.include_cluster_authorized_operations {
+            return Err(Error::IncludeClusterAuthorizedOperationsUnsupported(
+                version,
+            ));
+        }
+
+        if version < 8 && self.include_topic_authorized_operations {
+            return Err(Error::IncludeTopicAuthorizedOperationsUnsupported(version));
+        }
+
+        self.topics
+            .iter()
+            .flatten()
+            .try_for_each(|topic| topic.validate(version))
+    }
+}
+
 /// See: <https://kafka.apache.org/protocol.html#protocol_messages>
 pub struct Topic {
-    pub id: Uuid,
-    pub name: String,
+    /// The topic's unique ID, or None to look the topic up by name.
+    ///
+    /// Versions: 10+
+    pub id: Option<Uuid>,
+
+    /// The topic's name, or None to look the topic up by ID.
+    ///
+    /// Versions: 0+
+    pub name: Option<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 9+
     pub tagged_fields: TaggedFields,
 }
+
+impl Topic {
+    /// Creates a topic which is looked up by name.
+    pub fn named(name: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            name: Some(name.into()),
+            tagged_fields: Default::default(),
+        }
+    }
+
+    /// Creates a topic which is looked up by ID.
+    pub fn with_id(id: Uuid) -> Self {
+        Self {
+            id: Some(id),
+            name: None,
+            tagged_fields: Default::default(),
+        }
+    }
+
+    /// Checks that this topic can be sent with the given version.
+    fn validate(&self, version: i16) -> Result<(), Error> {
+        match (&self.id, &self.name) {
+            (None, None) => Err(Error::TopicUnidentified),
+            (Some(_), _) if version < MetadataRequest::TOPIC_ID_VERSION => {
+                Err(Error::TopicIdsUnsupported(version))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Encode for MetadataRequest {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), codec::Error> {
+        self.validate(version)?;
+
+        match (&self.topics, version) {
+            // Version 0 has no null topics, and requests all topics with an empty list instead.
+            (None, 0) => writer.array::<Topic>(&[], |_, _| Ok(()))?,
+            (topics, _) => writer.nullable_array(topics.as_deref(), |writer, topic| {
+                topic.encode(writer, version)
+            })?,
+        }
+
+        if version >= 4 {
+            writer.bool(self.allow_auto_topic_creation);
+        }
+
+        if (8..=10).contains(&version) {
+            writer.bool(self.include_cluster_authorized_operations);
+        }
+
+        if version >= 8 {
+            writer.bool(self.include_topic_authorized_operations);
+        }
+
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
+
+impl Encode for Topic {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), codec::Error> {
+        if version >= 10 {
+            writer.uuid(self.id.unwrap_or(Uuid::ZERO));
+            writer.nullable_string(self.name.as_deref());
+        } else {
+            writer.string(
+                self.name
+                    .as_deref()
+                    .ok_or(codec::Error::NullField("name"))?,
+            );
+        }
+
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
@@ -1,4 +1,11 @@
-use crate::{error_code::ErrorCode, types::prelude::*};
+use std::collections::HashSet;
+
+use crate::{
+    acl::AclOperation,
+    codec::{Decode, Error, Reader},
+    error_code::ErrorCode,
+    types::prelude::*,
+};
 
 /// See: <https://kafka.apache.org/protocol.html#protocol_messages>
 pub struct MetadataResponse {
@@ -11,10 +18,19 @@ pub struct MetadataResponse {
     pub cluster_id: Option<String>,
     pub controller_id: i32,
     pub topics: Vec<TopicMetadata>,
+
+    /// The operations the client is authorized to perform on the cluster, or None if they were
+    /// not requested via
+    /// [include_cluster_authorized_operations](super::request::MetadataRequest::include_cluster_authorized_operations).
+    ///
+    /// Versions: 8-10
+    pub cluster_authorized_operations: Option<HashSet<AclOperation>>,
+
     pub tagged_fields: TaggedFields,
 }
 
 /// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BrokerMetadata {
     /// This broker's ID.
     pub id: BrokerId,
@@ -39,7 +55,14 @@ pub struct TopicMetadata {
     pub id: Uuid,
     pub is_internal: bool,
     pub partitions: Vec<PartitionMetadata>,
-    pub authorized_operations: i32,
+
+    /// The operations the client is authorized to perform on this topic, or None if they were not
+    /// requested via
+    /// [include_topic_authorized_operations](super::request::MetadataRequest::include_topic_authorized_operations).
+    ///
+    /// See [AclOperation::parse_bitfield] for how these are decoded.
+    pub authorized_operations: Option<HashSet<AclOperation>>,
+
     pub tagged_fields: TaggedFields,
 }
 
@@ -54,3 +77,99 @@ pub struct PartitionMetadata {
     pub offline_replicas: Vec<BrokerId>,
     pub tagged_fields: TaggedFields,
 }
+
+impl Decode for MetadataResponse {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        let throttle_time = match version >= 3 {
+            true => reader.throttle_time()?,
+            false => None,
+        };
+        let brokers = reader.array("brokers", |reader| BrokerMetadata::decode(reader, version))?;
+        let cluster_id = match version >= 2 {
+            true => reader.nullable_string()?,
+            false => None,
+        };
+        let controller_id = match version >= 1 {
+            true => reader.i32()?,
+            false => -1,
+        };
+        let topics = reader.array("topics", |reader| TopicMetadata::decode(reader, version))?;
+        let cluster_authorized_operations = match (8..=10).contains(&version) {
+            true => AclOperation::parse_bitfield(reader.i32()?),
+            false => None,
+        };
+
+        Ok(Self {
+            throttle_time,
+            brokers,
+            cluster_id,
+            controller_id,
+            topics,
+            cluster_authorized_operations,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+impl Decode for BrokerMetadata {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            id: BrokerId(reader.i32()?),
+            hostname: reader.string("hostname")?,
+            port: reader.i32()?,
+            rack: match version >= 1 {
+                true => reader.nullable_string()?,
+                false => None,
+            },
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+impl Decode for TopicMetadata {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            error_code: reader.error_code()?,
+            name: reader.nullable_string()?,
+            id: match version >= 10 {
+                true => reader.uuid()?,
+                false => Uuid::ZERO,
+            },
+            is_internal: match version >= 1 {
+                true => reader.bool()?,
+                false => false,
+            },
+            partitions: reader.array("partitions", |reader| {
+                PartitionMetadata::decode(reader, version)
+            })?,
+            authorized_operations: match version >= 8 {
+                true => AclOperation::parse_bitfield(reader.i32()?),
+                false => None,
+            },
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+impl Decode for PartitionMetadata {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        let broker_ids = |reader: &mut Reader| reader.i32().map(BrokerId);
+
+        Ok(Self {
+            error_code: reader.error_code()?,
+            index: PartitionIndex(reader.i32()?),
+            leader_id: reader.i32()?,
+            leader_epoch: match version >= 7 {
+                true => Epoch(reader.i32()?),
+                false => Epoch(-1),
+            },
+            replica_nodes: reader.array("replica_nodes", broker_ids)?,
+            isr_nodes: reader.array("isr_nodes", broker_ids)?,
+            offline_replicas: match version >= 5 {
+                true => reader.array("offline_replicas", broker_ids)?,
+                false => vec![],
+            },
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::OffsetForLeaderEpochRequest, response::OffsetForLeaderEpochResponse,
+        OffsetForLeaderEpochApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct OffsetForLeaderEpochApi;
+
+impl Api for OffsetForLeaderEpochApi {
+    const KEY: ApiKey = ApiKey::OffsetForLeaderEpoch;
+
+    type Request = OffsetForLeaderEpochRequest;
+    type Response = OffsetForLeaderEpochResponse;
+}
@@ -0,0 +1,84 @@
+//! Includes definitions for requests to [OffsetForLeaderEpochApi](super::OffsetForLeaderEpochApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/OffsetForLeaderEpochRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request for the end offset of a leader epoch, which consumers use after a leader change to
+/// detect whether the log was truncated past their position.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/OffsetForLeaderEpochRequest.json>
+pub struct OffsetForLeaderEpochRequest {
+    /// If this request is being made by a broker, the ID of the broker. Otherwise, None.
+    ///
+    /// Versions: 3+
+    pub replica_id: Option<BrokerId>,
+
+    /// Each topic to get offsets for.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<Topic>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A topic to get offsets for.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/OffsetForLeaderEpochRequest.json>
+pub struct Topic {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// Each partition to get offsets for.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<Partition>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A partition to get the end offset of a leader epoch for.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/OffsetForLeaderEpochRequest.json>
+pub struct Partition {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub index: PartitionIndex,
+
+    /// The epoch the client believes the current leader has, used to fence requests to stale
+    /// leaders, or None to skip the check.
+    ///
+    /// Versions: 2+
+    pub current_leader_epoch: Option<Epoch>,
+
+    /// The epoch to look up an offset for.
+    ///
+    /// Versions: 0+
+    pub leader_epoch: Epoch,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,91 @@
+//! Includes definitions for responses from
+//! [OffsetForLeaderEpochApi](super::OffsetForLeaderEpochApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/OffsetForLeaderEpochResponse.json>
+
+use crate::{error_code::ErrorCode, types::prelude::*};
+
+/// A response message associated with
+/// [OffsetForLeaderEpochRequest](super::request::OffsetForLeaderEpochRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/OffsetForLeaderEpochResponse.json>
+pub struct OffsetForLeaderEpochResponse {
+    /// The duration for which the request was throttled due to a quota violation, or None if the
+    /// request did not violate any quota.
+    ///
+    /// Versions: 2+
+    pub throttle_time: Option<Duration>,
+
+    /// Each topic we fetched offsets for.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<TopicEpochEndOffsets>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the end offsets of a topic's partitions.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/OffsetForLeaderEpochResponse.json>
+pub struct TopicEpochEndOffsets {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// Each partition we fetched an end offset for.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<EpochEndOffset>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
+
+/// Describes the end offset of a leader epoch on a partition.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/OffsetForLeaderEpochResponse.json>
+pub struct EpochEndOffset {
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub index: PartitionIndex,
+
+    /// The largest epoch less than or equal to the requested epoch, or None if it is not known.
+    ///
+    /// Versions: 1+
+    pub leader_epoch: Option<Epoch>,
+
+    /// The offset one past the last record of [leader_epoch](Self::leader_epoch), or None if it is
+    /// not known. A consumer whose position is past this offset has seen truncated records.
+    ///
+    /// Versions: 0+
+    pub end_offset: Option<Offset>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 4+
+    pub tagged_fields: TaggedFields,
+}
@@ -8,6 +8,7 @@ pub mod prelude {
     pub use super::{request::ProduceRequest, response::ProduceResponse, ProduceApi};
 }
 
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
 pub struct ProduceApi;
 
 impl Api for ProduceApi {
@@ -16,3 +17,10 @@ impl Api for ProduceApi {
     type Request = ProduceRequest;
     type Response = ProduceResponse;
 }
+
+impl VersionedApi for ProduceApi {
+    /// Versions before 3 cannot carry record batches with magic number 2.
+    const MIN_VERSION: i16 = 3;
+    const MAX_VERSION: i16 = 11;
+    const FLEXIBLE_VERSION: Option<i16> = Some(9);
+}
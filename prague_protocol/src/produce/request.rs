@@ -5,7 +5,12 @@
 //! - <https://kafka.apache.org/protocol.html#protocol_messages>
 //! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ProduceRequest.json>
 
-use crate::{acks::AckRequirement, records::Records, types::prelude::*};
+use crate::{
+    acks::AckRequirement,
+    codec::{Encode, Error, Writer},
+    records::Records,
+    types::prelude::*,
+};
 
 /// A request to produce data.
 ///
@@ -89,3 +94,34 @@ pub struct PartitionProduceTarget {
     /// Versions: 9+
     pub tagged_fields: TaggedFields,
 }
+
+impl Encode for ProduceRequest {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), Error> {
+        writer.nullable_string(self.transactional_id.as_deref());
+        writer.i16(self.acks.into());
+        writer.i32(self.timeout);
+        writer.array(&self.topics, |writer, topic| topic.encode(writer, version))?;
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
+
+impl Encode for TopicProduceTarget {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), Error> {
+        writer.string(&self.name);
+        writer.array(&self.partitions, |writer, partition| {
+            partition.encode(writer, version)
+        })?;
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
+
+impl Encode for PartitionProduceTarget {
+    fn encode(&self, writer: &mut Writer, _version: i16) -> Result<(), Error> {
+        writer.i32(self.index);
+        writer.bytes(&self.records.to_bytes()?);
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
@@ -5,7 +5,11 @@
 //! - <https://kafka.apache.org/protocol.html#protocol_messages>
 //! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/ProduceResponse.json>
 
-use crate::{error_code::ErrorCode, types::prelude::TaggedFields};
+use crate::{
+    codec::{Decode, Error, Reader},
+    error_code::ErrorCode,
+    types::prelude::TaggedFields,
+};
 
 /// A response message associated with [ProduceRequest](super::request::ProduceRequest).
 ///
@@ -182,3 +186,107 @@ pub struct NodeResult {
     /// Version: 10+
     pub rack: Option<String>,
 }
+
+impl Decode for ProduceResponse {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        let topics = reader.array("topics", |reader| {
+            TopicProduceResult::decode(reader, version)
+        })?;
+        let throttle_time = match version >= 1 {
+            true => Some(reader.i32()?).filter(|throttle_time| *throttle_time > 0),
+            false => None,
+        };
+        let mut tagged_fields = reader.tagged_fields()?;
+        let endpoints = Reader::take_tagged(&mut tagged_fields, 0, |reader| {
+            reader.array("endpoints", NodeResult::decode_one)
+        })?
+        .unwrap_or_default();
+
+        Ok(Self {
+            topics,
+            throttle_time,
+            endpoints,
+            tagged_fields,
+        })
+    }
+}
+
+impl Decode for TopicProduceResult {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            name: reader.string("name")?,
+            partitions: reader.array("partitions", |reader| {
+                PartitionProduceResult::decode(reader, version)
+            })?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+impl Decode for PartitionProduceResult {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        let index = reader.i32()?;
+        let error_code = reader.error_code()?;
+        let base_offset = reader.i64()?;
+        let log_append_time = match version >= 2 {
+            true => Some(reader.i64()?).filter(|time| *time >= 0),
+            false => None,
+        };
+        let log_start_offset = match version >= 5 {
+            true => Some(reader.i64()?).filter(|offset| *offset >= 0),
+            false => None,
+        };
+        let (record_errors, error_message) = match version >= 8 {
+            true => (
+                reader.array("record_errors", BatchError::decode_one)?,
+                reader.nullable_string()?,
+            ),
+            false => (vec![], None),
+        };
+        let mut tagged_fields = reader.tagged_fields()?;
+        let current_leader = Reader::take_tagged(&mut tagged_fields, 0, |reader| {
+            let leader = CurrentLeaderResult {
+                id: Some(reader.i32()?).filter(|id| *id >= 0),
+                epoch: Some(reader.i32()?).filter(|epoch| *epoch >= 0),
+            };
+            reader.tagged_fields()?;
+            Ok(leader)
+        })?
+        .filter(|leader| leader.id.is_some());
+
+        Ok(Self {
+            index,
+            error_code,
+            base_offset,
+            log_append_time,
+            log_start_offset,
+            record_errors,
+            error_message,
+            current_leader,
+            tagged_fields,
+        })
+    }
+}
+
+impl BatchError {
+    fn decode_one(reader: &mut Reader) -> Result<Self, Error> {
+        Ok(Self {
+            index: reader.i32()?,
+            message: reader.nullable_string()?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+impl NodeResult {
+    fn decode_one(reader: &mut Reader) -> Result<Self, Error> {
+        let node = Self {
+            id: reader.i32()?,
+            hostname: reader.string("hostname")?,
+            port: reader.i32()?,
+            rack: reader.nullable_string()?,
+        };
+        reader.tagged_fields()?;
+        Ok(node)
+    }
+}
@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::PushTelemetryRequest, response::PushTelemetryResponse, PushTelemetryApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct PushTelemetryApi;
+
+impl Api for PushTelemetryApi {
+    const KEY: ApiKey = ApiKey::PushTelemetry;
+
+    type Request = PushTelemetryRequest;
+    type Response = PushTelemetryResponse;
+}
@@ -0,0 +1,49 @@
+//! Includes definitions for requests to [PushTelemetryApi](super::PushTelemetryApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/PushTelemetryRequest.json>
+
+use crate::{records::RecordBatchCompression, types::prelude::*};
+
+/// A request to push client metrics to the broker.
+///
+/// Use a [TelemetryReporter](crate::telemetry::TelemetryReporter) to build these according to the
+/// broker's subscription.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/PushTelemetryRequest.json>
+pub struct PushTelemetryRequest {
+    /// The unique ID of this client instance.
+    ///
+    /// Versions: 0+
+    pub client_instance_id: Uuid,
+
+    /// The ID of the subscription the metrics were collected for.
+    ///
+    /// Versions: 0+
+    pub subscription_id: i32,
+
+    /// If true, the client is shutting down and this is its final push.
+    ///
+    /// Versions: 0+
+    pub terminating: bool,
+
+    /// The compression applied to [metrics](Self::metrics).
+    ///
+    /// Versions: 0+
+    pub compression_type: RecordBatchCompression,
+
+    /// The metrics, encoded as an OTLP `MetricsData` protobuf message.
+    ///
+    /// Versions: 0+
+    pub metrics: Vec<u8>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
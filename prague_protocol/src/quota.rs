@@ -0,0 +1,145 @@
+//! Includes types shared by the
+//! [DescribeClientQuotasApi](crate::describe_client_quotas::DescribeClientQuotasApi) and
+//! [AlterClientQuotasApi](crate::alter_client_quotas::AlterClientQuotasApi).
+//!
+//! A quota entity is made up of one or more components, such as a user and a client ID. Quotas
+//! can be set on specific entities, or on default entities which apply to every user or client ID
+//! without a more specific quota.
+//!
+//! See: <https://kafka.apache.org/documentation/#design_quotas>
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Quota entity type `{0}` is not valid")]
+    InvalidQuotaEntityType(String),
+
+    #[error("Quota match type `{0}` is not valid")]
+    InvalidQuotaMatchType(i8),
+}
+
+/// The quota key limiting the rate at which a producer may publish, in bytes per second.
+pub const PRODUCER_BYTE_RATE: &str = "producer_byte_rate";
+
+/// The quota key limiting the rate at which a consumer may fetch, in bytes per second.
+pub const CONSUMER_BYTE_RATE: &str = "consumer_byte_rate";
+
+/// The quota key limiting the percentage of broker request handler and network thread time a
+/// client may use.
+pub const REQUEST_PERCENTAGE: &str = "request_percentage";
+
+/// The quota key limiting the rate at which partitions may be created or deleted.
+pub const CONTROLLER_MUTATION_RATE: &str = "controller_mutation_rate";
+
+/// The quota key limiting the rate at which connections may be created from an IP address.
+pub const CONNECTION_CREATION_RATE: &str = "connection_creation_rate";
+
+/// The kind of a quota entity component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuotaEntityType {
+    /// An authenticated user principal.
+    User,
+
+    /// A client ID.
+    ClientId,
+
+    /// A client IP address.
+    Ip,
+}
+
+impl QuotaEntityType {
+    /// Returns the name of this entity type as transmitted over the wire.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::ClientId => "client-id",
+            Self::Ip => "ip",
+        }
+    }
+}
+
+impl TryFrom<&str> for QuotaEntityType {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "user" => Ok(Self::User),
+            "client-id" => Ok(Self::ClientId),
+            "ip" => Ok(Self::Ip),
+            _ => Err(Error::InvalidQuotaEntityType(value.to_owned())),
+        }
+    }
+}
+
+/// A single component of a quota entity.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QuotaEntityComponent {
+    /// The entity type.
+    pub entity_type: QuotaEntityType,
+
+    /// The name of the entity, or None for the default entity of this type.
+    pub name: Option<String>,
+}
+
+impl QuotaEntityComponent {
+    /// Creates a component for the entity with the given name.
+    pub fn named(entity_type: QuotaEntityType, name: impl Into<String>) -> Self {
+        Self {
+            entity_type,
+            name: Some(name.into()),
+        }
+    }
+
+    /// Creates a component for the default entity of the given type.
+    pub fn default_entity(entity_type: QuotaEntityType) -> Self {
+        Self {
+            entity_type,
+            name: None,
+        }
+    }
+}
+
+/// A filter that matches a single component of quota entities.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QuotaFilterComponent {
+    /// The entity type the filter applies to.
+    pub entity_type: QuotaEntityType,
+
+    /// How the entity name is matched.
+    pub matching: QuotaMatch,
+}
+
+/// How a [QuotaFilterComponent] matches entity names.
+///
+/// Over the wire, this is transmitted as a `MatchType` and a nullable `Match` string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuotaMatch {
+    /// Matches the entity with exactly the given name.
+    Exact(String),
+
+    /// Matches the default entity.
+    Default,
+
+    /// Matches any specified entity, but not the default entity.
+    Any,
+}
+
+impl QuotaMatch {
+    /// Returns the match type of this filter as transmitted over the wire.
+    pub fn match_type(&self) -> i8 {
+        match self {
+            Self::Exact(_) => 0,
+            Self::Default => 1,
+            Self::Any => 2,
+        }
+    }
+
+    /// Parses a match type and the accompanying match string.
+    pub fn parse(match_type: i8, name: Option<String>) -> Result<Self, Error> {
+        match (match_type, name) {
+            (0, Some(name)) => Ok(Self::Exact(name)),
+            (0, None) | (1, _) => Ok(Self::Default),
+            (2, _) => Ok(Self::Any),
+            (code, _) => Err(Error::InvalidQuotaMatchType(code)),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Includes types shared by the KRaft quorum APIs.
+//!
+//! The [VoteApi](crate::vote::VoteApi), [BeginQuorumEpochApi](crate::begin_quorum_epoch::BeginQuorumEpochApi),
+//! [EndQuorumEpochApi](crate::end_quorum_epoch::EndQuorumEpochApi) and
+//! [FetchSnapshotApi](crate::fetch_snapshot::FetchSnapshotApi) are only exchanged between
+//! controllers. Clients never send them, but their types are provided so that captured controller
+//! traffic can be decoded.
+//!
+//! ## See also
+//!
+//! - <https://cwiki.apache.org/confluence/display/KAFKA/KIP-595%3A+A+Raft+Protocol+for+the+Metadata+Quorum>
+//! - <https://cwiki.apache.org/confluence/display/KAFKA/KIP-853%3A+KRaft+Controller+Membership+Changes>
+
+use crate::types::prelude::*;
+
+/// A listener on which a controller can be reached.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Listener {
+    /// The name of the listener.
+    pub name: String,
+
+    /// The hostname.
+    pub host: String,
+
+    /// The port.
+    pub port: u16,
+}
+
+/// The endpoint of a node, as sent so that replicas can discover the leader without a separate
+/// metadata request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NodeEndpoint {
+    /// The ID of the node.
+    pub node_id: BrokerId,
+
+    /// The hostname.
+    pub host: String,
+
+    /// The port.
+    pub port: u16,
+}
+
+/// Identifies a snapshot of the metadata log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SnapshotId {
+    /// The offset up to which the snapshot includes records, exclusive.
+    pub end_offset: Offset,
+
+    /// The epoch of the last record included in the snapshot.
+    pub epoch: Epoch,
+}
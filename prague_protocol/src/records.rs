@@ -1,13 +1,21 @@
-use crate::types::prelude::*;
+use std::io::{Read, Write};
+
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+
+use crate::{codec, types::prelude::*};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("Record batch compression code `{0}` is not valid")]
     InvalidRecordBatchCompression(i16),
+
+    #[error("Record batch magic number `{0}` is not supported")]
+    UnsupportedMagicNumber(i8),
 }
 
 ///
 /// See: <https://kafka.apache.org/documentation/#recordbatch>
+#[derive(Debug, Clone)]
 pub struct Records {
     pub base_offset: Offset,
     pub batch_length: i32,
@@ -26,6 +34,145 @@ pub struct Records {
 
 impl Records {
     pub const MAGIC_NUMBER: i8 = 2;
+
+    /// The size in bytes of a record batch without any records.
+    pub const BATCH_OVERHEAD: usize = 61;
+
+    /// The offset of the attributes within an encoded batch, from which the CRC is computed.
+    const CRC_START: usize = 21;
+
+    /// Encodes this batch, compressing its records as set in its attributes.
+    ///
+    /// The [batch_length](Self::batch_length), [crc](Self::crc) and each record's
+    /// [length](Record::length) are computed from the encoded batch, rather than taken from this
+    /// struct.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, codec::Error> {
+        let mut records = codec::Writer::new(false);
+        for record in &self.records {
+            record.encode(&mut records);
+        }
+
+        let records = match self.attributes.compression {
+            RecordBatchCompression::NoCompression => records.into_inner(),
+            RecordBatchCompression::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder
+                    .write_all(&records.into_inner())
+                    .and_then(|_| encoder.finish())
+                    .expect("writing to a Vec cannot fail")
+            }
+            compression => return Err(codec::Error::UnsupportedCompression(compression)),
+        };
+
+        let record_count = i32::try_from(self.records.len())
+            .map_err(|_| codec::Error::InvalidLength(self.records.len() as i64))?;
+
+        let mut writer = codec::Writer::new(false);
+        writer.i64(self.base_offset.0);
+        writer.i32(0);
+        writer.i32(self.partition_leader_epoch.0);
+        writer.i8(Self::MAGIC_NUMBER);
+        writer.u32(0);
+        writer.i16(i16::from(&self.attributes));
+        writer.i32(self.last_offset_delta.0);
+        writer.i64(self.base_timestamp.0);
+        writer.i64(self.max_timestamp.0);
+        writer.i64(self.producer_id.0);
+        writer.i16(self.producer_epoch);
+        writer.i32(self.base_sequence);
+        writer.i32(record_count);
+        writer.put(&records);
+
+        let mut batch = writer.into_inner();
+        let batch_length = (batch.len() - 12) as i32;
+        batch[8..12].copy_from_slice(&batch_length.to_be_bytes());
+        let crc = CyclicRedundancyCheck::crc32c(&batch[Self::CRC_START..]);
+        batch[17..21].copy_from_slice(&crc.0.to_be_bytes());
+
+        Ok(batch)
+    }
+
+    /// Decodes the record batches of a fetched record set, decompressing their records.
+    ///
+    /// The broker may cut the last batch short to stay within the fetch size limits, so a
+    /// trailing batch which is incomplete is dropped.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Vec<Self>, codec::Error> {
+        let mut reader = codec::Reader::new(bytes, false);
+        let mut batches = Vec::new();
+
+        // The base offset and batch length come before the rest of the batch.
+        while reader.remaining().len() >= 12 {
+            let batch_length = i32::from_be_bytes(
+                reader.remaining()[8..12]
+                    .try_into()
+                    .expect("took exactly 4 bytes"),
+            );
+            let batch_length = usize::try_from(batch_length)
+                .map_err(|_| codec::Error::InvalidLength(batch_length.into()))?;
+            if reader.remaining().len() < 12 + batch_length {
+                break;
+            }
+
+            let batch = reader.take(12 + batch_length)?;
+            batches.push(Self::decode_batch(&mut codec::Reader::new(batch, false))?);
+        }
+
+        Ok(batches)
+    }
+
+    fn decode_batch(reader: &mut codec::Reader) -> Result<Self, codec::Error> {
+        let base_offset = Offset(reader.i64()?);
+        let batch_length = reader.i32()?;
+        let partition_leader_epoch = Epoch(reader.i32()?);
+
+        let magic_number = reader.i8()?;
+        if magic_number != Self::MAGIC_NUMBER {
+            return Err(Error::UnsupportedMagicNumber(magic_number).into());
+        }
+
+        let crc = CyclicRedundancyCheck(reader.u32()?);
+        let attributes = RecordBatchAttributes::try_from(reader.i16()?)?;
+        let last_offset_delta = Duration(reader.i32()?);
+        let base_timestamp = Timestamp(reader.i64()?);
+        let max_timestamp = Timestamp(reader.i64()?);
+        let producer_id = ProducerId(reader.i64()?);
+        let producer_epoch = reader.i16()?;
+        let base_sequence = reader.i32()?;
+        let record_count = reader.i32()?;
+
+        let records = match attributes.compression {
+            RecordBatchCompression::NoCompression => reader.remaining().to_vec(),
+            RecordBatchCompression::Gzip => {
+                let mut records = Vec::new();
+                GzDecoder::new(reader.remaining())
+                    .read_to_end(&mut records)
+                    .map_err(|_| codec::Error::UnexpectedEof)?;
+                records
+            }
+            compression => return Err(codec::Error::UnsupportedCompression(compression)),
+        };
+
+        let mut reader = codec::Reader::new(&records, false);
+        let records = (0..record_count)
+            .map(|_| Record::decode(&mut reader))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            base_offset,
+            batch_length,
+            partition_leader_epoch,
+            magic_number,
+            crc,
+            attributes,
+            last_offset_delta,
+            base_timestamp,
+            max_timestamp,
+            producer_id,
+            producer_epoch,
+            base_sequence,
+            records,
+        })
+    }
 }
 
 /// Describes the attributes of a [record batch](RecordBatch).
@@ -38,6 +185,7 @@ impl Records {
 /// - `c`: Whether this record batch is a control batch.
 /// - `d`: Whether this record batch's `base_timestamp` is set as the delete horizon for
 ///   compaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RecordBatchAttributes {
     pub compression: RecordBatchCompression,
     pub timestamp_type: bool,
@@ -61,6 +209,30 @@ impl RecordBatchAttributes {
     const HAS_DELETE_HORIZON_MS_MASK: i16 = 0b1 << 6;
 }
 
+impl From<&RecordBatchAttributes> for i16 {
+    fn from(value: &RecordBatchAttributes) -> Self {
+        let flag = |set: bool, mask: i16| if set { mask } else { 0 };
+
+        i16::from(i8::from(value.compression))
+            | flag(
+                value.timestamp_type,
+                RecordBatchAttributes::TIMESTAMP_TYPE_MASK,
+            )
+            | flag(
+                value.is_transactional,
+                RecordBatchAttributes::IS_TRANSACTIONAL_MASK,
+            )
+            | flag(
+                value.is_control_batch,
+                RecordBatchAttributes::IS_CONTROL_BATCH_MASK,
+            )
+            | flag(
+                value.has_delete_horizon_ms,
+                RecordBatchAttributes::HAS_DELETE_HORIZON_MS_MASK,
+            )
+    }
+}
+
 impl TryFrom<i16> for RecordBatchAttributes {
     type Error = Error;
 
@@ -75,6 +247,7 @@ impl TryFrom<i16> for RecordBatchAttributes {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RecordBatchCompression {
     NoCompression,
     Gzip,
@@ -98,17 +271,260 @@ impl TryFrom<i16> for RecordBatchCompression {
     }
 }
 
+impl TryFrom<i8> for RecordBatchCompression {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        i16::from(value).try_into()
+    }
+}
+
+impl From<RecordBatchCompression> for i8 {
+    fn from(value: RecordBatchCompression) -> Self {
+        match value {
+            RecordBatchCompression::NoCompression => 0,
+            RecordBatchCompression::Gzip => 1,
+            RecordBatchCompression::Snappy => 2,
+            RecordBatchCompression::Lz4 => 3,
+            RecordBatchCompression::Zstd => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Record {
     pub length: VarInt,
     pub attributes: i8,
     pub timestamp_delta: VarLong,
     pub offset_delta: VarInt,
-    pub key: Vec<u8>,
-    pub value: Vec<u8>,
+
+    /// The record's key, or None if it has no key.
+    pub key: Option<Vec<u8>>,
+
+    /// The record's value, or None for a tombstone.
+    pub value: Option<Vec<u8>>,
+
     pub headers: Vec<Header>,
 }
 
+impl Record {
+    /// Returns the size in bytes of this record once encoded, including its length prefix.
+    pub fn encoded_len(&self) -> usize {
+        let body = self.body_len();
+        varint_len(body as i64) + body
+    }
+
+    /// Returns the size in bytes of this record once encoded, excluding its length prefix.
+    fn body_len(&self) -> usize {
+        let nullable_len = |value: Option<&[u8]>| match value {
+            Some(value) => varint_len(value.len() as i64) + value.len(),
+            None => varint_len(-1),
+        };
+
+        1 + varint_len(self.timestamp_delta.0)
+            + varint_len(i64::from(self.offset_delta.0))
+            + nullable_len(self.key.as_deref())
+            + nullable_len(self.value.as_deref())
+            + varint_len(self.headers.len() as i64)
+            + self
+                .headers
+                .iter()
+                .map(|header| {
+                    nullable_len(Some(header.key.as_bytes()))
+                        + nullable_len(header.value.as_deref())
+                })
+                .sum::<usize>()
+    }
+
+    fn decode(reader: &mut codec::Reader) -> Result<Self, codec::Error> {
+        let nullable = |reader: &mut codec::Reader| -> Result<_, codec::Error> {
+            match reader.varint()? {
+                -1 => Ok(None),
+                len => {
+                    let len = usize::try_from(len)
+                        .map_err(|_| codec::Error::InvalidLength(len.into()))?;
+                    Ok(Some(reader.take(len)?.to_vec()))
+                }
+            }
+        };
+
+        let length = VarInt(reader.varint()?);
+        let attributes = reader.i8()?;
+        let timestamp_delta = VarLong(reader.varlong()?);
+        let offset_delta = VarInt(reader.varint()?);
+        let key = nullable(reader)?;
+        let value = nullable(reader)?;
+
+        let header_count = reader.varint()?;
+        let headers = (0..header_count)
+            .map(|_| {
+                let key = nullable(reader)?.ok_or(codec::Error::NullField("header key"))?;
+                Ok(Header {
+                    key: String::from_utf8(key)?,
+                    value: nullable(reader)?,
+                })
+            })
+            .collect::<Result<_, codec::Error>>()?;
+
+        Ok(Self {
+            length,
+            attributes,
+            timestamp_delta,
+            offset_delta,
+            key,
+            value,
+            headers,
+        })
+    }
+
+    fn encode(&self, writer: &mut codec::Writer) {
+        let nullable = |writer: &mut codec::Writer, value: Option<&[u8]>| match value {
+            Some(value) => {
+                writer.varint(value.len() as i32);
+                writer.put(value);
+            }
+            None => writer.varint(-1),
+        };
+
+        writer.varint(self.body_len() as i32);
+        writer.i8(self.attributes);
+        writer.varlong(self.timestamp_delta.0);
+        writer.varint(self.offset_delta.0);
+        nullable(writer, self.key.as_deref());
+        nullable(writer, self.value.as_deref());
+        writer.varint(self.headers.len() as i32);
+
+        for header in &self.headers {
+            nullable(writer, Some(header.key.as_bytes()));
+            nullable(writer, header.value.as_deref());
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Header {
     pub key: String,
-    pub value: Vec<u8>,
+
+    /// The header's value, or None if it has no value.
+    pub value: Option<Vec<u8>>,
+}
+
+/// Returns the size in bytes of a zigzag-encoded variable-length integer.
+fn varint_len(value: i64) -> usize {
+    let zigzag = ((value << 1) ^ (value >> 63)) as u64;
+    (64 - zigzag.leading_zeros() as usize).max(1).div_ceil(7)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a transactional batch of two records, one with a key and a header.
+    fn batch(compression: RecordBatchCompression) -> Records {
+        let record =
+            |timestamp_delta, offset_delta, key: Option<&[u8]>, value: &[u8], headers| Record {
+                length: VarInt(0),
+                attributes: 0,
+                timestamp_delta: VarLong(timestamp_delta),
+                offset_delta: VarInt(offset_delta),
+                key: key.map(<[u8]>::to_vec),
+                value: Some(value.to_vec()),
+                headers,
+            };
+        let header = Header {
+            key: "h".to_owned(),
+            value: Some(b"x".to_vec()),
+        };
+
+        Records {
+            base_offset: Offset(0),
+            batch_length: 0,
+            partition_leader_epoch: Epoch(-1),
+            magic_number: Records::MAGIC_NUMBER,
+            crc: CyclicRedundancyCheck(0),
+            attributes: RecordBatchAttributes {
+                compression,
+                timestamp_type: false,
+                is_transactional: true,
+                is_control_batch: false,
+                has_delete_horizon_ms: false,
+            },
+            last_offset_delta: Duration(1),
+            base_timestamp: Timestamp(1000),
+            max_timestamp: Timestamp(1005),
+            producer_id: ProducerId(7),
+            producer_epoch: 3,
+            base_sequence: 42,
+            records: vec![
+                record(0, 0, Some(b"k"), b"v1", vec![header]),
+                record(5, 1, None, b"v2", vec![]),
+            ],
+        }
+    }
+
+    #[test]
+    fn to_bytes() {
+        let records = batch(RecordBatchCompression::NoCompression);
+
+        let expected: &[u8] = &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // base offset
+            0x00, 0x00, 0x00, 0x48, // batch length
+            0xff, 0xff, 0xff, 0xff, // partition leader epoch
+            0x02, // magic
+            0xc5, 0x43, 0x56, 0xed, // crc
+            0x00, 0x10, // attributes
+            0x00, 0x00, 0x00, 0x01, // last offset delta
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xe8, // base timestamp
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xed, // max timestamp
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, // producer ID
+            0x00, 0x03, // producer epoch
+            0x00, 0x00, 0x00, 0x2a, // base sequence
+            0x00, 0x00, 0x00, 0x02, // record count
+            // first record
+            0x1a, 0x00, 0x00, 0x00, 0x02, 0x6b, 0x04, 0x76, 0x31, 0x02, 0x02, 0x68, 0x02, 0x78,
+            // second record
+            0x10, 0x00, 0x0a, 0x02, 0x01, 0x04, 0x76, 0x32, 0x00,
+        ];
+
+        let bytes = records.to_bytes().unwrap();
+        assert_eq!(bytes, expected);
+        assert_eq!(
+            bytes.len(),
+            Records::BATCH_OVERHEAD
+                + records
+                    .records
+                    .iter()
+                    .map(Record::encoded_len)
+                    .sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn from_bytes() {
+        for compression in [
+            RecordBatchCompression::NoCompression,
+            RecordBatchCompression::Gzip,
+        ] {
+            let bytes = batch(compression).to_bytes().unwrap();
+
+            // A second batch which the broker cut short is dropped.
+            let mut fetched = bytes.clone();
+            fetched.extend_from_slice(&bytes[..bytes.len() - 1]);
+
+            let batches = Records::from_bytes(&fetched).unwrap();
+            assert_eq!(batches.len(), 1);
+
+            let decoded = &batches[0];
+            assert_eq!(decoded.attributes.compression, compression);
+            assert!(decoded.attributes.is_transactional);
+            assert_eq!(decoded.producer_id, ProducerId(7));
+            assert_eq!(decoded.base_sequence, 42);
+            assert_eq!(decoded.records.len(), 2);
+            assert_eq!(decoded.records[0].key.as_deref(), Some(&b"k"[..]));
+            assert_eq!(decoded.records[0].headers[0].key, "h");
+            assert_eq!(decoded.records[1].key, None);
+            assert_eq!(decoded.records[1].timestamp_delta.0, 5);
+            assert_eq!(decoded.to_bytes().unwrap(), bytes);
+        }
+    }
 }
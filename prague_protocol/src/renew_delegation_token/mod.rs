@@ -0,0 +1,22 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::RenewDelegationTokenRequest, response::RenewDelegationTokenResponse,
+        RenewDelegationTokenApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct RenewDelegationTokenApi;
+
+impl Api for RenewDelegationTokenApi {
+    const KEY: ApiKey = ApiKey::RenewDelegationToken;
+
+    type Request = RenewDelegationTokenRequest;
+    type Response = RenewDelegationTokenResponse;
+}
@@ -0,0 +1,33 @@
+//! Includes definitions for requests to
+//! [RenewDelegationTokenApi](super::RenewDelegationTokenApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/RenewDelegationTokenRequest.json>
+
+use crate::{delegation_token::DelegationTokenHmac, types::prelude::*};
+
+/// A request to renew a delegation token, extending its expiry.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/RenewDelegationTokenRequest.json>
+pub struct RenewDelegationTokenRequest {
+    /// The HMAC of the token.
+    ///
+    /// Versions: 0+
+    pub hmac: DelegationTokenHmac,
+
+    /// How long in milliseconds to extend the token's expiry by. The expiry is never extended
+    /// beyond the token's max timestamp.
+    ///
+    /// Versions: 0+
+    pub renew_period: i64,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
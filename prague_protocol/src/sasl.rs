@@ -0,0 +1,197 @@
+//! Includes the client side of the SASL mechanisms supported by this crate, as used by the
+//! [SaslHandshakeApi](crate::sasl_handshake::SaslHandshakeApi) and
+//! [SaslAuthenticateApi](crate::sasl_authenticate::SaslAuthenticateApi).
+//!
+//! A client first sends a handshake naming the [mechanism](SaslCredentials::mechanism_name).
+//! It then sends each message produced by a [SaslAuthenticator] in an authenticate request,
+//! feeding each response back in, until the authenticator is complete.
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/documentation/#security_sasl>
+//! - <https://cwiki.apache.org/confluence/display/KAFKA/KIP-152+-+Improve+diagnostics+for+SASL+authentication+failures>
+
+use std::fmt;
+
+use crate::{
+    delegation_token::{DelegationToken, TOKEN_AUTH_EXTENSION},
+    scram::{self, ScramClient, ScramMechanism},
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("SASL server message was not valid UTF-8")]
+    InvalidUtf8,
+
+    #[error("SASL server sent a message after authentication completed")]
+    AlreadyComplete,
+
+    #[error("SASL exchange was continued without the server's message")]
+    MissingServerMessage,
+
+    #[error(transparent)]
+    Scram(#[from] scram::Error),
+}
+
+/// The credentials with which to authenticate.
+#[derive(Clone)]
+pub enum SaslCredentials {
+    /// Authenticates with the `PLAIN` mechanism, which sends the password in the clear and should
+    /// only be used over TLS.
+    Plain { username: String, password: String },
+
+    /// Authenticates with a SCRAM mechanism.
+    Scram {
+        mechanism: ScramMechanism,
+        username: String,
+        password: String,
+
+        /// Whether the credentials belong to a delegation token rather than a user.
+        token_auth: bool,
+    },
+}
+
+impl SaslCredentials {
+    /// Creates credentials for the `PLAIN` mechanism.
+    pub fn plain(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self::Plain {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    /// Creates credentials for a SCRAM mechanism.
+    pub fn scram(
+        mechanism: ScramMechanism,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        Self::Scram {
+            mechanism,
+            username: username.into(),
+            password: password.into(),
+            token_auth: false,
+        }
+    }
+
+    /// Creates credentials which authenticate with a delegation token over a SCRAM mechanism.
+    pub fn delegation_token(mechanism: ScramMechanism, token: &DelegationToken) -> Self {
+        let (username, password) = token.scram_credentials();
+
+        Self::Scram {
+            mechanism,
+            username: username.to_owned(),
+            password,
+            token_auth: true,
+        }
+    }
+
+    /// Returns the name of the mechanism, as sent in the handshake.
+    pub fn mechanism_name(&self) -> &'static str {
+        match self {
+            Self::Plain { .. } => "PLAIN",
+            Self::Scram { mechanism, .. } => mechanism.mechanism_name(),
+        }
+    }
+
+    /// Starts a new exchange with these credentials.
+    pub fn authenticator(&self) -> Result<SaslAuthenticator, Error> {
+        let state = match self {
+            Self::Plain { username, password } => {
+                State::Plain(format!("\0{username}\0{password}").into_bytes())
+            }
+            Self::Scram {
+                mechanism,
+                username,
+                password,
+                token_auth,
+            } => {
+                let extensions = match token_auth {
+                    true => vec![(TOKEN_AUTH_EXTENSION, "true")],
+                    false => vec![],
+                };
+                State::ScramFirst(ScramClient::new(
+                    *mechanism, username, password, extensions,
+                )?)
+            }
+        };
+
+        Ok(SaslAuthenticator { state })
+    }
+}
+
+impl fmt::Debug for SaslCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plain { username, .. } => f
+                .debug_struct("Plain")
+                .field("username", username)
+                .finish_non_exhaustive(),
+            Self::Scram {
+                mechanism,
+                username,
+                token_auth,
+                ..
+            } => f
+                .debug_struct("Scram")
+                .field("mechanism", mechanism)
+                .field("username", username)
+                .field("token_auth", token_auth)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Produces the client's messages of a single SASL exchange.
+pub struct SaslAuthenticator {
+    state: State,
+}
+
+enum State {
+    Plain(Vec<u8>),
+    ScramFirst(ScramClient),
+    ScramFinal(ScramClient),
+    Verifying(ScramClient),
+    AwaitingSuccess,
+    Complete,
+}
+
+impl SaslAuthenticator {
+    /// Returns the client's next message, given the server's last message or None to start the
+    /// exchange. Returns None once the exchange is complete.
+    pub fn step(&mut self, server_message: Option<&[u8]>) -> Result<Option<Vec<u8>>, Error> {
+        let server_message = server_message
+            .map(|message| std::str::from_utf8(message).map_err(|_| Error::InvalidUtf8))
+            .transpose()?;
+
+        let (state, message) = match (
+            std::mem::replace(&mut self.state, State::Complete),
+            server_message,
+        ) {
+            (State::Plain(initial), _) => (State::AwaitingSuccess, Some(initial)),
+            (State::ScramFirst(client), _) => {
+                let message = client.client_first_message().into_bytes();
+                (State::ScramFinal(client), Some(message))
+            }
+            (State::ScramFinal(mut client), Some(server_first)) => {
+                let message = client.client_final_message(server_first)?.into_bytes();
+                (State::Verifying(client), Some(message))
+            }
+            (State::Verifying(client), Some(server_final)) => {
+                client.verify_server_final_message(server_final)?;
+                (State::Complete, None)
+            }
+            (State::AwaitingSuccess, Some(_)) => (State::Complete, None),
+            (State::Complete, _) => return Err(Error::AlreadyComplete),
+            (_, None) => return Err(Error::MissingServerMessage),
+        };
+
+        self.state = state;
+        Ok(message)
+    }
+
+    /// Returns whether the exchange is complete.
+    pub fn is_complete(&self) -> bool {
+        matches!(self.state, State::Complete)
+    }
+}
@@ -0,0 +1,27 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::SaslAuthenticateRequest, response::SaslAuthenticateResponse, SaslAuthenticateApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct SaslAuthenticateApi;
+
+impl Api for SaslAuthenticateApi {
+    const KEY: ApiKey = ApiKey::SaslAuthenticate;
+
+    type Request = SaslAuthenticateRequest;
+    type Response = SaslAuthenticateResponse;
+}
+
+impl VersionedApi for SaslAuthenticateApi {
+    const MIN_VERSION: i16 = 0;
+    const MAX_VERSION: i16 = 2;
+    const FLEXIBLE_VERSION: Option<i16> = Some(2);
+}
@@ -0,0 +1,37 @@
+//! Includes definitions for requests to [SaslAuthenticateApi](super::SaslAuthenticateApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/SaslAuthenticateRequest.json>
+
+use crate::{
+    codec::{Encode, Error, Writer},
+    types::prelude::*,
+};
+
+/// A request carrying one message of a SASL exchange from the client.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/SaslAuthenticateRequest.json>
+pub struct SaslAuthenticateRequest {
+    /// The SASL authentication bytes from the client, as defined by the SASL mechanism.
+    ///
+    /// Versions: 0+
+    pub auth_bytes: Vec<u8>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Encode for SaslAuthenticateRequest {
+    fn encode(&self, writer: &mut Writer, _version: i16) -> Result<(), Error> {
+        writer.bytes(&self.auth_bytes);
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+//! Includes definitions for responses from [SaslAuthenticateApi](super::SaslAuthenticateApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/SaslAuthenticateResponse.json>
+
+use crate::{
+    codec::{Decode, Error, Reader},
+    error_code::ErrorCode,
+    types::prelude::*,
+};
+
+/// A response message associated with
+/// [SaslAuthenticateRequest](super::request::SaslAuthenticateRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/SaslAuthenticateResponse.json>
+pub struct SaslAuthenticateResponse {
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The error message, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_message: Option<String>,
+
+    /// The SASL authentication bytes from the server, as defined by the SASL mechanism.
+    ///
+    /// Versions: 0+
+    pub auth_bytes: Vec<u8>,
+
+    /// The number of milliseconds after which the broker will close the connection unless the
+    /// client reauthenticates, or None if the session does not expire.
+    ///
+    /// Versions: 1+
+    pub session_lifetime_ms: Option<i64>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 2+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Decode for SaslAuthenticateResponse {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            error_code: reader.error_code()?,
+            error_message: reader.nullable_string()?,
+            auth_bytes: reader.bytes("auth_bytes")?,
+            session_lifetime_ms: match version >= 1 {
+                true => Some(reader.i64()?).filter(|lifetime| *lifetime > 0),
+                false => None,
+            },
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
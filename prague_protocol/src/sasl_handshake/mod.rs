@@ -0,0 +1,27 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::SaslHandshakeRequest, response::SaslHandshakeResponse, SaslHandshakeApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct SaslHandshakeApi;
+
+impl Api for SaslHandshakeApi {
+    const KEY: ApiKey = ApiKey::SaslHandshake;
+
+    type Request = SaslHandshakeRequest;
+    type Response = SaslHandshakeResponse;
+}
+
+impl VersionedApi for SaslHandshakeApi {
+    const MIN_VERSION: i16 = 0;
+    const MAX_VERSION: i16 = 1;
+    const FLEXIBLE_VERSION: Option<i16> = None;
+}
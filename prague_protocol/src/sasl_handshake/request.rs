@@ -0,0 +1,32 @@
+//! Includes definitions for requests to [SaslHandshakeApi](super::SaslHandshakeApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/SaslHandshakeRequest.json>
+
+use crate::codec::{Encode, Error, Writer};
+
+/// A request to start authenticating with a SASL mechanism.
+///
+/// With version 1, the exchange that follows is carried by
+/// [SaslAuthenticateApi](crate::sasl_authenticate::SaslAuthenticateApi) requests. With version 0,
+/// it is carried by raw frames without Kafka headers, which this crate does not support.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/SaslHandshakeRequest.json>
+pub struct SaslHandshakeRequest {
+    /// The SASL mechanism chosen by the client, e.g. `SCRAM-SHA-512`.
+    ///
+    /// Versions: 0+
+    pub mechanism: String,
+}
+
+impl Encode for SaslHandshakeRequest {
+    fn encode(&self, writer: &mut Writer, _version: i16) -> Result<(), Error> {
+        writer.string(&self.mechanism);
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+//! Includes definitions for responses from [SaslHandshakeApi](super::SaslHandshakeApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/SaslHandshakeResponse.json>
+
+use crate::{
+    codec::{Decode, Error, Reader},
+    error_code::ErrorCode,
+};
+
+/// A response message associated with
+/// [SaslHandshakeRequest](super::request::SaslHandshakeRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/SaslHandshakeResponse.json>
+pub struct SaslHandshakeResponse {
+    /// The error code, or None if there was no error. This is
+    /// [UnsupportedSaslMechanism](ErrorCode::UnsupportedSaslMechanism) if the broker does not
+    /// enable the chosen mechanism.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The mechanisms enabled on the broker.
+    ///
+    /// Versions: 0+
+    pub mechanisms: Vec<String>,
+}
+
+impl Decode for SaslHandshakeResponse {
+    fn decode(reader: &mut Reader, _version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            error_code: reader.error_code()?,
+            mechanisms: reader.array("mechanisms", |reader| reader.string("mechanism"))?,
+        })
+    }
+}
@@ -0,0 +1,470 @@
+//! Includes types shared by the
+//! [DescribeUserScramCredentialsApi](crate::describe_user_scram_credentials::DescribeUserScramCredentialsApi)
+//! and
+//! [AlterUserScramCredentialsApi](crate::alter_user_scram_credentials::AlterUserScramCredentialsApi),
+//! along with client-side derivation of SCRAM credentials and the client side of a SCRAM exchange.
+//!
+//! ## See also
+//!
+//! - <https://datatracker.ietf.org/doc/html/rfc5802>
+//! - <https://cwiki.apache.org/confluence/display/KAFKA/KIP-84%3A+Support+SASL+SCRAM+mechanisms>
+//! - <https://cwiki.apache.org/confluence/display/KAFKA/KIP-554%3A+Add+Broker-side+SCRAM+Config+API>
+
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine,
+};
+use hmac::{digest::core_api::BlockSizeUser, Mac, SimpleHmac};
+use rand::RngCore;
+use sha2::{Digest, Sha256, Sha512};
+
+use crate::alter_user_scram_credentials::request::ScramCredentialUpsertion;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("SCRAM mechanism `{0}` is not valid")]
+    InvalidScramMechanism(i8),
+
+    #[error("SCRAM mechanism `{0:?}` cannot be used to derive credentials")]
+    UnsupportedScramMechanism(ScramMechanism),
+
+    #[error("Iteration count `{iterations}` is outside of the allowed range for `{mechanism:?}`")]
+    InvalidIterations {
+        mechanism: ScramMechanism,
+        iterations: i32,
+    },
+
+    #[error("SCRAM server message `{0}` was not valid")]
+    InvalidServerMessage(String),
+
+    #[error("SCRAM server rejected authentication: {0}")]
+    ServerError(String),
+
+    #[error("SCRAM server nonce does not extend the client nonce")]
+    NonceMismatch,
+
+    #[error("SCRAM server signature did not match")]
+    InvalidServerSignature,
+}
+
+/// A SCRAM mechanism supported by Kafka.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScramMechanism {
+    /// Represents any mechanism which this client cannot understand.
+    Unknown,
+
+    /// SCRAM using SHA-256.
+    ScramSha256,
+
+    /// SCRAM using SHA-512.
+    ScramSha512,
+}
+
+impl ScramMechanism {
+    /// The fewest iterations brokers accept for a credential.
+    pub const MIN_ITERATIONS: i32 = 4096;
+
+    /// The most iterations brokers accept for a credential.
+    pub const MAX_ITERATIONS: i32 = 16384;
+
+    /// Returns the SASL mechanism name of this mechanism.
+    pub fn mechanism_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "UNKNOWN",
+            Self::ScramSha256 => "SCRAM-SHA-256",
+            Self::ScramSha512 => "SCRAM-SHA-512",
+        }
+    }
+}
+
+impl TryFrom<i8> for ScramMechanism {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::ScramSha256),
+            2 => Ok(Self::ScramSha512),
+            code => Err(Error::InvalidScramMechanism(code)),
+        }
+    }
+}
+
+/// A SCRAM credential derived on the client, so that the plaintext password never leaves it.
+///
+/// Only the [salted password](Self::salted_password) is sent to the broker, which stores the
+/// [stored key](Self::stored_key) and [server key](Self::server_key) derived from it. These are
+/// exposed so that tooling can verify or record what the broker will store.
+pub struct ScramCredential {
+    /// The mechanism this credential is for.
+    pub mechanism: ScramMechanism,
+
+    /// The random salt.
+    pub salt: Vec<u8>,
+
+    /// The number of iterations used to derive [salted_password](Self::salted_password).
+    pub iterations: i32,
+
+    /// `Hi(password, salt, iterations)`, i.e. PBKDF2 with the mechanism's HMAC.
+    pub salted_password: Vec<u8>,
+
+    /// `H(HMAC(salted_password, "Client Key"))`.
+    pub stored_key: Vec<u8>,
+
+    /// `HMAC(salted_password, "Server Key")`.
+    pub server_key: Vec<u8>,
+}
+
+impl ScramCredential {
+    /// The length in bytes of salts generated by [with_random_salt](Self::with_random_salt).
+    pub const SALT_LENGTH: usize = 32;
+
+    /// Derives a credential from a password with a randomly generated salt.
+    pub fn with_random_salt(
+        mechanism: ScramMechanism,
+        password: &str,
+        iterations: i32,
+    ) -> Result<Self, Error> {
+        let mut salt = vec![0; Self::SALT_LENGTH];
+        rand::thread_rng().fill_bytes(&mut salt);
+        Self::derive(mechanism, password, salt, iterations)
+    }
+
+    /// Derives a credential from a password and salt.
+    pub fn derive(
+        mechanism: ScramMechanism,
+        password: &str,
+        salt: Vec<u8>,
+        iterations: i32,
+    ) -> Result<Self, Error> {
+        if !(ScramMechanism::MIN_ITERATIONS..=ScramMechanism::MAX_ITERATIONS).contains(&iterations)
+        {
+            return Err(Error::InvalidIterations {
+                mechanism,
+                iterations,
+            });
+        }
+
+        let (salted_password, stored_key, server_key) = match mechanism {
+            ScramMechanism::ScramSha256 => {
+                derive_keys::<Sha256>(password.as_bytes(), &salt, iterations as u32)
+            }
+            ScramMechanism::ScramSha512 => {
+                derive_keys::<Sha512>(password.as_bytes(), &salt, iterations as u32)
+            }
+            ScramMechanism::Unknown => return Err(Error::UnsupportedScramMechanism(mechanism)),
+        };
+
+        Ok(Self {
+            mechanism,
+            salt,
+            iterations,
+            salted_password,
+            stored_key,
+            server_key,
+        })
+    }
+
+    /// Creates the upsertion that sets this credential for the given user.
+    pub fn into_upsertion(self, name: impl Into<String>) -> ScramCredentialUpsertion {
+        ScramCredentialUpsertion {
+            name: name.into(),
+            mechanism: self.mechanism,
+            iterations: self.iterations,
+            salt: self.salt,
+            salted_password: self.salted_password,
+            tagged_fields: Default::default(),
+        }
+    }
+}
+
+/// The client side of a SCRAM exchange, as used to authenticate with
+/// [SaslAuthenticateApi](crate::sasl_authenticate::SaslAuthenticateApi).
+///
+/// The client sends [client_first_message](Self::client_first_message), answers the server's
+/// first message with [client_final_message](Self::client_final_message), and then checks the
+/// server's final message with [verify_server_final_message](Self::verify_server_final_message),
+/// which proves that the server also knows the credential.
+pub struct ScramClient {
+    mechanism: ScramMechanism,
+    password: String,
+    nonce: String,
+    client_first_bare: String,
+    server_signature: Option<Vec<u8>>,
+}
+
+impl ScramClient {
+    /// The GS2 header of every client first message, which declares that channel binding is not
+    /// supported.
+    const GS2_HEADER: &'static str = "n,,";
+
+    /// Starts an exchange for the given user, with the given SCRAM extensions, such as
+    /// [TOKEN_AUTH_EXTENSION](crate::delegation_token::TOKEN_AUTH_EXTENSION).
+    pub fn new<'a>(
+        mechanism: ScramMechanism,
+        username: &str,
+        password: &str,
+        extensions: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Self, Error> {
+        let mut nonce = [0; 24];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        Self::with_nonce(
+            mechanism,
+            username,
+            password,
+            extensions,
+            URL_SAFE_NO_PAD.encode(nonce),
+        )
+    }
+
+    /// Starts an exchange with the given client nonce.
+    fn with_nonce<'a>(
+        mechanism: ScramMechanism,
+        username: &str,
+        password: &str,
+        extensions: impl IntoIterator<Item = (&'a str, &'a str)>,
+        nonce: String,
+    ) -> Result<Self, Error> {
+        if mechanism == ScramMechanism::Unknown {
+            return Err(Error::UnsupportedScramMechanism(mechanism));
+        }
+
+        let username = username.replace('=', "=3D").replace(',', "=2C");
+        let extensions: String = extensions
+            .into_iter()
+            .map(|(key, value)| format!(",{key}={value}"))
+            .collect();
+
+        Ok(Self {
+            mechanism,
+            password: password.to_owned(),
+            client_first_bare: format!("n={username},r={nonce}{extensions}"),
+            nonce,
+            server_signature: None,
+        })
+    }
+
+    /// Returns the first message of the exchange.
+    pub fn client_first_message(&self) -> String {
+        format!("{}{}", Self::GS2_HEADER, self.client_first_bare)
+    }
+
+    /// Returns the client's proof in answer to the server's first message.
+    pub fn client_final_message(&mut self, server_first: &str) -> Result<String, Error> {
+        let attributes = parse_attributes(server_first)?;
+        let attribute = |name: &str| {
+            attributes
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| *value)
+                .ok_or_else(|| Error::InvalidServerMessage(server_first.to_owned()))
+        };
+
+        let nonce = attribute("r")?;
+        if !nonce.starts_with(&self.nonce) || nonce.len() == self.nonce.len() {
+            return Err(Error::NonceMismatch);
+        }
+
+        let salt = STANDARD
+            .decode(attribute("s")?)
+            .map_err(|_| Error::InvalidServerMessage(server_first.to_owned()))?;
+        let iterations: i32 = attribute("i")?
+            .parse()
+            .map_err(|_| Error::InvalidServerMessage(server_first.to_owned()))?;
+        if iterations < ScramMechanism::MIN_ITERATIONS {
+            return Err(Error::InvalidIterations {
+                mechanism: self.mechanism,
+                iterations,
+            });
+        }
+
+        let without_proof = format!("c={},r={nonce}", STANDARD.encode(Self::GS2_HEADER));
+        let auth_message = format!("{},{server_first},{without_proof}", self.client_first_bare);
+
+        let password = self.password.as_bytes();
+        let (proof, server_signature) = match self.mechanism {
+            ScramMechanism::ScramSha256 => {
+                prove::<Sha256>(password, &salt, iterations as u32, &auth_message)
+            }
+            ScramMechanism::ScramSha512 => {
+                prove::<Sha512>(password, &salt, iterations as u32, &auth_message)
+            }
+            ScramMechanism::Unknown => {
+                return Err(Error::UnsupportedScramMechanism(self.mechanism))
+            }
+        };
+
+        self.server_signature = Some(server_signature);
+        Ok(format!("{without_proof},p={}", STANDARD.encode(proof)))
+    }
+
+    /// Checks the server's final message, which either carries its signature or an error.
+    pub fn verify_server_final_message(&self, server_final: &str) -> Result<(), Error> {
+        let attributes = parse_attributes(server_final)?;
+
+        match attributes.first() {
+            Some(("e", error)) => Err(Error::ServerError((*error).to_owned())),
+            Some(("v", signature)) => {
+                let signature = STANDARD
+                    .decode(signature)
+                    .map_err(|_| Error::InvalidServerMessage(server_final.to_owned()))?;
+
+                match self.server_signature.as_ref() == Some(&signature) {
+                    true => Ok(()),
+                    false => Err(Error::InvalidServerSignature),
+                }
+            }
+            _ => Err(Error::InvalidServerMessage(server_final.to_owned())),
+        }
+    }
+}
+
+/// Splits a SCRAM message into its `key=value` attributes.
+fn parse_attributes(message: &str) -> Result<Vec<(&str, &str)>, Error> {
+    message
+        .split(',')
+        .map(|attribute| {
+            attribute
+                .split_once('=')
+                .ok_or_else(|| Error::InvalidServerMessage(message.to_owned()))
+        })
+        .collect()
+}
+
+/// Returns `HMAC(key, message)` for the given digest.
+fn hmac<D>(key: &[u8], message: &[u8]) -> Vec<u8>
+where
+    D: Digest + BlockSizeUser + Clone,
+{
+    let mut mac =
+        <SimpleHmac<D> as Mac>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Returns `Hi(password, salt, iterations)`, i.e. PBKDF2 with the digest's HMAC.
+fn salt_password<D>(password: &[u8], salt: &[u8], iterations: u32) -> Vec<u8>
+where
+    D: Digest + BlockSizeUser + Clone + Sync,
+{
+    let mut salted_password = vec![0; <D as Digest>::output_size()];
+    pbkdf2::pbkdf2::<SimpleHmac<D>>(password, salt, iterations, &mut salted_password)
+        .expect("HMAC accepts keys of any length");
+    salted_password
+}
+
+/// Returns the salted password, stored key and server key for the given digest.
+fn derive_keys<D>(password: &[u8], salt: &[u8], iterations: u32) -> (Vec<u8>, Vec<u8>, Vec<u8>)
+where
+    D: Digest + BlockSizeUser + Clone + Sync,
+{
+    let salted_password = salt_password::<D>(password, salt, iterations);
+    let client_key = hmac::<D>(&salted_password, b"Client Key");
+    let server_key = hmac::<D>(&salted_password, b"Server Key");
+    let stored_key = D::digest(&client_key).to_vec();
+
+    (salted_password, stored_key, server_key)
+}
+
+/// Returns the client proof and the expected server signature for the given auth message.
+fn prove<D>(password: &[u8], salt: &[u8], iterations: u32, auth_message: &str) -> (Vec<u8>, Vec<u8>)
+where
+    D: Digest + BlockSizeUser + Clone + Sync,
+{
+    let salted_password = salt_password::<D>(password, salt, iterations);
+    let client_key = hmac::<D>(&salted_password, b"Client Key");
+    let server_key = hmac::<D>(&salted_password, b"Server Key");
+    let stored_key = D::digest(&client_key);
+
+    let client_signature = hmac::<D>(&stored_key, auth_message.as_bytes());
+    let proof = client_key
+        .iter()
+        .zip(client_signature)
+        .map(|(key, signature)| key ^ signature)
+        .collect();
+
+    (proof, hmac::<D>(&server_key, auth_message.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The SCRAM-SHA-256 example exchange from RFC 7677, section 3. SCRAM-SHA-1, which the
+    // examples of RFC 5802 use, is not supported by Kafka.
+    const CLIENT_NONCE: &str = "rOprNGfwEbeRWgbNEkqO";
+    const SALT: &str = "W22ZaJ0SNY7soEsUEjb6gQ==";
+    const SERVER_FIRST: &str =
+        "r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,s=W22ZaJ0SNY7soEsUEjb6gQ==,i=4096";
+    const CLIENT_FINAL: &str = "c=biws,r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,\
+                                p=dHzbZapWIk4jUhN+Ute9ytag9zjfMHgsqmmiz7AndVQ=";
+    const SERVER_FINAL: &str = "v=6rriTRBi23WpRR/wtup+mMhUZUn/dB5nLTJRsjl95G4=";
+
+    fn client() -> ScramClient {
+        ScramClient::with_nonce(
+            ScramMechanism::ScramSha256,
+            "user",
+            "pencil",
+            [],
+            CLIENT_NONCE.to_owned(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn derive() {
+        let salt = STANDARD.decode(SALT).unwrap();
+        let credential =
+            ScramCredential::derive(ScramMechanism::ScramSha256, "pencil", salt, 4096).unwrap();
+
+        assert_eq!(
+            STANDARD.encode(&credential.salted_password),
+            "xKSVEDI6tPlSysH6mUQZOeeOp01r6B3fcJbodRPcYV0="
+        );
+        assert_eq!(
+            STANDARD.encode(&credential.stored_key),
+            "WG5d8oPm3OtcPnkdi4Uo7BkeZkBFzpcXkuLmtbsT4qY="
+        );
+        assert_eq!(
+            STANDARD.encode(&credential.server_key),
+            "wfPLwcE6nTWhTAmQ7tl2KeoiWGPlZqQxSrmfPwDl2dU="
+        );
+    }
+
+    #[test]
+    fn exchange() {
+        let mut client = client();
+        assert_eq!(
+            client.client_first_message(),
+            "n,,n=user,r=rOprNGfwEbeRWgbNEkqO"
+        );
+        assert_eq!(
+            client.client_final_message(SERVER_FIRST).unwrap(),
+            CLIENT_FINAL
+        );
+        client.verify_server_final_message(SERVER_FINAL).unwrap();
+    }
+
+    #[test]
+    fn tampered_server_signature() {
+        let mut client = client();
+        client.client_final_message(SERVER_FIRST).unwrap();
+
+        let mut signature = STANDARD.decode(&SERVER_FINAL[2..]).unwrap();
+        signature[0] ^= 1;
+        let server_final = format!("v={}", STANDARD.encode(signature));
+
+        assert!(matches!(
+            client.verify_server_final_message(&server_final),
+            Err(Error::InvalidServerSignature)
+        ));
+    }
+
+    #[test]
+    fn server_signature_before_client_final() {
+        assert!(matches!(
+            client().verify_server_final_message(SERVER_FINAL),
+            Err(Error::InvalidServerSignature)
+        ));
+    }
+}
@@ -0,0 +1,746 @@
+//! Includes a client-side reporter for pushing client metrics to brokers, as used by the
+//! [GetTelemetrySubscriptionsApi](crate::get_telemetry_subscriptions::GetTelemetrySubscriptionsApi)
+//! and [PushTelemetryApi](crate::push_telemetry::PushTelemetryApi).
+//!
+//! The broker tells each client which metrics it wants, how often to push them and how they may be
+//! compressed. Metrics are pushed as OTLP `MetricsData` protobuf messages, so that cluster
+//! operators can forward them to their existing observability tooling.
+//!
+//! ## See also
+//!
+//! - <https://cwiki.apache.org/confluence/display/KAFKA/KIP-714%3A+Client+metrics+and+observability>
+//! - <https://github.com/open-telemetry/opentelemetry-proto/blob/main/opentelemetry/proto/metrics/v1/metrics.proto>
+
+use std::{collections::HashMap, io::Write};
+
+use flate2::{write::GzEncoder, Compression};
+use rand::Rng;
+
+use crate::{
+    error_code::ErrorCode,
+    get_telemetry_subscriptions::{
+        request::GetTelemetrySubscriptionsRequest, response::GetTelemetrySubscriptionsResponse,
+    },
+    push_telemetry::{request::PushTelemetryRequest, response::PushTelemetryResponse},
+    records::RecordBatchCompression,
+    types::prelude::*,
+};
+
+/// The compression types this reporter can apply, in order of preference.
+const SUPPORTED_COMPRESSION_TYPES: [RecordBatchCompression; 1] = [RecordBatchCompression::Gzip];
+
+/// How long to wait before retrying a subscription request when the broker has not told us
+/// otherwise.
+const DEFAULT_PUSH_INTERVAL: Duration = Duration(5 * 60 * 1000);
+
+/// A single metric collected by the client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metric {
+    /// The metric name, e.g. `org.apache.kafka.producer.record.queue.time.max`. This is matched
+    /// against the prefixes requested by the broker.
+    pub name: String,
+
+    /// A description of the metric.
+    pub description: String,
+
+    /// The unit of the metric, e.g. `ms`.
+    pub unit: String,
+
+    /// Attributes distinguishing this metric from others with the same name, such as a topic.
+    pub attributes: Vec<(String, String)>,
+
+    /// The current value.
+    pub value: MetricValue,
+}
+
+/// The value of a [Metric].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MetricValue {
+    /// A value which may go up or down, such as a queue size.
+    Gauge(f64),
+
+    /// A monotonically increasing total since the client started, such as a request count. This
+    /// is converted to a delta if the broker requests delta temporality.
+    Counter(f64),
+}
+
+/// A subscription to client metrics, as assigned by the broker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelemetrySubscription {
+    /// The ID of the subscription.
+    pub subscription_id: i32,
+
+    /// The compression types the broker accepts, in order of preference.
+    pub accepted_compression_types: Vec<RecordBatchCompression>,
+
+    /// The interval at which metrics should be pushed.
+    pub push_interval: Duration,
+
+    /// The largest serialized metrics payload the broker will accept.
+    pub telemetry_max_bytes: i32,
+
+    /// Whether counters should be pushed as deltas rather than cumulative totals.
+    pub delta_temporality: bool,
+
+    /// The prefixes of the requested metrics.
+    pub requested_metrics: Vec<String>,
+}
+
+impl TelemetrySubscription {
+    /// Returns whether the broker requested the metric with the given name.
+    pub fn is_requested(&self, name: &str) -> bool {
+        self.requested_metrics
+            .iter()
+            .any(|prefix| name.starts_with(prefix.as_str()))
+    }
+
+    /// Returns the compression type to push metrics with, i.e. the broker's most preferred type
+    /// that this reporter supports, or no compression if there is none.
+    pub fn compression_type(&self) -> RecordBatchCompression {
+        self.accepted_compression_types
+            .iter()
+            .copied()
+            .find(|compression| SUPPORTED_COMPRESSION_TYPES.contains(compression))
+            .unwrap_or(RecordBatchCompression::NoCompression)
+    }
+}
+
+impl From<&GetTelemetrySubscriptionsResponse> for TelemetrySubscription {
+    fn from(value: &GetTelemetrySubscriptionsResponse) -> Self {
+        Self {
+            subscription_id: value.subscription_id,
+            accepted_compression_types: value.accepted_compression_types.clone(),
+            push_interval: value.push_interval,
+            telemetry_max_bytes: value.telemetry_max_bytes,
+            delta_temporality: value.delta_temporality,
+            requested_metrics: value.requested_metrics.clone(),
+        }
+    }
+}
+
+/// The state of a [TelemetryReporter].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TelemetryState {
+    /// A subscription should be requested at the given time.
+    SubscriptionNeeded { at: Timestamp },
+
+    /// A subscription has been requested, and the reporter is waiting for the response.
+    AwaitingSubscription,
+
+    /// Metrics should be pushed for the subscription at the given time.
+    Subscribed {
+        subscription: TelemetrySubscription,
+        next_push: Timestamp,
+    },
+
+    /// Metrics have been pushed, and the reporter is waiting for the response.
+    AwaitingPush {
+        subscription: TelemetrySubscription,
+        counters: PushedCounters,
+    },
+
+    /// The final metrics have been pushed, and no more requests will be made.
+    Terminated,
+
+    /// The broker does not support telemetry or rejected our metrics, so no more requests will be
+    /// made.
+    Disabled,
+}
+
+/// Tracks the broker's telemetry subscription for a client and builds the requests which fulfill
+/// it.
+///
+/// The reporter performs no I/O itself. Instead, the client periodically calls
+/// [poll_subscription](Self::poll_subscription) and [poll_push](Self::poll_push) with the current
+/// time, sends any requests they return, and passes the responses back to
+/// [handle_subscriptions_response](Self::handle_subscriptions_response) and
+/// [handle_push_response](Self::handle_push_response). On shutdown, the client sends the request
+/// returned by [terminating_push](Self::terminating_push).
+#[derive(Debug)]
+pub struct TelemetryReporter {
+    client_instance_id: Uuid,
+    resource_attributes: Vec<(String, String)>,
+    state: TelemetryState,
+    counters: HashMap<MetricKey, CounterState>,
+    started: Option<Timestamp>,
+}
+
+/// Identifies a metric by its name and attributes.
+type MetricKey = (String, Vec<(String, String)>);
+
+/// The counters included in a push, which are only marked as pushed once the broker accepts it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PushedCounters(Vec<(MetricKey, CounterState)>);
+
+/// What was last reported for a counter.
+#[derive(Debug, Clone, Copy)]
+struct CounterState {
+    /// When the counter was first collected, or when the reporter was first polled if the counter
+    /// was first collected in a push.
+    first_seen: Timestamp,
+
+    /// When the counter was last pushed.
+    last_pushed: Timestamp,
+
+    /// The cumulative value of the counter when it was last pushed.
+    last_value: f64,
+}
+
+impl PartialEq for CounterState {
+    fn eq(&self, other: &Self) -> bool {
+        self.first_seen == other.first_seen
+            && self.last_pushed == other.last_pushed
+            && self.last_value.to_bits() == other.last_value.to_bits()
+    }
+}
+
+impl Eq for CounterState {}
+
+impl TelemetryReporter {
+    /// Creates a reporter which describes the client with the given OTLP resource attributes.
+    pub fn new(resource_attributes: Vec<(String, String)>) -> Self {
+        Self {
+            client_instance_id: Uuid::ZERO,
+            resource_attributes,
+            state: TelemetryState::SubscriptionNeeded { at: Timestamp(0) },
+            counters: HashMap::new(),
+            started: None,
+        }
+    }
+
+    /// Returns the unique ID the broker assigned to this client instance, or [Uuid::ZERO] if one
+    /// has not been assigned yet.
+    pub fn client_instance_id(&self) -> Uuid {
+        self.client_instance_id
+    }
+
+    /// Returns the state of the reporter.
+    pub fn state(&self) -> &TelemetryState {
+        &self.state
+    }
+
+    /// Returns when the reporter next needs to be polled, or None if it is waiting on a response
+    /// or will make no more requests.
+    pub fn next_deadline(&self) -> Option<Timestamp> {
+        match &self.state {
+            TelemetryState::SubscriptionNeeded { at } => Some(*at),
+            TelemetryState::Subscribed { next_push, .. } => Some(*next_push),
+            _ => None,
+        }
+    }
+
+    /// Returns a subscription request if one is due.
+    pub fn poll_subscription(
+        &mut self,
+        now: Timestamp,
+    ) -> Option<GetTelemetrySubscriptionsRequest> {
+        self.started.get_or_insert(now);
+
+        match self.state {
+            TelemetryState::SubscriptionNeeded { at } if now >= at => {
+                self.state = TelemetryState::AwaitingSubscription;
+
+                Some(GetTelemetrySubscriptionsRequest {
+                    client_instance_id: self.client_instance_id,
+                    tagged_fields: Default::default(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Updates the subscription from the broker's response.
+    ///
+    /// The first push for a new subscription is jittered between half and one and a half push
+    /// intervals, so that clients started together do not push together.
+    pub fn handle_subscriptions_response(
+        &mut self,
+        response: &GetTelemetrySubscriptionsResponse,
+        now: Timestamp,
+    ) {
+        match response.error_code {
+            None => {}
+            Some(ErrorCode::InvalidRequest | ErrorCode::UnsupportedVersion) => {
+                self.state = TelemetryState::Disabled;
+                return;
+            }
+            Some(_) => {
+                self.state = TelemetryState::SubscriptionNeeded {
+                    at: after(now, DEFAULT_PUSH_INTERVAL),
+                };
+                return;
+            }
+        }
+
+        if self.client_instance_id == Uuid::ZERO {
+            self.client_instance_id = response.client_instance_id;
+        }
+
+        let subscription = TelemetrySubscription::from(response);
+
+        // Without any requested metrics there is nothing to push, but the subscription may change
+        // later.
+        if subscription.requested_metrics.is_empty() {
+            self.state = TelemetryState::SubscriptionNeeded {
+                at: after(now, subscription.push_interval),
+            };
+            return;
+        }
+
+        let jitter = rand::thread_rng().gen_range(0.5..1.5);
+        let first_push = Duration((subscription.push_interval.0 as f64 * jitter) as i32);
+
+        self.state = TelemetryState::Subscribed {
+            subscription,
+            next_push: after(now, first_push),
+        };
+    }
+
+    /// Returns a push request with the requested metrics if one is due.
+    ///
+    /// If the encoded metrics exceed the broker's size limit, they are dropped and the push is
+    /// skipped until the next interval.
+    pub fn poll_push<'a>(
+        &mut self,
+        metrics: impl IntoIterator<Item = &'a Metric>,
+        now: Timestamp,
+    ) -> Option<PushTelemetryRequest> {
+        let subscription = match &self.state {
+            TelemetryState::Subscribed {
+                subscription,
+                next_push,
+            } if now >= *next_push => subscription.clone(),
+            _ => return None,
+        };
+
+        match self.push_request(&subscription, metrics, now, false) {
+            Some((request, counters)) => {
+                self.state = TelemetryState::AwaitingPush {
+                    subscription,
+                    counters,
+                };
+                Some(request)
+            }
+            None => {
+                self.state = TelemetryState::Subscribed {
+                    next_push: after(now, subscription.push_interval),
+                    subscription,
+                };
+                None
+            }
+        }
+    }
+
+    /// Returns the final push request to send when the client shuts down, regardless of whether a
+    /// push is due. No more requests will be made afterwards.
+    pub fn terminating_push<'a>(
+        &mut self,
+        metrics: impl IntoIterator<Item = &'a Metric>,
+        now: Timestamp,
+    ) -> Option<PushTelemetryRequest> {
+        let state = std::mem::replace(&mut self.state, TelemetryState::Terminated);
+
+        match state {
+            TelemetryState::Subscribed { subscription, .. }
+            | TelemetryState::AwaitingPush { subscription, .. } => self
+                .push_request(&subscription, metrics, now, true)
+                .map(|(request, _)| request),
+            TelemetryState::Disabled => {
+                self.state = TelemetryState::Disabled;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Schedules the next request based on the broker's response to a push.
+    ///
+    /// Like the Java client, the reporter requests a new subscription straight away if the
+    /// subscription or compression type is no longer valid, and stops pushing altogether if the
+    /// broker rejects the request itself. Counters are only marked as pushed if the push was
+    /// accepted, so that the next push reports the deltas of a failed one as well.
+    pub fn handle_push_response(&mut self, response: &PushTelemetryResponse, now: Timestamp) {
+        let (subscription, counters) =
+            match std::mem::replace(&mut self.state, TelemetryState::Disabled) {
+                TelemetryState::AwaitingPush {
+                    subscription,
+                    counters,
+                } => (subscription, counters),
+                state => {
+                    self.state = state;
+                    return;
+                }
+            };
+
+        if response.error_code.is_none() {
+            self.counters.extend(counters.0);
+        }
+
+        self.state = match response.error_code {
+            None | Some(ErrorCode::TelemetryTooLarge | ErrorCode::ThrottlingQuotaExceeded) => {
+                TelemetryState::Subscribed {
+                    next_push: after(now, subscription.push_interval),
+                    subscription,
+                }
+            }
+            Some(ErrorCode::UnknownSubscriptionId | ErrorCode::UnsupportedCompressionType) => {
+                TelemetryState::SubscriptionNeeded { at: now }
+            }
+            Some(ErrorCode::InvalidRequest | ErrorCode::InvalidRecord) => TelemetryState::Disabled,
+            Some(_) => TelemetryState::SubscriptionNeeded {
+                at: after(now, subscription.push_interval),
+            },
+        };
+    }
+
+    /// Encodes and compresses the requested metrics, returning None if they are too large.
+    ///
+    /// The request is returned along with the counters it pushes, which are applied once the
+    /// broker accepts it.
+    fn push_request<'a>(
+        &self,
+        subscription: &TelemetrySubscription,
+        metrics: impl IntoIterator<Item = &'a Metric>,
+        now: Timestamp,
+        terminating: bool,
+    ) -> Option<(PushTelemetryRequest, PushedCounters)> {
+        let metrics: Vec<_> = metrics
+            .into_iter()
+            .filter(|metric| subscription.is_requested(&metric.name))
+            .collect();
+
+        let (encoded, counters) = self.encode(&metrics, subscription.delta_temporality, now);
+        let compression_type = subscription.compression_type();
+        let payload = compress(compression_type, encoded);
+
+        if payload.len() > subscription.telemetry_max_bytes.max(0) as usize {
+            return None;
+        }
+
+        let request = PushTelemetryRequest {
+            client_instance_id: self.client_instance_id,
+            subscription_id: subscription.subscription_id,
+            terminating,
+            compression_type,
+            metrics: payload,
+            tagged_fields: Default::default(),
+        };
+        Some((request, PushedCounters(counters)))
+    }
+
+    /// Encodes metrics as an OTLP `MetricsData` message, converting counters to the requested
+    /// temporality.
+    ///
+    /// Returns the encoded message along with the state of each counter once it is pushed.
+    fn encode(
+        &self,
+        metrics: &[&Metric],
+        delta_temporality: bool,
+        now: Timestamp,
+    ) -> (Vec<u8>, Vec<(MetricKey, CounterState)>) {
+        let mut counters = Vec::new();
+        let mut scope_metrics = ProtoWriter::default();
+        scope_metrics.message(1, |scope| {
+            scope.string(1, env!("CARGO_PKG_NAME"));
+            scope.string(2, env!("CARGO_PKG_VERSION"));
+        });
+
+        for metric in metrics {
+            scope_metrics.message(2, |writer| {
+                writer.string(1, &metric.name);
+                writer.string(2, &metric.description);
+                writer.string(3, &metric.unit);
+
+                match metric.value {
+                    MetricValue::Gauge(value) => writer.message(5, |gauge| {
+                        gauge.message(1, |point| {
+                            number_data_point(point, &metric.attributes, None, now, value)
+                        });
+                    }),
+                    MetricValue::Counter(total) => {
+                        let key = (metric.name.clone(), metric.attributes.clone());
+                        let counter = self.counters.get(&key).copied().unwrap_or_else(|| {
+                            let started = self.started.unwrap_or(now).min(now);
+                            CounterState {
+                                first_seen: started,
+                                last_pushed: started,
+                                last_value: 0.0,
+                            }
+                        });
+
+                        let (start, value) = match delta_temporality {
+                            true => (counter.last_pushed, total - counter.last_value),
+                            false => (counter.first_seen, total),
+                        };
+
+                        counters.push((
+                            key,
+                            CounterState {
+                                last_pushed: now,
+                                last_value: total,
+                                ..counter
+                            },
+                        ));
+
+                        writer.message(7, |sum| {
+                            sum.message(1, |point| {
+                                number_data_point(
+                                    point,
+                                    &metric.attributes,
+                                    Some(start),
+                                    now,
+                                    value,
+                                )
+                            });
+                            sum.varint(2, if delta_temporality { 1 } else { 2 });
+                            sum.varint(3, 1);
+                        });
+                    }
+                }
+            });
+        }
+
+        let mut resource_metrics = ProtoWriter::default();
+        resource_metrics.message(1, |resource| {
+            for (key, value) in &self.resource_attributes {
+                resource.message(1, |attribute| key_value(attribute, key, value));
+            }
+        });
+        resource_metrics.bytes(2, &scope_metrics.0);
+
+        let mut metrics_data = ProtoWriter::default();
+        metrics_data.bytes(1, &resource_metrics.0);
+        (metrics_data.0, counters)
+    }
+}
+
+/// Returns the time the given duration after `now`.
+fn after(now: Timestamp, duration: Duration) -> Timestamp {
+    Timestamp(now.0 + i64::from(duration.0))
+}
+
+/// Compresses an encoded payload with the given compression type.
+fn compress(compression_type: RecordBatchCompression, payload: Vec<u8>) -> Vec<u8> {
+    match compression_type {
+        RecordBatchCompression::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder
+                .write_all(&payload)
+                .and_then(|_| encoder.finish())
+                .expect("writing to a Vec cannot fail")
+        }
+        _ => payload,
+    }
+}
+
+/// Writes an OTLP `NumberDataPoint` holding a double.
+fn number_data_point(
+    writer: &mut ProtoWriter,
+    attributes: &[(String, String)],
+    start: Option<Timestamp>,
+    now: Timestamp,
+    value: f64,
+) {
+    if let Some(start) = start {
+        writer.fixed64(2, unix_nanos(start));
+    }
+    writer.fixed64(3, unix_nanos(now));
+    writer.fixed64(4, value.to_bits());
+    for (key, value) in attributes {
+        writer.message(7, |attribute| key_value(attribute, key, value));
+    }
+}
+
+/// Writes an OTLP `KeyValue` holding a string.
+fn key_value(writer: &mut ProtoWriter, key: &str, value: &str) {
+    writer.string(1, key);
+    writer.message(2, |any_value| any_value.string(1, value));
+}
+
+/// Converts a timestamp in milliseconds to nanoseconds since the Unix epoch.
+fn unix_nanos(timestamp: Timestamp) -> u64 {
+    (timestamp.0.max(0) as u64).saturating_mul(1_000_000)
+}
+
+/// Writes the subset of the protobuf wire format needed for OTLP metrics.
+#[derive(Default)]
+struct ProtoWriter(Vec<u8>);
+
+impl ProtoWriter {
+    const VARINT: u64 = 0;
+    const FIXED64: u64 = 1;
+    const LENGTH_DELIMITED: u64 = 2;
+
+    fn raw_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.0.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.0.push(value as u8);
+    }
+
+    fn tag(&mut self, field: u64, wire_type: u64) {
+        self.raw_varint(field << 3 | wire_type);
+    }
+
+    fn varint(&mut self, field: u64, value: u64) {
+        self.tag(field, Self::VARINT);
+        self.raw_varint(value);
+    }
+
+    fn fixed64(&mut self, field: u64, value: u64) {
+        self.tag(field, Self::FIXED64);
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bytes(&mut self, field: u64, value: &[u8]) {
+        self.tag(field, Self::LENGTH_DELIMITED);
+        self.raw_varint(value.len() as u64);
+        self.0.extend_from_slice(value);
+    }
+
+    fn string(&mut self, field: u64, value: &str) {
+        self.bytes(field, value.as_bytes());
+    }
+
+    fn message(&mut self, field: u64, write: impl FnOnce(&mut Self)) {
+        let mut message = Self::default();
+        write(&mut message);
+        self.bytes(field, &message.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subscription(telemetry_max_bytes: i32) -> TelemetrySubscription {
+        TelemetrySubscription {
+            subscription_id: 1,
+            accepted_compression_types: vec![],
+            push_interval: Duration(1000),
+            telemetry_max_bytes,
+            delta_temporality: true,
+            requested_metrics: vec!["org.apache.kafka.".to_owned()],
+        }
+    }
+
+    fn counter(value: f64) -> Metric {
+        Metric {
+            name: "org.apache.kafka.producer.request.count".to_owned(),
+            description: "The number of requests sent.".to_owned(),
+            unit: "1".to_owned(),
+            attributes: vec![],
+            value: MetricValue::Counter(value),
+        }
+    }
+
+    fn subscribe(reporter: &mut TelemetryReporter, telemetry_max_bytes: i32, now: Timestamp) {
+        reporter.state = TelemetryState::Subscribed {
+            subscription: subscription(telemetry_max_bytes),
+            next_push: now,
+        };
+    }
+
+    fn counter_state(reporter: &TelemetryReporter) -> Option<CounterState> {
+        reporter.counters.values().next().copied()
+    }
+
+    fn response(error_code: Option<ErrorCode>) -> PushTelemetryResponse {
+        PushTelemetryResponse {
+            throttle_time: None,
+            error_code,
+            tagged_fields: Default::default(),
+        }
+    }
+
+    /// Returns whether the uncompressed payload holds a data point with the given value.
+    fn reports(request: &PushTelemetryRequest, value: f64) -> bool {
+        let mut point = vec![0x21];
+        point.extend_from_slice(&value.to_bits().to_le_bytes());
+        request
+            .metrics
+            .windows(point.len())
+            .any(|bytes| bytes == point)
+    }
+
+    #[test]
+    fn dropped_push_keeps_counter_state() {
+        let mut reporter = TelemetryReporter::new(vec![]);
+        assert!(reporter.poll_subscription(Timestamp(100)).is_some());
+
+        subscribe(&mut reporter, 1, Timestamp(1000));
+        assert!(reporter
+            .poll_push([&counter(5.0)], Timestamp(1000))
+            .is_none());
+        assert!(counter_state(&reporter).is_none());
+
+        subscribe(&mut reporter, 1 << 20, Timestamp(2000));
+        assert!(reporter
+            .poll_push([&counter(8.0)], Timestamp(2000))
+            .is_some());
+        assert!(counter_state(&reporter).is_none());
+        reporter.handle_push_response(&response(None), Timestamp(2100));
+
+        let state = counter_state(&reporter).unwrap();
+        assert_eq!(state.first_seen, Timestamp(100));
+        assert_eq!(state.last_pushed, Timestamp(2000));
+        assert_eq!(state.last_value, 8.0);
+    }
+
+    #[test]
+    fn new_counter_starts_when_reporter_started() {
+        let mut reporter = TelemetryReporter::new(vec![]);
+        assert!(reporter.poll_subscription(Timestamp(100)).is_some());
+
+        let metric = counter(5.0);
+        let (_, counters) = reporter.encode(&[&metric], true, Timestamp(1000));
+        assert!(reporter.counters.is_empty());
+
+        let (_, state) = &counters[0];
+        assert_eq!(state.first_seen, Timestamp(100));
+        assert_eq!(state.last_pushed, Timestamp(1000));
+        assert_eq!(state.last_value, 5.0);
+    }
+
+    #[test]
+    fn failed_push_is_included_in_retry() {
+        let mut reporter = TelemetryReporter::new(vec![]);
+        assert!(reporter.poll_subscription(Timestamp(100)).is_some());
+
+        subscribe(&mut reporter, 1 << 20, Timestamp(1000));
+        let request = reporter
+            .poll_push([&counter(5.0)], Timestamp(1000))
+            .unwrap();
+        assert!(reports(&request, 5.0));
+        reporter.handle_push_response(&response(None), Timestamp(1100));
+
+        subscribe(&mut reporter, 1 << 20, Timestamp(2000));
+        let request = reporter
+            .poll_push([&counter(8.0)], Timestamp(2000))
+            .unwrap();
+        assert!(reports(&request, 3.0));
+        reporter.handle_push_response(
+            &response(Some(ErrorCode::ThrottlingQuotaExceeded)),
+            Timestamp(2100),
+        );
+
+        let state = counter_state(&reporter).unwrap();
+        assert_eq!(state.last_pushed, Timestamp(1000));
+        assert_eq!(state.last_value, 5.0);
+
+        // The retry reports everything since the last accepted push.
+        let TelemetryState::Subscribed { next_push, .. } = *reporter.state() else {
+            panic!("expected a subscription, found {:?}", reporter.state());
+        };
+        let request = reporter.poll_push([&counter(12.0)], next_push).unwrap();
+        assert!(reports(&request, 7.0));
+        assert!(!reports(&request, 4.0));
+        reporter.handle_push_response(&response(None), next_push);
+
+        let state = counter_state(&reporter).unwrap();
+        assert_eq!(state.first_seen, Timestamp(100));
+        assert_eq!(state.last_pushed, next_push);
+        assert_eq!(state.last_value, 12.0);
+    }
+}
@@ -0,0 +1,87 @@
+//! Includes types shared by the transaction introspection APIs.
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Transaction state `{0}` is not valid")]
+    InvalidTransactionState(String),
+}
+
+/// The state of a transaction, as tracked by its transaction coordinator.
+///
+/// Like group states, transaction states are transmitted as strings.
+///
+/// See: <https://github.com/apache/kafka/blob/trunk/clients/src/main/java/org/apache/kafka/clients/admin/TransactionState.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransactionState {
+    /// The state of the transaction is not known.
+    Unknown,
+
+    /// The transactional ID has no ongoing transaction.
+    Empty,
+
+    /// A transaction is in progress.
+    Ongoing,
+
+    /// The transaction is being committed, and markers are being written to its partitions.
+    PrepareCommit,
+
+    /// The transaction is being aborted, and markers are being written to its partitions.
+    PrepareAbort,
+
+    /// The transaction was committed.
+    CompleteCommit,
+
+    /// The transaction was aborted.
+    CompleteAbort,
+
+    /// The transactional ID has expired and its metadata is being removed.
+    Dead,
+
+    /// The producer was fenced and its ongoing transaction is being aborted.
+    PrepareEpochFence,
+}
+
+impl TransactionState {
+    /// Returns the name of this state as transmitted over the wire.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown",
+            Self::Empty => "Empty",
+            Self::Ongoing => "Ongoing",
+            Self::PrepareCommit => "PrepareCommit",
+            Self::PrepareAbort => "PrepareAbort",
+            Self::CompleteCommit => "CompleteCommit",
+            Self::CompleteAbort => "CompleteAbort",
+            Self::Dead => "Dead",
+            Self::PrepareEpochFence => "PrepareEpochFence",
+        }
+    }
+
+    /// Returns whether a transaction in this state still holds back the last stable offset of its
+    /// partitions, i.e. whether consumers reading committed data are waiting on it.
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self,
+            Self::Ongoing | Self::PrepareCommit | Self::PrepareAbort | Self::PrepareEpochFence
+        )
+    }
+}
+
+impl TryFrom<&str> for TransactionState {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "Unknown" => Ok(Self::Unknown),
+            "Empty" => Ok(Self::Empty),
+            "Ongoing" => Ok(Self::Ongoing),
+            "PrepareCommit" => Ok(Self::PrepareCommit),
+            "PrepareAbort" => Ok(Self::PrepareAbort),
+            "CompleteCommit" => Ok(Self::CompleteCommit),
+            "CompleteAbort" => Ok(Self::CompleteAbort),
+            "Dead" => Ok(Self::Dead),
+            "PrepareEpochFence" => Ok(Self::PrepareEpochFence),
+            _ => Err(Error::InvalidTransactionState(value.to_owned())),
+        }
+    }
+}
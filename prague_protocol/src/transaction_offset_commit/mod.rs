@@ -0,0 +1,29 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::TransactionOffsetCommitRequest, response::TransactionOffsetCommitResponse,
+        TransactionOffsetCommitApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct TransactionOffsetCommitApi;
+
+impl Api for TransactionOffsetCommitApi {
+    const KEY: ApiKey = ApiKey::TransactionOffsetCommit;
+
+    type Request = TransactionOffsetCommitRequest;
+    type Response = TransactionOffsetCommitResponse;
+}
+
+impl VersionedApi for TransactionOffsetCommitApi {
+    /// Versions 4 and 5 only add error codes for transactions verified by the broker.
+    const MIN_VERSION: i16 = 0;
+    const MAX_VERSION: i16 = 3;
+    const FLEXIBLE_VERSION: Option<i16> = Some(3);
+}
@@ -0,0 +1,164 @@
+//! Includes definitions for requests to
+//! [TransactionOffsetCommitApi](super::TransactionOffsetCommitApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/TxnOffsetCommitRequest.json>
+
+use crate::{
+    codec::{Encode, Error, Writer},
+    types::prelude::*,
+};
+
+/// A request to commit the offsets of a consumer group as part of the ongoing transaction of a
+/// producer. The offsets only become visible to the group once the transaction commits.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/TxnOffsetCommitRequest.json>
+pub struct TransactionOffsetCommitRequest {
+    /// The ID of the transaction.
+    ///
+    /// Versions: 0+
+    pub transactional_id: String,
+
+    /// The ID of the group.
+    ///
+    /// Versions: 0+
+    pub group_id: String,
+
+    /// The current producer ID in use by the transactional ID.
+    ///
+    /// Versions: 0+
+    pub producer_id: ProducerId,
+
+    /// The current epoch associated with the producer ID.
+    ///
+    /// Versions: 0+
+    pub producer_epoch: i16,
+
+    /// The generation of the consumer, or None if it is not part of a generation.
+    ///
+    /// Versions: 3+
+    pub generation_id: Option<i32>,
+
+    /// The member ID assigned by the group coordinator.
+    ///
+    /// Versions: 3+
+    pub member_id: String,
+
+    /// The unique identifier of the consumer instance provided by the end user, or None if it is
+    /// not a static member.
+    ///
+    /// Versions: 3+
+    pub group_instance_id: Option<String>,
+
+    /// Each topic that we want to commit offsets for.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<TransactionOffsetCommitTopic>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The offsets to commit for the partitions of a topic.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/TxnOffsetCommitRequest.json>
+pub struct TransactionOffsetCommitTopic {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The partitions inside the topic that we want to commit offsets for.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<TransactionOffsetCommitPartition>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The offset to commit for a partition.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/TxnOffsetCommitRequest.json>
+pub struct TransactionOffsetCommitPartition {
+    /// The index of the partition within the topic.
+    ///
+    /// Versions: 0+
+    pub partition_index: PartitionIndex,
+
+    /// The message offset to be committed.
+    ///
+    /// Versions: 0+
+    pub committed_offset: Offset,
+
+    /// The leader epoch of the last consumed record, or None if it is unknown.
+    ///
+    /// Versions: 2+
+    pub committed_leader_epoch: Option<Epoch>,
+
+    /// Any associated metadata the client wants to keep.
+    ///
+    /// Versions: 0+
+    pub committed_metadata: Option<String>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Encode for TransactionOffsetCommitRequest {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), Error> {
+        writer.string(&self.transactional_id);
+        writer.string(&self.group_id);
+        writer.i64(self.producer_id.0);
+        writer.i16(self.producer_epoch);
+        if version >= 3 {
+            writer.i32(self.generation_id.unwrap_or(-1));
+            writer.string(&self.member_id);
+            writer.nullable_string(self.group_instance_id.as_deref());
+        }
+        writer.array(&self.topics, |writer, topic| topic.encode(writer, version))?;
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
+
+impl Encode for TransactionOffsetCommitTopic {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), Error> {
+        writer.string(&self.name);
+        writer.array(&self.partitions, |writer, partition| {
+            partition.encode(writer, version)
+        })?;
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
+
+impl Encode for TransactionOffsetCommitPartition {
+    fn encode(&self, writer: &mut Writer, version: i16) -> Result<(), Error> {
+        writer.i32(self.partition_index.0);
+        writer.i64(self.committed_offset.0);
+        if version >= 2 {
+            writer.i32(self.committed_leader_epoch.map_or(-1, |epoch| epoch.0));
+        }
+        writer.nullable_string(self.committed_metadata.as_deref());
+        writer.tagged_fields(&self.tagged_fields, vec![]);
+        Ok(())
+    }
+}
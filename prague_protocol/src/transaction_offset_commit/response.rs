@@ -0,0 +1,118 @@
+//! Includes definitions for responses from
+//! [TransactionOffsetCommitApi](super::TransactionOffsetCommitApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/TxnOffsetCommitResponse.json>
+
+use crate::{
+    codec::{Decode, Error, Reader},
+    error_code::ErrorCode,
+    types::prelude::*,
+};
+
+/// A response message associated with
+/// [TransactionOffsetCommitRequest](super::request::TransactionOffsetCommitRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/TxnOffsetCommitResponse.json>
+pub struct TransactionOffsetCommitResponse {
+    /// The duration in milliseconds for which the request was throttled due to a quota violation,
+    /// or None if the request did not violate any quota.
+    ///
+    /// Versions: 0+
+    pub throttle_time: Option<Duration>,
+
+    /// The responses for each topic.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<TransactionOffsetCommitTopicResponse>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The results of committing the offsets of a topic.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/TxnOffsetCommitResponse.json>
+pub struct TransactionOffsetCommitTopicResponse {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub name: String,
+
+    /// The responses for each partition in the topic.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<TransactionOffsetCommitPartitionResponse>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The result of committing the offset of a partition.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/TxnOffsetCommitResponse.json>
+pub struct TransactionOffsetCommitPartitionResponse {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub partition_index: PartitionIndex,
+
+    /// The error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 3+
+    pub tagged_fields: TaggedFields,
+}
+
+impl Decode for TransactionOffsetCommitResponse {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            throttle_time: reader.throttle_time()?,
+            topics: reader.array("topics", |reader| {
+                TransactionOffsetCommitTopicResponse::decode(reader, version)
+            })?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+impl Decode for TransactionOffsetCommitTopicResponse {
+    fn decode(reader: &mut Reader, version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            name: reader.string("name")?,
+            partitions: reader.array("partitions", |reader| {
+                TransactionOffsetCommitPartitionResponse::decode(reader, version)
+            })?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
+
+impl Decode for TransactionOffsetCommitPartitionResponse {
+    fn decode(reader: &mut Reader, _version: i16) -> Result<Self, Error> {
+        Ok(Self {
+            partition_index: PartitionIndex(reader.i32()?),
+            error_code: reader.error_code()?,
+            tagged_fields: reader.tagged_fields()?,
+        })
+    }
+}
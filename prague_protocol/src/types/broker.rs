@@ -1 +1,2 @@
-pub struct BrokerId(i32);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BrokerId(pub i32);
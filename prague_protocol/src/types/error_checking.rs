@@ -1 +1,40 @@
-pub struct CyclicRedundancyCheck(u32);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CyclicRedundancyCheck(pub u32);
+
+impl CyclicRedundancyCheck {
+    /// The reversed Castagnoli polynomial, as used by record batches.
+    const CASTAGNOLI: u32 = 0x82f6_3b78;
+
+    /// The lookup table for [crc32c](Self::crc32c), one entry per byte value.
+    const TABLE: [u32; 256] = {
+        let mut table = [0; 256];
+        let mut byte = 0;
+
+        while byte < 256 {
+            let mut crc = byte as u32;
+            let mut bit = 0;
+
+            while bit < 8 {
+                crc = match crc & 1 {
+                    1 => (crc >> 1) ^ Self::CASTAGNOLI,
+                    _ => crc >> 1,
+                };
+                bit += 1;
+            }
+
+            table[byte] = crc;
+            byte += 1;
+        }
+
+        table
+    };
+
+    /// Computes the CRC-32C checksum of the given bytes.
+    pub fn crc32c(bytes: &[u8]) -> Self {
+        let crc = bytes.iter().fold(!0u32, |crc, byte| {
+            Self::TABLE[((crc ^ u32::from(*byte)) & 0xff) as usize] ^ (crc >> 8)
+        });
+
+        Self(!crc)
+    }
+}
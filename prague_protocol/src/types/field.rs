@@ -1 +1,3 @@
-pub struct Field;
+/// The raw value of a tagged field which is not otherwise understood by its message.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Field(pub Vec<u8>);
@@ -5,6 +5,7 @@ pub mod partition;
 pub mod producer;
 pub mod tagged_fields;
 pub mod time;
+pub mod topic;
 pub mod uuid;
 pub mod var;
 
@@ -12,7 +13,7 @@ pub mod prelude {
     pub use super::{
         broker::BrokerId, error_checking::CyclicRedundancyCheck, field::Field,
         partition::PartitionIndex, producer::ProducerId, tagged_fields::TaggedFields,
-        time::Duration, time::Epoch, time::Offset, time::Timestamp, uuid::Uuid, var::VarInt,
-        var::VarLong,
+        time::Duration, time::Epoch, time::Offset, time::Timestamp, topic::TopicRef, uuid::Uuid,
+        var::VarInt, var::VarLong,
     };
 }
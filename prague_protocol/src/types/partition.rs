@@ -1 +1,2 @@
-pub struct PartitionIndex(i32);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PartitionIndex(pub i32);
@@ -1 +1,2 @@
-pub struct ProducerId(i64);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProducerId(pub i64);
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use super::field::Field;
 
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct TaggedFields {
     pub fields: HashMap<i32, Field>,
 }
@@ -0,0 +1,32 @@
+use super::uuid::Uuid;
+
+/// Identifies a topic either by name or by its unique ID.
+///
+/// Topic IDs were introduced by KIP-516, and APIs moved from names to IDs in later versions, so
+/// which of the two may be sent depends on the negotiated version.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TopicRef {
+    /// The topic name.
+    Name(String),
+
+    /// The topic's unique ID.
+    Id(Uuid),
+}
+
+impl TopicRef {
+    /// Returns the topic name, if this refers to the topic by name.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Name(name) => Some(name),
+            Self::Id(_) => None,
+        }
+    }
+
+    /// Returns the topic ID, if this refers to the topic by ID.
+    pub fn id(&self) -> Option<Uuid> {
+        match self {
+            Self::Name(_) => None,
+            Self::Id(id) => Some(*id),
+        }
+    }
+}
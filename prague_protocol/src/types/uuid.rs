@@ -1 +1,8 @@
-pub struct Uuid;
+/// A 128-bit universally unique identifier, as used for topic IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Uuid(pub u128);
+
+impl Uuid {
+    /// The all-zero UUID, which Kafka uses to represent the absence of an ID.
+    pub const ZERO: Self = Self(0);
+}
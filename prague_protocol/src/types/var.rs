@@ -1,3 +1,7 @@
-pub struct VarInt;
+/// A signed integer which is zigzag-encoded with a variable length, as used within records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct VarInt(pub i32);
 
-pub struct VarLong;
+/// A signed long which is zigzag-encoded with a variable length, as used within records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct VarLong(pub i64);
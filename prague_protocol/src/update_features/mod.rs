@@ -0,0 +1,21 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{
+        request::UpdateFeaturesRequest, response::UpdateFeaturesResponse, UpdateFeaturesApi,
+    };
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct UpdateFeaturesApi;
+
+impl Api for UpdateFeaturesApi {
+    const KEY: ApiKey = ApiKey::UpdateFeatures;
+
+    type Request = UpdateFeaturesRequest;
+    type Response = UpdateFeaturesResponse;
+}
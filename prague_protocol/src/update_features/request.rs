@@ -0,0 +1,99 @@
+//! Includes definitions for requests to [UpdateFeaturesApi](super::UpdateFeaturesApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/UpdateFeaturesRequest.json>
+
+use crate::types::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Feature upgrade type `{0}` is not valid")]
+    InvalidFeatureUpgradeType(i8),
+}
+
+/// A request to change the finalized versions of cluster-wide features, such as
+/// `metadata.version` during an upgrade.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/UpdateFeaturesRequest.json>
+pub struct UpdateFeaturesRequest {
+    /// How long to wait for the update to complete.
+    ///
+    /// Versions: 0+
+    pub timeout: Duration,
+
+    /// The features to update.
+    ///
+    /// Versions: 0+
+    pub feature_updates: Vec<FeatureUpdate>,
+
+    /// If true, the updates are validated but not applied.
+    ///
+    /// Versions: 1+
+    pub validate_only: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// An update to a single feature.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/UpdateFeaturesRequest.json>
+pub struct FeatureUpdate {
+    /// The name of the feature.
+    ///
+    /// Versions: 0+
+    pub feature: String,
+
+    /// The new maximum version level of the feature. A level below 1 disables the feature.
+    ///
+    /// Versions: 0+
+    pub max_version_level: i16,
+
+    /// How the feature is updated. Versions before 1 transmit this as an `AllowDowngrade`
+    /// boolean, so only [FeatureUpgradeType::Upgrade] and [FeatureUpgradeType::SafeDowngrade] can
+    /// be sent.
+    ///
+    /// Versions: 0+
+    pub upgrade_type: FeatureUpgradeType,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// How a feature's version level may be changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeatureUpgradeType {
+    /// The version level may only be increased.
+    Upgrade,
+
+    /// The version level may be decreased, provided no metadata would be lost.
+    SafeDowngrade,
+
+    /// The version level may be decreased, even if metadata would be lost.
+    UnsafeDowngrade,
+}
+
+impl TryFrom<i8> for FeatureUpgradeType {
+    type Error = Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Upgrade),
+            2 => Ok(Self::SafeDowngrade),
+            3 => Ok(Self::UnsafeDowngrade),
+            code => Err(Error::InvalidFeatureUpgradeType(code)),
+        }
+    }
+}
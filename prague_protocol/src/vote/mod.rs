@@ -0,0 +1,19 @@
+use self::prelude::*;
+use crate::api::prelude::*;
+
+pub mod request;
+pub mod response;
+
+pub mod prelude {
+    pub use super::{request::VoteRequest, response::VoteResponse, VoteApi};
+}
+
+/// See: <https://kafka.apache.org/protocol.html#protocol_messages>
+pub struct VoteApi;
+
+impl Api for VoteApi {
+    const KEY: ApiKey = ApiKey::Vote;
+
+    type Request = VoteRequest;
+    type Response = VoteResponse;
+}
@@ -0,0 +1,115 @@
+//! Includes definitions for requests to [VoteApi](super::VoteApi).
+//!
+//! ## See also
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/VoteRequest.json>
+
+use crate::types::prelude::*;
+
+/// A request from a candidate for a controller's vote in a leader election.
+///
+/// This is only sent between controllers. See [raft](crate::raft) for details.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/VoteRequest.json>
+pub struct VoteRequest {
+    /// The ID of the cluster, or None if not known.
+    ///
+    /// Versions: 0+
+    pub cluster_id: Option<String>,
+
+    /// The ID of the voter receiving the request, or None if not sent.
+    ///
+    /// Versions: 1+
+    pub voter_id: Option<BrokerId>,
+
+    /// The topics to request votes for.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<VoteTopic>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A topic to request votes for.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/VoteRequest.json>
+pub struct VoteTopic {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub topic_name: String,
+
+    /// The partitions to request votes for.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<VotePartition>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// A partition to request a vote for.
+///
+/// ## See also
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/VoteRequest.json>
+pub struct VotePartition {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub partition_index: PartitionIndex,
+
+    /// The epoch of the replica requesting the vote.
+    ///
+    /// Versions: 0+
+    pub replica_epoch: Epoch,
+
+    /// The ID of the replica requesting the vote.
+    ///
+    /// Versions: 0+
+    pub replica_id: BrokerId,
+
+    /// The directory ID of the replica requesting the vote.
+    ///
+    /// Versions: 1+
+    pub replica_directory_id: Uuid,
+
+    /// The directory ID of the voter receiving the request.
+    ///
+    /// Versions: 1+
+    pub voter_directory_id: Uuid,
+
+    /// The epoch of the last record in the requesting replica's log.
+    ///
+    /// Versions: 0+
+    pub last_log_offset_epoch: Epoch,
+
+    /// The end offset of the requesting replica's log.
+    ///
+    /// Versions: 0+
+    pub last_log_offset: Offset,
+
+    /// If true, this is a pre-vote, which asks whether the voter would grant a vote without
+    /// bumping the epoch.
+    ///
+    /// Versions: 2+
+    pub pre_vote: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
@@ -0,0 +1,97 @@
+//! Includes definitions for responses from [VoteApi](super::VoteApi).
+//!
+//! ## See also:
+//!
+//! - <https://kafka.apache.org/protocol.html#protocol_messages>
+//! - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/VoteResponse.json>
+
+use crate::{error_code::ErrorCode, raft::NodeEndpoint, types::prelude::*};
+
+/// A response message associated with [VoteRequest](super::request::VoteRequest).
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/VoteResponse.json>
+pub struct VoteResponse {
+    /// The top-level error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The results for each topic.
+    ///
+    /// Versions: 0+
+    pub topics: Vec<VoteTopicResponse>,
+
+    /// The endpoints of the leaders reported in [topics](Self::topics).
+    ///
+    /// Versions: 1+
+    pub node_endpoints: Vec<NodeEndpoint>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The vote results for a single topic.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/VoteResponse.json>
+pub struct VoteTopicResponse {
+    /// The topic name.
+    ///
+    /// Versions: 0+
+    pub topic_name: String,
+
+    /// The results for each partition.
+    ///
+    /// Versions: 0+
+    pub partitions: Vec<VotePartitionResponse>,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
+
+/// The vote result for a single partition.
+///
+/// ## See also:
+///
+/// - <https://kafka.apache.org/protocol.html#protocol_messages>
+/// - <https://github.com/apache/kafka/blob/trunk/clients/src/main/resources/common/message/VoteResponse.json>
+pub struct VotePartitionResponse {
+    /// The partition index.
+    ///
+    /// Versions: 0+
+    pub partition_index: PartitionIndex,
+
+    /// The partition-level error code, or None if there was no error.
+    ///
+    /// Versions: 0+
+    pub error_code: Option<ErrorCode>,
+
+    /// The ID of the current leader, or None if the voter does not know of one.
+    ///
+    /// Versions: 0+
+    pub leader_id: Option<BrokerId>,
+
+    /// The latest known leader epoch.
+    ///
+    /// Versions: 0+
+    pub leader_epoch: Epoch,
+
+    /// Whether the vote was granted.
+    ///
+    /// Versions: 0+
+    pub vote_granted: bool,
+
+    /// The tagged fields.
+    ///
+    /// Versions: 0+
+    pub tagged_fields: TaggedFields,
+}
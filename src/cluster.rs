@@ -0,0 +1,1017 @@
+//! Includes the cluster metadata cache, which tracks the brokers in a cluster and which broker
+//! leads each partition.
+//!
+//! Everything which routes requests to a partition's leader asks the [Cluster] where to send
+//! them, and tells it when a response shows that its metadata is stale. Produce and fetch
+//! responses may also name a partition's new leader, as described by
+//! [KIP-951](https://cwiki.apache.org/confluence/display/KAFKA/KIP-951%3A+Leader+discovery+optimisations+for+the+client),
+//! which the cache applies without waiting for a metadata refresh.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use prague_protocol::{
+    error_code::ErrorCode,
+    fetch::response::FetchResponse,
+    metadata::{
+        prelude::*,
+        request::Topic,
+        response::{BrokerMetadata, PartitionMetadata, TopicMetadata},
+    },
+    produce::response::ProduceResponse,
+    types::prelude::*,
+};
+use tokio::{
+    sync::Notify,
+    task::JoinHandle,
+    time::{self, Instant},
+};
+
+use crate::connection::{self, pipelined::PipelinedConnection, ConnectionConfig};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("There are no bootstrap servers or known brokers to connect to")]
+    NoBrokers,
+
+    #[error("Could not reach any broker: {0}")]
+    Unreachable(#[source] connection::Error),
+
+    #[error(transparent)]
+    Connection(#[from] connection::Error),
+
+    #[error("Metadata for topic `{topic}` failed with `{error_code:?}`")]
+    Topic {
+        topic: String,
+        error_code: ErrorCode,
+    },
+
+    #[error("Timed out waiting for metadata for topic `{0}`")]
+    Timeout(String),
+}
+
+/// Configures a [Cluster].
+#[derive(Debug, Clone)]
+pub struct ClusterConfig {
+    /// The `host:port` addresses used to discover the cluster. Only one needs to be reachable.
+    pub bootstrap_servers: Vec<String>,
+
+    /// Configures connections made to fetch metadata.
+    pub connection: ConnectionConfig,
+
+    /// How long metadata is cached before it is refreshed, even if nothing invalidated it.
+    pub metadata_max_age: Duration,
+
+    /// How long to wait between failed or incomplete refreshes.
+    pub refresh_backoff: Duration,
+
+    /// How long to wait for metadata for a topic before giving up.
+    pub max_block: Duration,
+
+    /// Whether brokers may create requested topics which do not exist, if they are configured to
+    /// do so.
+    pub allow_auto_topic_creation: bool,
+}
+
+impl ClusterConfig {
+    /// Creates a configuration with the given bootstrap servers and the Java client's defaults.
+    pub fn new(bootstrap_servers: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            bootstrap_servers: bootstrap_servers.into_iter().map(Into::into).collect(),
+            connection: ConnectionConfig::default(),
+            metadata_max_age: Duration::from_secs(5 * 60),
+            refresh_backoff: Duration::from_millis(100),
+            max_block: Duration::from_secs(60),
+            allow_auto_topic_creation: true,
+        }
+    }
+}
+
+/// A partition's new leader, as reported by a broker which no longer leads it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaderUpdate {
+    pub topic: String,
+    pub partition: PartitionIndex,
+    pub leader: BrokerId,
+    pub leader_epoch: Epoch,
+}
+
+/// A snapshot of the cluster's metadata.
+#[derive(Debug, Clone, Default)]
+pub struct Metadata {
+    /// The cluster's ID, or None if the brokers are too old to report it.
+    pub cluster_id: Option<String>,
+
+    /// The ID of the controller, or None if there is no active controller.
+    pub controller_id: Option<BrokerId>,
+
+    /// The brokers in the cluster.
+    pub brokers: HashMap<BrokerId, BrokerMetadata>,
+
+    /// The topics being tracked, by name.
+    pub topics: HashMap<String, TopicInfo>,
+
+    topic_names: HashMap<Uuid, String>,
+}
+
+impl Metadata {
+    /// Returns the broker with the given ID.
+    pub fn broker(&self, id: BrokerId) -> Option<&BrokerMetadata> {
+        self.brokers.get(&id)
+    }
+
+    /// Returns the topic with the given name.
+    pub fn topic(&self, name: &str) -> Option<&TopicInfo> {
+        self.topics.get(name)
+    }
+
+    /// Returns the ID of the topic with the given name, or None if it is not known.
+    pub fn topic_id(&self, name: &str) -> Option<Uuid> {
+        self.topics.get(name)?.id
+    }
+
+    /// Returns the name of the topic with the given ID, or None if it is not known.
+    pub fn topic_name(&self, id: Uuid) -> Option<&str> {
+        self.topic_names.get(&id).map(String::as_str)
+    }
+
+    /// Returns the given partition of the topic with the given name.
+    pub fn partition(&self, topic: &str, index: PartitionIndex) -> Option<&PartitionInfo> {
+        self.topics.get(topic)?.partitions.get(&index)
+    }
+
+    /// Returns the leader of the given partition of the topic with the given name, or None if it
+    /// is not known or has no leader.
+    pub fn leader(&self, topic: &str, index: PartitionIndex) -> Option<&BrokerMetadata> {
+        self.broker(self.partition(topic, index)?.leader?)
+    }
+
+    /// Returns a copy of this metadata updated from a response.
+    ///
+    /// Like the Java client, a partition whose leader epoch is older than the cached one keeps
+    /// its cached state, since the response came from a broker with stale metadata. Topics which
+    /// are no longer tracked are dropped.
+    fn merge(&self, response: MetadataResponse, tracked: &HashSet<String>) -> Self {
+        let mut topics: HashMap<_, _> = self
+            .topics
+            .iter()
+            .filter(|(name, _)| tracked.contains(*name))
+            .map(|(name, topic)| (name.clone(), topic.clone()))
+            .collect();
+
+        for topic in response.topics {
+            let Some(name) = topic.name.clone() else {
+                continue;
+            };
+
+            let mut updated = TopicInfo::from(topic);
+            if let Some(cached) = topics.get(&name).filter(|cached| cached.id == updated.id) {
+                for (index, partition) in &mut updated.partitions {
+                    match cached.partitions.get(index) {
+                        Some(cached) if cached.leader_epoch > partition.leader_epoch => {
+                            *partition = cached.clone();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            topics.insert(name, updated);
+        }
+
+        let topic_names = topics
+            .values()
+            .filter_map(|topic| Some((topic.id?, topic.name.clone())))
+            .collect();
+
+        Self {
+            cluster_id: response.cluster_id,
+            controller_id: Some(BrokerId(response.controller_id)).filter(|id| id.0 >= 0),
+            brokers: response
+                .brokers
+                .into_iter()
+                .map(|broker| (broker.id, broker))
+                .collect(),
+            topics,
+            topic_names,
+        }
+    }
+
+    /// Returns a copy of this metadata with the given partition leaders and broker addresses,
+    /// and how many partitions changed leader.
+    ///
+    /// Like the Java client, a leader is only applied if its epoch is newer than the cached
+    /// one, and if its broker's address is known.
+    fn with_leaders(
+        &self,
+        leaders: impl IntoIterator<Item = LeaderUpdate>,
+        endpoints: impl IntoIterator<Item = BrokerMetadata>,
+    ) -> (Self, usize) {
+        let mut metadata = self.clone();
+        metadata
+            .brokers
+            .extend(endpoints.into_iter().map(|broker| (broker.id, broker)));
+
+        let mut updated = 0;
+        for update in leaders {
+            if !metadata.brokers.contains_key(&update.leader) {
+                continue;
+            }
+
+            let Some(partition) = metadata
+                .topics
+                .get_mut(&update.topic)
+                .and_then(|topic| topic.partitions.get_mut(&update.partition))
+            else {
+                continue;
+            };
+            if partition
+                .leader_epoch
+                .is_some_and(|epoch| epoch >= update.leader_epoch)
+            {
+                continue;
+            }
+
+            partition.leader = Some(update.leader);
+            partition.leader_epoch = Some(update.leader_epoch);
+            partition.error_code = None;
+            updated += 1;
+        }
+
+        (metadata, updated)
+    }
+}
+
+/// The metadata of a single topic.
+#[derive(Debug, Clone)]
+pub struct TopicInfo {
+    /// The topic's name.
+    pub name: String,
+
+    /// The topic's ID, or None if the brokers are too old to report it.
+    pub id: Option<Uuid>,
+
+    /// Whether the topic is internal to Kafka.
+    pub is_internal: bool,
+
+    /// The error the broker reported for the topic, or None if there was no error.
+    pub error_code: Option<ErrorCode>,
+
+    /// The topic's partitions, by index.
+    pub partitions: BTreeMap<PartitionIndex, PartitionInfo>,
+}
+
+impl TopicInfo {
+    /// Returns the partition with the given index.
+    pub fn partition(&self, index: PartitionIndex) -> Option<&PartitionInfo> {
+        self.partitions.get(&index)
+    }
+
+    /// Returns how many partitions the topic has.
+    pub fn partition_count(&self) -> usize {
+        self.partitions.len()
+    }
+}
+
+impl From<TopicMetadata> for TopicInfo {
+    fn from(topic: TopicMetadata) -> Self {
+        Self {
+            name: topic.name.unwrap_or_default(),
+            id: Some(topic.id).filter(|id| *id != Uuid::ZERO),
+            is_internal: topic.is_internal,
+            error_code: topic.error_code,
+            partitions: topic
+                .partitions
+                .into_iter()
+                .map(|partition| (partition.index, PartitionInfo::from(partition)))
+                .collect(),
+        }
+    }
+}
+
+/// The metadata of a single partition.
+#[derive(Debug, Clone)]
+pub struct PartitionInfo {
+    /// The partition's index.
+    pub index: PartitionIndex,
+
+    /// The ID of the partition's leader, or None if it has no leader.
+    pub leader: Option<BrokerId>,
+
+    /// The epoch of the partition's leader, or None if the brokers are too old to report it.
+    pub leader_epoch: Option<Epoch>,
+
+    /// The brokers which host a replica of the partition.
+    pub replicas: Vec<BrokerId>,
+
+    /// The replicas which are in sync with the leader.
+    pub isr: Vec<BrokerId>,
+
+    /// The replicas which are offline.
+    pub offline_replicas: Vec<BrokerId>,
+
+    /// The error the broker reported for the partition, or None if there was no error.
+    pub error_code: Option<ErrorCode>,
+}
+
+impl From<PartitionMetadata> for PartitionInfo {
+    fn from(partition: PartitionMetadata) -> Self {
+        Self {
+            index: partition.index,
+            leader: Some(BrokerId(partition.leader_id)).filter(|id| id.0 >= 0),
+            leader_epoch: Some(partition.leader_epoch).filter(|epoch| epoch.0 >= 0),
+            replicas: partition.replica_nodes,
+            isr: partition.isr_nodes,
+            offline_replicas: partition.offline_replicas,
+            error_code: partition.error_code,
+        }
+    }
+}
+
+/// Discovers a cluster from its bootstrap servers and caches its metadata.
+///
+/// Metadata is refreshed for the topics which have been [tracked](Cluster::track), whenever
+/// [refresh](Cluster::refresh) is called, when [topic](Cluster::topic) is called for an unknown
+/// topic, and in the background by [spawn_refresher](Cluster::spawn_refresher): every
+/// [metadata_max_age](ClusterConfig::metadata_max_age), or as soon as possible after the
+/// metadata was [invalidated](Cluster::invalidate).
+#[derive(Debug)]
+pub struct Cluster {
+    config: ClusterConfig,
+    state: Mutex<State>,
+    refreshing: tokio::sync::Mutex<()>,
+    wakeup: Arc<Notify>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    metadata: Arc<Metadata>,
+    topics: HashSet<String>,
+    last_refresh: Option<Instant>,
+    last_attempt: Option<Instant>,
+    stale: bool,
+    connection: Option<PipelinedConnection>,
+    next_node: usize,
+}
+
+impl Cluster {
+    /// Creates a cluster which has not fetched any metadata yet.
+    pub fn new(config: ClusterConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(State {
+                stale: true,
+                ..Default::default()
+            }),
+            refreshing: tokio::sync::Mutex::new(()),
+            wakeup: Arc::default(),
+        }
+    }
+
+    /// Creates a cluster and fetches its metadata from the bootstrap servers.
+    pub async fn connect(config: ClusterConfig) -> Result<Self, Error> {
+        let cluster = Self::new(config);
+        cluster.refresh().await?;
+        Ok(cluster)
+    }
+
+    /// Returns the configuration of this cluster.
+    pub fn config(&self) -> &ClusterConfig {
+        &self.config
+    }
+
+    /// Returns the latest metadata.
+    pub fn metadata(&self) -> Arc<Metadata> {
+        Arc::clone(&self.state().metadata)
+    }
+
+    /// Replaces the cached metadata as if it had just been refreshed.
+    #[cfg(test)]
+    pub(crate) fn set_metadata(&self, metadata: Metadata) {
+        let mut state = self.state();
+        state.metadata = Arc::new(metadata);
+        state.stale = false;
+        state.last_refresh = Some(Instant::now());
+    }
+
+    /// Adds topics to those whose metadata is fetched, and invalidates the metadata if any of
+    /// them are new.
+    pub fn track(&self, topics: impl IntoIterator<Item = impl Into<String>>) {
+        let mut state = self.state();
+        let mut added = false;
+
+        for topic in topics {
+            added |= state.topics.insert(topic.into());
+        }
+
+        if added {
+            state.stale = true;
+            self.wakeup.notify_one();
+        }
+    }
+
+    /// Removes topics from those whose metadata is fetched. They are dropped from the metadata
+    /// with the next refresh.
+    pub fn untrack<'a>(&self, topics: impl IntoIterator<Item = &'a str>) {
+        let mut state = self.state();
+
+        for topic in topics {
+            state.topics.remove(topic);
+        }
+    }
+
+    /// Marks the metadata as stale, so that it is refreshed as soon as the backoff allows.
+    pub fn invalidate(&self) {
+        self.state().stale = true;
+        self.wakeup.notify_one();
+    }
+
+    /// Invalidates the metadata if the given error code means it is stale, and returns whether
+    /// it did.
+    pub fn handle_error_code(&self, error_code: ErrorCode) -> bool {
+        let invalidates = error_code.invalidates_metadata();
+        if invalidates {
+            self.invalidate();
+        }
+        invalidates
+    }
+
+    /// Applies partition leaders reported by brokers which no longer lead those partitions, and
+    /// returns how many partitions changed leader.
+    ///
+    /// `endpoints` are the addresses of the new leaders, which may not be part of the cached
+    /// metadata yet. Leaders which are older than the cached ones, or whose broker is not known,
+    /// are ignored.
+    pub fn update_leaders(
+        &self,
+        leaders: impl IntoIterator<Item = LeaderUpdate>,
+        endpoints: impl IntoIterator<Item = BrokerMetadata>,
+    ) -> usize {
+        let mut state = self.state();
+        let (metadata, updated) = state.metadata.with_leaders(leaders, endpoints);
+
+        if updated > 0 {
+            state.metadata = Arc::new(metadata);
+        }
+        updated
+    }
+
+    /// Applies the [current_leader](prague_protocol::produce::response::PartitionProduceResult::current_leader)
+    /// of each partition in a produce response, and returns how many partitions changed leader.
+    pub fn update_leaders_from_produce(&self, response: &ProduceResponse) -> usize {
+        let leaders = response.topics.iter().flat_map(|topic| {
+            topic.partitions.iter().filter_map(|partition| {
+                let leader = partition.current_leader.as_ref()?;
+                Some(LeaderUpdate {
+                    topic: topic.name.clone(),
+                    partition: PartitionIndex(partition.index),
+                    leader: BrokerId(leader.id?),
+                    leader_epoch: Epoch(leader.epoch?),
+                })
+            })
+        });
+
+        let endpoints = response.endpoints.iter().map(|node| BrokerMetadata {
+            id: BrokerId(node.id),
+            hostname: node.hostname.clone(),
+            port: node.port,
+            rack: node.rack.clone(),
+            tagged_fields: Default::default(),
+        });
+
+        self.update_leaders(leaders.collect::<Vec<_>>(), endpoints)
+    }
+
+    /// Applies the [current_leader](prague_protocol::fetch::response::Partition::current_leader)
+    /// of each partition in a fetch response, and returns how many partitions changed leader.
+    ///
+    /// Topics which are referred to by an unknown ID are skipped.
+    pub fn update_leaders_from_fetch(&self, response: &FetchResponse) -> usize {
+        let metadata = self.metadata();
+        let leaders = response.topic_responses.iter().flat_map(|topic| {
+            let name = match &topic.topic {
+                TopicRef::Name(name) => Some(name.as_str()),
+                TopicRef::Id(id) => metadata.topic_name(*id),
+            };
+
+            topic.partitions.iter().filter_map(move |partition| {
+                let leader = partition.current_leader.as_ref()?;
+                Some(LeaderUpdate {
+                    topic: name?.to_owned(),
+                    partition: partition.index,
+                    leader: leader.leader_id?,
+                    leader_epoch: leader.leader_epoch?,
+                })
+            })
+        });
+
+        let endpoints = response.node_endpoints.iter().map(|node| BrokerMetadata {
+            id: node.node_id,
+            hostname: node.host.clone(),
+            port: node.port,
+            rack: node.rack.clone(),
+            tagged_fields: Default::default(),
+        });
+
+        self.update_leaders(leaders.collect::<Vec<_>>(), endpoints)
+    }
+
+    /// Returns when the metadata should next be refreshed.
+    pub fn next_refresh(&self) -> Instant {
+        let state = self.state();
+
+        match (state.stale, state.last_refresh, state.last_attempt) {
+            (true, _, Some(attempt)) => attempt + self.config.refresh_backoff,
+            (true, _, None) => Instant::now(),
+            (false, Some(refresh), _) => refresh + self.config.metadata_max_age,
+            (false, None, _) => Instant::now(),
+        }
+    }
+
+    /// Returns the metadata of a topic, tracking it and refreshing the metadata until it is
+    /// known or [max_block](ClusterConfig::max_block) elapses.
+    ///
+    /// Retriable errors, such as a topic whose leaders are still being elected, are retried
+    /// after the [refresh_backoff](ClusterConfig::refresh_backoff). Other errors are returned.
+    pub async fn topic(&self, name: &str) -> Result<TopicInfo, Error> {
+        let deadline = Instant::now() + self.config.max_block;
+        self.track([name]);
+
+        loop {
+            if let Some(topic) = self.metadata().topic(name) {
+                match topic.error_code {
+                    // A topic without partitions is retried like one without leaders.
+                    None if !topic.partitions.is_empty()
+                        && topic.partitions.values().all(|p| p.leader.is_some()) =>
+                    {
+                        return Ok(topic.clone());
+                    }
+                    Some(error_code) if !error_code.is_retriable() => {
+                        return Err(Error::Topic {
+                            topic: name.to_owned(),
+                            error_code,
+                        });
+                    }
+                    _ => self.invalidate(),
+                }
+            }
+
+            let next_refresh = self.next_refresh();
+            if next_refresh >= deadline {
+                return Err(Error::Timeout(name.to_owned()));
+            }
+
+            time::sleep_until(next_refresh).await;
+            if let Err(error) = self.refresh().await {
+                if Instant::now() + self.config.refresh_backoff >= deadline {
+                    return Err(error);
+                }
+            }
+        }
+    }
+
+    /// Fetches the metadata of every tracked topic and returns it.
+    ///
+    /// Only one refresh runs at a time. A caller which waited for another refresh to finish
+    /// reuses its result, unless the metadata was invalidated in the meantime.
+    pub async fn refresh(&self) -> Result<Arc<Metadata>, Error> {
+        let requested_at = Instant::now();
+        let _refreshing = self.refreshing.lock().await;
+
+        {
+            let state = self.state();
+            if !state.stale && state.last_refresh.is_some_and(|last| last >= requested_at) {
+                return Ok(Arc::clone(&state.metadata));
+            }
+        }
+
+        let (tracked, candidates) = {
+            let mut state = self.state();
+            state.last_attempt = Some(Instant::now());
+            // Anything which invalidates the metadata from now on needs another refresh.
+            state.stale = false;
+            (state.topics.clone(), self.candidates(&state))
+        };
+
+        let request = MetadataRequest {
+            topics: Some(tracked.iter().map(Topic::named).collect()),
+            allow_auto_topic_creation: self.config.allow_auto_topic_creation,
+            include_cluster_authorized_operations: false,
+            include_topic_authorized_operations: false,
+            tagged_fields: Default::default(),
+        };
+
+        let response = match self.send(&request, candidates).await {
+            Ok(response) => response,
+            Err(error) => {
+                self.state().stale = true;
+                return Err(error);
+            }
+        };
+
+        let mut state = self.state();
+        let metadata = Arc::new(state.metadata.merge(response, &state.topics));
+
+        // Topics whose metadata is incomplete are refreshed again after the backoff.
+        let incomplete = tracked.iter().any(|name| match metadata.topic(name) {
+            Some(topic) => {
+                topic.error_code.is_some_and(|code| code.is_retriable())
+                    || topic.partitions.values().any(|p| p.leader.is_none())
+            }
+            None => true,
+        });
+
+        state.metadata = Arc::clone(&metadata);
+        state.last_refresh = Some(Instant::now());
+        state.stale |= incomplete;
+        Ok(metadata)
+    }
+
+    /// Spawns a task which refreshes the metadata on schedule, until the cluster is dropped.
+    ///
+    /// Refreshes happen every [metadata_max_age](ClusterConfig::metadata_max_age), or after the
+    /// [refresh_backoff](ClusterConfig::refresh_backoff) once the metadata is invalidated.
+    pub fn spawn_refresher(self: &Arc<Self>) -> JoinHandle<()> {
+        let cluster = Arc::downgrade(self);
+        let wakeup = Arc::clone(&self.wakeup);
+
+        tokio::spawn(async move {
+            loop {
+                let Some(next_refresh) = cluster.upgrade().map(|cluster| cluster.next_refresh())
+                else {
+                    return;
+                };
+
+                // Invalidation wakes the task early, so that it waits for the backoff instead.
+                if time::timeout_at(next_refresh, wakeup.notified())
+                    .await
+                    .is_ok()
+                {
+                    continue;
+                }
+
+                let Some(cluster) = cluster.upgrade() else {
+                    return;
+                };
+
+                // Failures are retried after the backoff.
+                let _ = cluster.refresh().await;
+            }
+        })
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|error| error.into_inner())
+    }
+
+    /// Returns the addresses to fetch metadata from, in the order to try them: known brokers
+    /// first, then the bootstrap servers, rotating between refreshes to spread the load.
+    fn candidates(&self, state: &State) -> Vec<String> {
+        let mut candidates: Vec<_> = state
+            .metadata
+            .brokers
+            .values()
+            .map(|broker| format!("{}:{}", broker.hostname, broker.port))
+            .chain(self.config.bootstrap_servers.iter().cloned())
+            .collect();
+
+        if !candidates.is_empty() {
+            let len = candidates.len();
+            candidates.rotate_left(state.next_node % len);
+        }
+
+        candidates
+    }
+
+    /// Sends a metadata request over the cached connection, or else the first candidate which
+    /// can be reached.
+    async fn send(
+        &self,
+        request: &MetadataRequest,
+        candidates: Vec<String>,
+    ) -> Result<MetadataResponse, Error> {
+        let cached = self
+            .state()
+            .connection
+            .clone()
+            .filter(|connection| !connection.is_closed());
+
+        if let Some(connection) = cached {
+            match connection.send::<MetadataApi>(request).await {
+                Ok(response) => return Ok(response),
+                Err(_) => self.state().connection = None,
+            }
+        }
+
+        let mut last_error = None;
+        for address in candidates {
+            self.state().next_node += 1;
+
+            let connection =
+                match PipelinedConnection::connect(address, self.config.connection.clone()).await {
+                    Ok(connection) => connection,
+                    Err(error) => {
+                        last_error = Some(error);
+                        continue;
+                    }
+                };
+
+            match connection.send::<MetadataApi>(request).await {
+                Ok(response) => {
+                    self.state().connection = Some(connection);
+                    return Ok(response);
+                }
+                Err(error) => last_error = Some(error),
+            }
+        }
+
+        Err(last_error.map_or(Error::NoBrokers, Error::Unreachable))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prague_protocol::fetch::response::{self, LeaderIdAndEpoch, NodeEndpoint};
+
+    use super::*;
+
+    const TOPIC_ID: Uuid = Uuid(7);
+
+    fn broker(id: i32) -> BrokerMetadata {
+        BrokerMetadata {
+            id: BrokerId(id),
+            hostname: format!("broker-{id}"),
+            port: 9092,
+            rack: None,
+            tagged_fields: Default::default(),
+        }
+    }
+
+    fn partition(index: i32, leader: i32, leader_epoch: i32) -> PartitionInfo {
+        PartitionInfo {
+            index: PartitionIndex(index),
+            leader: Some(BrokerId(leader)),
+            leader_epoch: Some(Epoch(leader_epoch)),
+            replicas: vec![],
+            isr: vec![],
+            offline_replicas: vec![],
+            error_code: None,
+        }
+    }
+
+    /// Returns metadata with brokers 1 and 2, in which broker 1 leads both partitions of `topic`
+    /// with epoch 5.
+    fn metadata() -> Metadata {
+        let topic = TopicInfo {
+            name: "topic".to_owned(),
+            id: Some(TOPIC_ID),
+            is_internal: false,
+            error_code: None,
+            partitions: [partition(0, 1, 5), partition(1, 1, 5)]
+                .into_iter()
+                .map(|partition| (partition.index, partition))
+                .collect(),
+        };
+
+        Metadata {
+            cluster_id: None,
+            controller_id: None,
+            brokers: [broker(1), broker(2)]
+                .into_iter()
+                .map(|broker| (broker.id, broker))
+                .collect(),
+            topics: [("topic".to_owned(), topic)].into_iter().collect(),
+            topic_names: [(TOPIC_ID, "topic".to_owned())].into_iter().collect(),
+        }
+    }
+
+    /// Returns a cluster whose cached metadata is fresh.
+    fn cluster(metadata: Metadata) -> Cluster {
+        let cluster = Cluster::new(ClusterConfig::new(["127.0.0.1:1"]));
+        cluster.set_metadata(metadata);
+        cluster
+    }
+
+    fn leader(metadata: &Metadata, index: i32) -> (Option<BrokerId>, Option<Epoch>) {
+        let partition = metadata.partition("topic", PartitionIndex(index)).unwrap();
+        (partition.leader, partition.leader_epoch)
+    }
+
+    #[test]
+    fn fetch_leader_hint_moves_leader_without_refresh() {
+        let cluster = cluster(metadata());
+        let response = FetchResponse {
+            throttle_time: None,
+            error_code: None,
+            session_id: None,
+            topic_responses: vec![response::Topic {
+                topic: TopicRef::Id(TOPIC_ID),
+                partitions: vec![response::Partition {
+                    index: PartitionIndex(0),
+                    error_code: Some(ErrorCode::NotLeaderOrFollower),
+                    high_watermark: Offset(-1),
+                    last_stable_offset: Offset(-1),
+                    log_start_offset: Offset(-1),
+                    aborted_transactions: vec![],
+                    preferred_read_replica: BrokerId(-1),
+                    records: vec![],
+                    current_leader: Some(LeaderIdAndEpoch {
+                        leader_id: Some(BrokerId(3)),
+                        leader_epoch: Some(Epoch(6)),
+                    }),
+                    tagged_fields: Default::default(),
+                }],
+                tagged_fields: Default::default(),
+            }],
+            node_endpoints: vec![NodeEndpoint {
+                node_id: BrokerId(3),
+                host: "broker-3".to_owned(),
+                port: 9093,
+                rack: None,
+            }],
+            tagged_fields: Default::default(),
+        };
+
+        assert_eq!(cluster.update_leaders_from_fetch(&response), 1);
+
+        let metadata = cluster.metadata();
+        assert_eq!(leader(&metadata, 0), (Some(BrokerId(3)), Some(Epoch(6))));
+        assert_eq!(leader(&metadata, 1), (Some(BrokerId(1)), Some(Epoch(5))));
+        assert_eq!(
+            metadata.leader("topic", PartitionIndex(0)).unwrap().port,
+            9093
+        );
+        assert!(!cluster.state().stale);
+    }
+
+    fn topic_metadata(id: Uuid, partitions: Vec<PartitionMetadata>) -> TopicMetadata {
+        TopicMetadata {
+            error_code: None,
+            name: Some("topic".to_owned()),
+            id,
+            is_internal: false,
+            partitions,
+            authorized_operations: None,
+            tagged_fields: Default::default(),
+        }
+    }
+
+    fn partition_metadata(index: i32, leader_id: i32, leader_epoch: i32) -> PartitionMetadata {
+        PartitionMetadata {
+            error_code: None,
+            index: PartitionIndex(index),
+            leader_id,
+            leader_epoch: Epoch(leader_epoch),
+            replica_nodes: vec![],
+            isr_nodes: vec![],
+            offline_replicas: vec![],
+            tagged_fields: Default::default(),
+        }
+    }
+
+    fn metadata_response(topics: Vec<TopicMetadata>) -> MetadataResponse {
+        MetadataResponse {
+            throttle_time: None,
+            brokers: vec![broker(1), broker(2)],
+            cluster_id: Some("cluster".to_owned()),
+            controller_id: 1,
+            topics,
+            cluster_authorized_operations: None,
+            tagged_fields: Default::default(),
+        }
+    }
+
+    fn tracked(topics: &[&str]) -> HashSet<String> {
+        topics.iter().map(|topic| topic.to_string()).collect()
+    }
+
+    fn leader_update(partition: i32, leader: i32, leader_epoch: i32) -> LeaderUpdate {
+        LeaderUpdate {
+            topic: "topic".to_owned(),
+            partition: PartitionIndex(partition),
+            leader: BrokerId(leader),
+            leader_epoch: Epoch(leader_epoch),
+        }
+    }
+
+    #[test]
+    fn merge_keeps_newer_cached_epochs() {
+        // Partition 0 comes from a broker with stale metadata, while partition 1 moved on.
+        let response = metadata_response(vec![topic_metadata(
+            TOPIC_ID,
+            vec![partition_metadata(0, 2, 4), partition_metadata(1, 2, 6)],
+        )]);
+        let merged = metadata().merge(response, &tracked(&["topic"]));
+
+        assert_eq!(leader(&merged, 0), (Some(BrokerId(1)), Some(Epoch(5))));
+        assert_eq!(leader(&merged, 1), (Some(BrokerId(2)), Some(Epoch(6))));
+        assert_eq!(merged.cluster_id.as_deref(), Some("cluster"));
+        assert_eq!(merged.topic_name(TOPIC_ID), Some("topic"));
+    }
+
+    #[test]
+    fn merge_replaces_recreated_topics() {
+        // A topic with a new ID was deleted and recreated, so its epochs start over.
+        let response = metadata_response(vec![topic_metadata(
+            Uuid(8),
+            vec![partition_metadata(0, 2, 0)],
+        )]);
+        let merged = metadata().merge(response, &tracked(&["topic"]));
+
+        assert_eq!(leader(&merged, 0), (Some(BrokerId(2)), Some(Epoch(0))));
+        assert!(merged.partition("topic", PartitionIndex(1)).is_none());
+        assert_eq!(merged.topic_id("topic"), Some(Uuid(8)));
+    }
+
+    #[test]
+    fn merge_drops_untracked_topics() {
+        let merged = metadata().merge(metadata_response(vec![]), &tracked(&[]));
+        assert!(merged.topic("topic").is_none());
+
+        let merged = metadata().merge(metadata_response(vec![]), &tracked(&["topic"]));
+        assert_eq!(leader(&merged, 0), (Some(BrokerId(1)), Some(Epoch(5))));
+    }
+
+    #[test]
+    fn with_leaders_applies_only_newer_epochs_of_known_brokers() {
+        let (updated, count) = metadata().with_leaders(
+            [
+                leader_update(0, 2, 5),
+                leader_update(1, 3, 6),
+                leader_update(2, 2, 6),
+            ],
+            [],
+        );
+        assert_eq!(count, 0);
+        assert_eq!(leader(&updated, 0), (Some(BrokerId(1)), Some(Epoch(5))));
+        assert_eq!(leader(&updated, 1), (Some(BrokerId(1)), Some(Epoch(5))));
+
+        let (updated, count) = metadata().with_leaders(
+            [leader_update(0, 2, 6), leader_update(1, 3, 6)],
+            [broker(3)],
+        );
+        assert_eq!(count, 2);
+        assert_eq!(leader(&updated, 0), (Some(BrokerId(2)), Some(Epoch(6))));
+        assert_eq!(leader(&updated, 1), (Some(BrokerId(3)), Some(Epoch(6))));
+        assert!(updated.broker(BrokerId(3)).is_some());
+    }
+
+    #[test]
+    fn update_leaders_keeps_metadata_without_changes() {
+        let cluster = cluster(metadata());
+        let before = cluster.metadata();
+
+        assert_eq!(cluster.update_leaders([leader_update(0, 2, 4)], []), 0);
+        assert!(Arc::ptr_eq(&before, &cluster.metadata()));
+
+        assert_eq!(cluster.update_leaders([leader_update(0, 2, 6)], []), 1);
+        assert_eq!(
+            leader(&cluster.metadata(), 0),
+            (Some(BrokerId(2)), Some(Epoch(6)))
+        );
+        assert_eq!(leader(&before, 0), (Some(BrokerId(1)), Some(Epoch(5))));
+    }
+
+    #[test]
+    fn invalidate_schedules_refresh_after_backoff() {
+        let invalidated = cluster(metadata());
+        let last_refresh = invalidated.state().last_refresh.unwrap();
+        assert_eq!(
+            invalidated.next_refresh(),
+            last_refresh + invalidated.config().metadata_max_age
+        );
+
+        assert!(invalidated.handle_error_code(ErrorCode::NotLeaderOrFollower));
+        assert!(invalidated.state().stale);
+        assert!(invalidated.next_refresh() <= Instant::now());
+
+        let attempt = Instant::now();
+        invalidated.state().last_attempt = Some(attempt);
+        assert_eq!(
+            invalidated.next_refresh(),
+            attempt + invalidated.config().refresh_backoff
+        );
+
+        let unaffected = cluster(metadata());
+        assert!(!unaffected.handle_error_code(ErrorCode::InvalidRecord));
+        assert!(!unaffected.state().stale);
+    }
+
+    #[tokio::test]
+    async fn topic_without_partitions_is_not_returned() {
+        let mut metadata = metadata();
+        metadata.topics.get_mut("topic").unwrap().partitions.clear();
+
+        let mut config = ClusterConfig::new(["127.0.0.1:1"]);
+        config.max_block = Duration::from_millis(200);
+        config.refresh_backoff = Duration::from_millis(50);
+
+        let cluster = Cluster::new(config);
+        cluster.state().metadata = Arc::new(metadata);
+
+        assert!(cluster.topic("topic").await.is_err());
+    }
+}
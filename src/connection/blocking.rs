@@ -0,0 +1,352 @@
+//! Includes a blocking connection to a single broker.
+
+use std::{
+    io::{self, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    time::Instant,
+};
+
+use prague_protocol::{
+    api::prelude::*,
+    api_versions::prelude::*,
+    codec::{self, Decode, Encode},
+    error_code::ErrorCode,
+    headers,
+    sasl_authenticate::prelude::*,
+    sasl_handshake::prelude::*,
+};
+
+use super::{
+    check_sasl_authenticate, check_sasl_handshake, classify, sasl_authenticate_request,
+    BrokerVersions, ConnectionConfig, Error, SASL_HANDSHAKE_VERSION,
+};
+
+/// A blocking connection to a single broker, which sends one request at a time.
+///
+/// After any failure which may leave a partial frame on the socket, such as a timeout or a broker
+/// which disconnects mid-frame, the connection is closed and every later request fails with
+/// [Closed](Error::Closed). A new connection must then be made.
+#[derive(Debug)]
+pub struct BrokerConnection {
+    stream: Option<TcpStream>,
+    config: ConnectionConfig,
+    versions: BrokerVersions,
+    next_correlation_id: i32,
+}
+
+impl BrokerConnection {
+    /// Connects to the first reachable address and negotiates API versions with the broker.
+    pub fn connect(addr: impl ToSocketAddrs, config: ConnectionConfig) -> Result<Self, Error> {
+        let mut last_error = None;
+
+        for addr in addr.to_socket_addrs()? {
+            match TcpStream::connect_timeout(&addr, config.connect_timeout) {
+                Ok(stream) => return Self::from_stream(stream, config),
+                Err(error) => last_error = Some(error),
+            }
+        }
+
+        Err(last_error.map_or(Error::Disconnected, classify))
+    }
+
+    /// Negotiates API versions over an already connected stream, and then authenticates if
+    /// [sasl](ConnectionConfig::sasl) credentials are configured.
+    pub fn from_stream(stream: TcpStream, config: ConnectionConfig) -> Result<Self, Error> {
+        stream.set_nodelay(true)?;
+
+        let mut connection = Self {
+            stream: Some(stream),
+            config,
+            versions: BrokerVersions::default(),
+            next_correlation_id: 0,
+        };
+
+        let request = connection.config.api_versions_request();
+        let mut version = ApiVersionsApi::MAX_VERSION;
+        let response = loop {
+            let response = connection.send_versioned::<ApiVersionsApi>(&request, version)?;
+
+            match response.error_code {
+                Some(ErrorCode::UnsupportedVersion) => {
+                    match BrokerVersions::retry_version(&response, version) {
+                        Some(retry) => version = retry,
+                        None => break response,
+                    }
+                }
+                _ => break response,
+            }
+        };
+
+        connection.versions = BrokerVersions::try_from(response)?;
+        connection.authenticate()?;
+        Ok(connection)
+    }
+
+    /// Runs the SASL exchange, if credentials are configured.
+    fn authenticate(&mut self) -> Result<(), Error> {
+        let Some(credentials) = self.config.sasl.clone() else {
+            return Ok(());
+        };
+
+        self.versions.negotiate::<SaslAuthenticateApi>()?;
+        let request = ConnectionConfig::sasl_handshake_request(&credentials);
+        let response = self.send_versioned::<SaslHandshakeApi>(&request, SASL_HANDSHAKE_VERSION)?;
+        check_sasl_handshake(&credentials, response)?;
+
+        let mut authenticator = credentials.authenticator()?;
+        let mut message = authenticator.step(None)?;
+        while let Some(auth_bytes) = message {
+            let response =
+                self.send::<SaslAuthenticateApi>(&sasl_authenticate_request(auth_bytes))?;
+            let server_message = check_sasl_authenticate(response)?;
+            message = authenticator.step(Some(&server_message))?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the versions of each API the broker supports.
+    pub fn versions(&self) -> &BrokerVersions {
+        &self.versions
+    }
+
+    /// Returns whether the connection was closed after a failure.
+    pub fn is_closed(&self) -> bool {
+        self.stream.is_none()
+    }
+
+    /// Sends a request with the newest version supported by both sides, and waits for its
+    /// response.
+    ///
+    /// The API must be a [VersionedApi], since its version range is needed to negotiate with the
+    /// broker, and its flexible version decides how the headers and body are encoded.
+    pub fn send<A>(&mut self, request: &A::Request) -> Result<A::Response, Error>
+    where
+        A: VersionedApi,
+        A::Request: Encode,
+        A::Response: Decode,
+    {
+        let version = self.versions.negotiate::<A>()?;
+        self.send_versioned::<A>(request, version)
+    }
+
+    /// Sends a request with the given version, and waits for its response.
+    pub fn send_versioned<A>(
+        &mut self,
+        request: &A::Request,
+        version: i16,
+    ) -> Result<A::Response, Error>
+    where
+        A: VersionedApi,
+        A::Request: Encode,
+        A::Response: Decode,
+    {
+        let correlation_id = self.next_correlation_id;
+        self.next_correlation_id = self.next_correlation_id.wrapping_add(1);
+
+        let header = headers::Request {
+            api_key: A::KEY,
+            api_version: version,
+            correlation_id,
+            client_id: self.config.client_id.clone(),
+            tagged_fields: Default::default(),
+        };
+        let frame = codec::encode_request::<A>(&header, request)?;
+
+        let result = self.round_trip(&frame, correlation_id);
+        if result.is_err() {
+            self.stream = None;
+        }
+
+        let (_, response) = codec::decode_response::<A>(&result?, version)?;
+        Ok(response)
+    }
+
+    /// Writes a request frame and reads the response frame, without its size.
+    fn round_trip(&mut self, frame: &[u8], correlation_id: i32) -> Result<Vec<u8>, Error> {
+        let deadline = Instant::now() + self.config.request_timeout;
+        let stream = self.stream.as_mut().ok_or(Error::Closed)?;
+
+        stream.set_write_timeout(Some(self.config.request_timeout))?;
+        stream.write_all(frame).map_err(classify)?;
+
+        let mut size = [0; 4];
+        match read_frame(stream, &mut size, deadline)? {
+            0 => return Err(Error::Disconnected),
+            received if received < size.len() => {
+                return Err(Error::TruncatedFrame {
+                    expected: size.len(),
+                    received,
+                })
+            }
+            _ => {}
+        }
+
+        let size = i32::from_be_bytes(size);
+        let len = usize::try_from(size).map_err(|_| Error::InvalidResponseSize(size))?;
+        if len > self.config.max_response_size {
+            return Err(Error::ResponseTooLarge(len));
+        }
+
+        let mut response = vec![0; len];
+        let received = read_frame(stream, &mut response, deadline)?;
+        if received < len {
+            return Err(Error::TruncatedFrame {
+                expected: len,
+                received,
+            });
+        }
+
+        let received = codec::peek_correlation_id(&response)?;
+        match received == correlation_id {
+            true => Ok(response),
+            false => Err(Error::CorrelationMismatch {
+                expected: correlation_id,
+                received,
+            }),
+        }
+    }
+}
+
+/// Fills the buffer from the stream before the deadline, and returns how many bytes were read
+/// before the broker closed the connection.
+fn read_frame(stream: &mut TcpStream, buf: &mut [u8], deadline: Instant) -> Result<usize, Error> {
+    let mut received = 0;
+
+    while received < buf.len() {
+        let timeout = deadline
+            .checked_duration_since(Instant::now())
+            .filter(|timeout| !timeout.is_zero())
+            .ok_or(Error::Timeout)?;
+        stream.set_read_timeout(Some(timeout))?;
+
+        match stream.read(&mut buf[received..]) {
+            Ok(0) => break,
+            Ok(read) => received += read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(classify(error)),
+        }
+    }
+
+    Ok(received)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::TcpListener, thread, time::Duration};
+
+    use super::*;
+
+    /// Accepts one connection on a local listener, and hands it to the broker thread.
+    fn connect(broker: impl FnOnce(TcpStream) + Send + 'static) -> BrokerConnection {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || broker(listener.accept().unwrap().0));
+
+        BrokerConnection {
+            stream: Some(TcpStream::connect(addr).unwrap()),
+            config: ConnectionConfig {
+                request_timeout: Duration::from_millis(200),
+                ..ConnectionConfig::default()
+            },
+            versions: BrokerVersions::default(),
+            next_correlation_id: 0,
+        }
+    }
+
+    /// Reads a request frame, and returns its body.
+    fn read_request(stream: &mut TcpStream) -> Vec<u8> {
+        let mut size = [0; 4];
+        stream.read_exact(&mut size).unwrap();
+        let mut body = vec![0; i32::from_be_bytes(size) as usize];
+        stream.read_exact(&mut body).unwrap();
+        body
+    }
+
+    fn send(connection: &mut BrokerConnection) -> Result<ApiVersionsResponse, Error> {
+        let request = connection.config.api_versions_request();
+        connection.send_versioned::<ApiVersionsApi>(&request, ApiVersionsApi::MAX_VERSION)
+    }
+
+    #[test]
+    fn disconnect_mid_frame_is_truncated() {
+        let mut connection = connect(|mut stream| {
+            read_request(&mut stream);
+            stream.write_all(&10_i32.to_be_bytes()).unwrap();
+            stream.write_all(&[0, 0, 0]).unwrap();
+        });
+
+        assert!(matches!(
+            send(&mut connection),
+            Err(Error::TruncatedFrame {
+                expected: 10,
+                received: 3
+            })
+        ));
+        assert!(connection.is_closed());
+        assert!(matches!(send(&mut connection), Err(Error::Closed)));
+    }
+
+    #[test]
+    fn disconnect_mid_size_is_truncated() {
+        let mut connection = connect(|mut stream| {
+            read_request(&mut stream);
+            stream.write_all(&[0, 0]).unwrap();
+        });
+
+        assert!(matches!(
+            send(&mut connection),
+            Err(Error::TruncatedFrame {
+                expected: 4,
+                received: 2
+            })
+        ));
+        assert!(connection.is_closed());
+    }
+
+    #[test]
+    fn clean_eof_is_disconnected() {
+        let mut connection = connect(|mut stream| {
+            read_request(&mut stream);
+        });
+
+        assert!(matches!(send(&mut connection), Err(Error::Disconnected)));
+        assert!(connection.is_closed());
+    }
+
+    #[test]
+    fn silent_broker_times_out() {
+        let mut connection = connect(|mut stream| {
+            read_request(&mut stream);
+            // Holds the connection open until the client closes it.
+            let _ = stream.read(&mut [0]);
+        });
+
+        let started = Instant::now();
+        assert!(matches!(send(&mut connection), Err(Error::Timeout)));
+        assert!(started.elapsed() >= Duration::from_millis(200));
+        assert!(connection.is_closed());
+    }
+
+    #[test]
+    fn mismatched_correlation_id_is_rejected() {
+        let mut connection = connect(|mut stream| {
+            let request = read_request(&mut stream);
+            // The header starts with the api key, version, and then the correlation ID.
+            let sent = i32::from_be_bytes(request[4..8].try_into().unwrap());
+
+            let mut response = 4_i32.to_be_bytes().to_vec();
+            response.extend_from_slice(&(sent + 1).to_be_bytes());
+            stream.write_all(&response).unwrap();
+        });
+
+        assert!(matches!(
+            send(&mut connection),
+            Err(Error::CorrelationMismatch {
+                expected: 0,
+                received: 1
+            })
+        ));
+        assert!(connection.is_closed());
+    }
+}
@@ -0,0 +1,248 @@
+//! Includes connections to individual brokers.
+//!
+//! A connection sends requests framed with a [request header](prague_protocol::headers::Request)
+//! and decodes the matching responses. When it connects, it asks the broker which versions of
+//! each API it supports, and then sends every request with the newest version both sides
+//! support. If credentials are configured, it then authenticates with SASL before any other
+//! request is sent.
+
+use std::{collections::HashMap, io, sync::Arc, time::Duration};
+
+use prague_protocol::{
+    api::prelude::*,
+    api_versions::prelude::*,
+    error_code::ErrorCode,
+    sasl::{self, SaslCredentials},
+    sasl_authenticate::prelude::*,
+    sasl_handshake::prelude::*,
+    types::prelude::TaggedFields,
+};
+
+pub mod blocking;
+pub mod pipelined;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    #[error(transparent)]
+    Codec(#[from] prague_protocol::codec::Error),
+
+    #[error("Timed out waiting for the broker")]
+    Timeout,
+
+    #[error("Broker closed the connection")]
+    Disconnected,
+
+    #[error("Broker closed the connection after `{received}` of `{expected}` bytes of a frame")]
+    TruncatedFrame { expected: usize, received: usize },
+
+    #[error("Connection was closed after a previous failure")]
+    Closed,
+
+    #[error("Connection failed while the request was in flight: {0}")]
+    ConnectionFailed(Arc<Error>),
+
+    #[error("Expected a response with correlation ID `{expected}`, but received `{received}`")]
+    CorrelationMismatch { expected: i32, received: i32 },
+
+    #[error("Received a response with correlation ID `{0}`, which matches no pending request")]
+    UnexpectedCorrelationId(i32),
+
+    #[error("Response of `{0}` bytes exceeds the maximum response size")]
+    ResponseTooLarge(usize),
+
+    #[error("Response size `{0}` was not valid")]
+    InvalidResponseSize(i32),
+
+    #[error("Broker does not support any version of `{0:?}` which this client supports")]
+    UnsupportedApi(ApiKey),
+
+    #[error("Broker rejected the API versions request with `{0:?}`")]
+    ApiVersions(ErrorCode),
+
+    #[error("Broker rejected SASL mechanism `{mechanism}` with `{error_code:?}`; it enables {enabled:?}")]
+    SaslHandshake {
+        mechanism: &'static str,
+        error_code: ErrorCode,
+        enabled: Vec<String>,
+    },
+
+    #[error("Authentication failed with `{error_code:?}`: {}", message.as_deref().unwrap_or("no message"))]
+    Authentication {
+        error_code: ErrorCode,
+        message: Option<String>,
+    },
+
+    #[error(transparent)]
+    Sasl(#[from] sasl::Error),
+}
+
+/// Configures a connection to a broker.
+#[derive(Debug, Clone)]
+pub struct ConnectionConfig {
+    /// The client ID sent with every request, which brokers use in logs and quotas.
+    pub client_id: Option<String>,
+
+    /// The name of the client software sent to brokers which support KIP-511.
+    pub client_software_name: String,
+
+    /// The version of the client software sent to brokers which support KIP-511.
+    pub client_software_version: String,
+
+    /// How long to wait for a connection to be established.
+    pub connect_timeout: Duration,
+
+    /// How long to wait for the broker to respond to a request.
+    pub request_timeout: Duration,
+
+    /// The largest response which will be accepted, to guard against corrupt size prefixes.
+    pub max_response_size: usize,
+
+    /// The credentials with which to authenticate once connected, or None if the broker does not
+    /// require authentication.
+    pub sasl: Option<SaslCredentials>,
+
+    /// The most requests which a pipelined connection sends before waiting for responses.
+    /// Blocking connections only ever have one request in flight.
+    pub max_in_flight: usize,
+}
+
+impl Default for ConnectionConfig {
+    fn default() -> Self {
+        Self {
+            client_id: Some("prague".to_owned()),
+            client_software_name: env!("CARGO_PKG_NAME").to_owned(),
+            client_software_version: env!("CARGO_PKG_VERSION").to_owned(),
+            connect_timeout: Duration::from_secs(10),
+            request_timeout: Duration::from_secs(30),
+            max_response_size: 100 * 1024 * 1024,
+            sasl: None,
+            max_in_flight: 5,
+        }
+    }
+}
+
+impl ConnectionConfig {
+    /// Builds the API versions request sent when connecting.
+    pub(crate) fn api_versions_request(&self) -> ApiVersionsRequest {
+        ApiVersionsRequest {
+            client_software_name: self.client_software_name.clone(),
+            client_software_version: self.client_software_version.clone(),
+            tagged_fields: TaggedFields::default(),
+        }
+    }
+
+    /// Builds the SASL handshake request sent after negotiating API versions.
+    pub(crate) fn sasl_handshake_request(credentials: &SaslCredentials) -> SaslHandshakeRequest {
+        SaslHandshakeRequest {
+            mechanism: credentials.mechanism_name().to_owned(),
+        }
+    }
+}
+
+/// The version of [SaslHandshakeApi] which is followed by [SaslAuthenticateApi] requests, rather
+/// than raw SASL frames.
+pub(crate) const SASL_HANDSHAKE_VERSION: i16 = 1;
+
+/// Checks that the broker accepted the SASL mechanism.
+pub(crate) fn check_sasl_handshake(
+    credentials: &SaslCredentials,
+    response: SaslHandshakeResponse,
+) -> Result<(), Error> {
+    match response.error_code {
+        Some(error_code) => Err(Error::SaslHandshake {
+            mechanism: credentials.mechanism_name(),
+            error_code,
+            enabled: response.mechanisms,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Wraps a SASL message in an authenticate request.
+pub(crate) fn sasl_authenticate_request(auth_bytes: Vec<u8>) -> SaslAuthenticateRequest {
+    SaslAuthenticateRequest {
+        auth_bytes,
+        tagged_fields: TaggedFields::default(),
+    }
+}
+
+/// Returns the broker's SASL message, or the reason authentication failed.
+pub(crate) fn check_sasl_authenticate(
+    response: SaslAuthenticateResponse,
+) -> Result<Vec<u8>, Error> {
+    match response.error_code {
+        Some(error_code) => Err(Error::Authentication {
+            error_code,
+            message: response.error_message,
+        }),
+        None => Ok(response.auth_bytes),
+    }
+}
+
+/// The versions of each API which a broker supports.
+#[derive(Debug, Clone, Default)]
+pub struct BrokerVersions {
+    versions: HashMap<i16, (i16, i16)>,
+}
+
+impl BrokerVersions {
+    /// Returns the range of versions the broker supports for the given API, or None if it does
+    /// not support it.
+    pub fn get(&self, api_key: ApiKey) -> Option<(i16, i16)> {
+        self.versions.get(&i16::from(api_key)).copied()
+    }
+
+    /// Returns the newest version of the given API supported by both the broker and this client.
+    pub fn negotiate<A: VersionedApi>(&self) -> Result<i16, Error> {
+        let (min, max) = self.get(A::KEY).ok_or(Error::UnsupportedApi(A::KEY))?;
+        let version = max.min(A::MAX_VERSION);
+
+        match version >= min.max(A::MIN_VERSION) {
+            true => Ok(version),
+            false => Err(Error::UnsupportedApi(A::KEY)),
+        }
+    }
+
+    /// Returns the version of ApiVersions to retry with after the broker rejected the one sent,
+    /// which it reports in the response. Returns None if there is no better version to try.
+    pub(crate) fn retry_version(response: &ApiVersionsResponse, sent: i16) -> Option<i16> {
+        let (_, max) = response.versions(ApiKey::ApiVersions)?;
+        let version = max.min(ApiVersionsApi::MAX_VERSION);
+        (ApiVersionsApi::MIN_VERSION..sent)
+            .contains(&version)
+            .then_some(version)
+    }
+}
+
+impl TryFrom<ApiVersionsResponse> for BrokerVersions {
+    type Error = Error;
+
+    fn try_from(response: ApiVersionsResponse) -> Result<Self, Self::Error> {
+        if let Some(error_code) = response.error_code {
+            return Err(Error::ApiVersions(error_code));
+        }
+
+        let versions = response
+            .api_keys
+            .into_iter()
+            .map(|api| (api.api_key, (api.min_version, api.max_version)))
+            .collect();
+
+        Ok(Self { versions })
+    }
+}
+
+/// Maps I/O errors which signal a timeout or a closed connection to their own variants.
+pub(crate) fn classify(error: io::Error) -> Error {
+    match error.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => Error::Timeout,
+        io::ErrorKind::UnexpectedEof
+        | io::ErrorKind::ConnectionReset
+        | io::ErrorKind::ConnectionAborted
+        | io::ErrorKind::BrokenPipe => Error::Disconnected,
+        _ => Error::Io(error),
+    }
+}
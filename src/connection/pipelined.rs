@@ -0,0 +1,596 @@
+//! Includes an async connection to a single broker, which pipelines concurrent requests.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicI32, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use prague_protocol::{
+    api::prelude::*,
+    api_versions::prelude::*,
+    codec::{self, Decode, Encode},
+    error_code::ErrorCode,
+    headers,
+    sasl_authenticate::prelude::*,
+    sasl_handshake::prelude::*,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{
+        tcp::{OwnedReadHalf, OwnedWriteHalf},
+        TcpStream, ToSocketAddrs,
+    },
+    sync::{mpsc, oneshot, Semaphore},
+    time,
+};
+
+use super::{
+    check_sasl_authenticate, check_sasl_handshake, classify, sasl_authenticate_request,
+    BrokerVersions, ConnectionConfig, Error, SASL_HANDSHAKE_VERSION,
+};
+
+type Reply = oneshot::Sender<Result<Vec<u8>, Error>>;
+
+/// An async connection to a single broker, which sends concurrent requests over one socket and
+/// matches each response to its request by correlation ID.
+///
+/// Up to [max_in_flight](ConnectionConfig::max_in_flight) requests are sent before waiting for
+/// responses, and further requests wait for a slot. The connection is cheap to clone, and every
+/// clone shares the same socket.
+///
+/// If the broker disconnects, a frame cannot be read or written, or a request times out, the
+/// connection is closed: every pending request fails with
+/// [ConnectionFailed](Error::ConnectionFailed), and every later request fails with
+/// [Closed](Error::Closed). A new connection must then be made.
+#[derive(Debug, Clone)]
+pub struct PipelinedConnection {
+    shared: Arc<Shared>,
+    frames: mpsc::UnboundedSender<Vec<u8>>,
+    versions: Arc<BrokerVersions>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    config: ConnectionConfig,
+    next_correlation_id: AtomicI32,
+    in_flight: Semaphore,
+    pending: Mutex<Pending>,
+}
+
+#[derive(Debug, Default)]
+struct Pending {
+    replies: HashMap<i32, Reply>,
+    closed: bool,
+}
+
+impl PipelinedConnection {
+    /// Connects to the first reachable address and negotiates API versions with the broker.
+    ///
+    /// This must be called within a tokio runtime, since it spawns the tasks which read from and
+    /// write to the socket.
+    pub async fn connect(
+        addr: impl ToSocketAddrs,
+        config: ConnectionConfig,
+    ) -> Result<Self, Error> {
+        let stream = time::timeout(config.connect_timeout, TcpStream::connect(addr))
+            .await
+            .map_err(|_| Error::Timeout)?
+            .map_err(classify)?;
+
+        Self::from_stream(stream, config).await
+    }
+
+    /// Negotiates API versions over an already connected stream, and then authenticates if
+    /// [sasl](ConnectionConfig::sasl) credentials are configured.
+    pub async fn from_stream(stream: TcpStream, config: ConnectionConfig) -> Result<Self, Error> {
+        let mut connection = Self::spawn(stream, config)?;
+
+        let request = connection.shared.config.api_versions_request();
+        let mut version = ApiVersionsApi::MAX_VERSION;
+        let response = loop {
+            let response = connection
+                .send_versioned::<ApiVersionsApi>(&request, version)
+                .await?;
+
+            match response.error_code {
+                Some(ErrorCode::UnsupportedVersion) => {
+                    match BrokerVersions::retry_version(&response, version) {
+                        Some(retry) => version = retry,
+                        None => break response,
+                    }
+                }
+                _ => break response,
+            }
+        };
+
+        connection.versions = Arc::new(BrokerVersions::try_from(response)?);
+        connection.authenticate().await?;
+        Ok(connection)
+    }
+
+    /// Spawns the tasks which read from and write to the stream, without negotiating versions.
+    fn spawn(stream: TcpStream, config: ConnectionConfig) -> Result<Self, Error> {
+        stream.set_nodelay(true)?;
+
+        let (reader, writer) = stream.into_split();
+        let (frames, outgoing) = mpsc::unbounded_channel();
+        let shared = Arc::new(Shared {
+            in_flight: Semaphore::new(config.max_in_flight.max(1)),
+            config,
+            next_correlation_id: AtomicI32::new(0),
+            pending: Mutex::default(),
+        });
+
+        tokio::spawn(write_frames(Arc::clone(&shared), writer, outgoing));
+        tokio::spawn(read_frames(Arc::clone(&shared), reader));
+
+        Ok(Self {
+            shared,
+            frames,
+            versions: Arc::default(),
+        })
+    }
+
+    /// Runs the SASL exchange, if credentials are configured.
+    async fn authenticate(&self) -> Result<(), Error> {
+        let Some(credentials) = &self.shared.config.sasl else {
+            return Ok(());
+        };
+
+        self.versions.negotiate::<SaslAuthenticateApi>()?;
+        let request = ConnectionConfig::sasl_handshake_request(credentials);
+        let response = self
+            .send_versioned::<SaslHandshakeApi>(&request, SASL_HANDSHAKE_VERSION)
+            .await?;
+        check_sasl_handshake(credentials, response)?;
+
+        let mut authenticator = credentials.authenticator()?;
+        let mut message = authenticator.step(None)?;
+        while let Some(auth_bytes) = message {
+            let response = self
+                .send::<SaslAuthenticateApi>(&sasl_authenticate_request(auth_bytes))
+                .await?;
+            let server_message = check_sasl_authenticate(response)?;
+            message = authenticator.step(Some(&server_message))?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the versions of each API the broker supports.
+    pub fn versions(&self) -> &BrokerVersions {
+        &self.versions
+    }
+
+    /// Returns whether the connection was closed after a failure.
+    pub fn is_closed(&self) -> bool {
+        self.shared.pending().closed
+    }
+
+    /// Returns how many requests are waiting for a response.
+    pub fn in_flight(&self) -> usize {
+        self.shared.pending().replies.len()
+    }
+
+    /// Sends a request with the newest version supported by both sides, and waits for its
+    /// response.
+    pub async fn send<A>(&self, request: &A::Request) -> Result<A::Response, Error>
+    where
+        A: VersionedApi,
+        A::Request: Encode,
+        A::Response: Decode,
+    {
+        let version = self.versions.negotiate::<A>()?;
+        self.send_versioned::<A>(request, version).await
+    }
+
+    /// Sends a request with the given version, and waits for its response.
+    pub async fn send_versioned<A>(
+        &self,
+        request: &A::Request,
+        version: i16,
+    ) -> Result<A::Response, Error>
+    where
+        A: VersionedApi,
+        A::Request: Encode,
+        A::Response: Decode,
+    {
+        let _permit = self
+            .shared
+            .in_flight
+            .acquire()
+            .await
+            .map_err(|_| Error::Closed)?;
+
+        let correlation_id = self
+            .shared
+            .next_correlation_id
+            .fetch_add(1, Ordering::Relaxed);
+        let header = headers::Request {
+            api_key: A::KEY,
+            api_version: version,
+            correlation_id,
+            client_id: self.shared.config.client_id.clone(),
+            tagged_fields: Default::default(),
+        };
+        let frame = codec::encode_request::<A>(&header, request)?;
+
+        // The reply is registered before the frame is queued, so that the reader always finds
+        // it. Frames are written by their own task, so that a cancelled request never leaves a
+        // partial frame on the socket.
+        let (reply, response) = oneshot::channel();
+        {
+            let mut pending = self.shared.pending();
+            if pending.closed {
+                return Err(Error::Closed);
+            }
+            pending.replies.insert(correlation_id, reply);
+        }
+        if self.frames.send(frame).is_err() {
+            return Err(Error::Closed);
+        }
+
+        let frame = match time::timeout(self.shared.config.request_timeout, response).await {
+            Ok(Ok(frame)) => frame?,
+            Ok(Err(_)) => return Err(Error::Closed),
+            Err(_) => {
+                // Like the Java client, a request which times out closes the connection, since
+                // its response may still arrive and the broker may be unhealthy.
+                self.shared.pending().replies.remove(&correlation_id);
+                self.shared.close(Error::Timeout);
+                return Err(Error::Timeout);
+            }
+        };
+
+        let (_, response) = codec::decode_response::<A>(&frame, version)?;
+        Ok(response)
+    }
+
+    /// Sends a request to which the broker never responds, such as a produce request which
+    /// requires no acknowledgements, with the newest version supported by both sides.
+    ///
+    /// This returns once the request is queued, without taking a slot of
+    /// [max_in_flight](ConnectionConfig::max_in_flight) beyond that.
+    pub async fn send_without_response<A>(&self, request: &A::Request) -> Result<(), Error>
+    where
+        A: VersionedApi,
+        A::Request: Encode,
+    {
+        let version = self.versions.negotiate::<A>()?;
+        let _permit = self
+            .shared
+            .in_flight
+            .acquire()
+            .await
+            .map_err(|_| Error::Closed)?;
+
+        let header = headers::Request {
+            api_key: A::KEY,
+            api_version: version,
+            correlation_id: self
+                .shared
+                .next_correlation_id
+                .fetch_add(1, Ordering::Relaxed),
+            client_id: self.shared.config.client_id.clone(),
+            tagged_fields: Default::default(),
+        };
+        let frame = codec::encode_request::<A>(&header, request)?;
+
+        if self.is_closed() || self.frames.send(frame).is_err() {
+            return Err(Error::Closed);
+        }
+        Ok(())
+    }
+}
+
+impl Shared {
+    fn pending(&self) -> std::sync::MutexGuard<'_, Pending> {
+        self.pending
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+
+    /// Closes the connection, failing every pending request with the given cause.
+    fn close(&self, cause: Error) {
+        let replies = {
+            let mut pending = self.pending();
+            pending.closed = true;
+            std::mem::take(&mut pending.replies)
+        };
+        self.in_flight.close();
+
+        let cause = Arc::new(cause);
+        for (_, reply) in replies {
+            let _ = reply.send(Err(Error::ConnectionFailed(Arc::clone(&cause))));
+        }
+    }
+}
+
+/// Writes queued frames to the socket until every connection handle is dropped or a write fails.
+async fn write_frames(
+    shared: Arc<Shared>,
+    mut writer: OwnedWriteHalf,
+    mut outgoing: mpsc::UnboundedReceiver<Vec<u8>>,
+) {
+    while let Some(frame) = outgoing.recv().await {
+        let written = time::timeout(shared.config.request_timeout, writer.write_all(&frame)).await;
+
+        match written {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => return shared.close(classify(error)),
+            Err(_) => return shared.close(Error::Timeout),
+        }
+    }
+
+    let _ = writer.shutdown().await;
+}
+
+/// Reads response frames from the socket and passes each to the request with its correlation ID,
+/// until the connection fails.
+async fn read_frames(shared: Arc<Shared>, mut reader: OwnedReadHalf) {
+    let cause = loop {
+        let frame = match read_frame(&shared.config, &mut reader).await {
+            Ok(frame) => frame,
+            Err(error) => break error,
+        };
+
+        let correlation_id = match codec::peek_correlation_id(&frame) {
+            Ok(correlation_id) => correlation_id,
+            Err(error) => break error.into(),
+        };
+
+        let (reply, closed) = {
+            let mut pending = shared.pending();
+            (pending.replies.remove(&correlation_id), pending.closed)
+        };
+
+        match (reply, closed) {
+            (Some(reply), _) => {
+                let _ = reply.send(Ok(frame));
+            }
+            (None, true) => return,
+            (None, false) => break Error::UnexpectedCorrelationId(correlation_id),
+        }
+    };
+
+    shared.close(cause);
+}
+
+/// Reads a single response frame, without its size.
+async fn read_frame(
+    config: &ConnectionConfig,
+    reader: &mut OwnedReadHalf,
+) -> Result<Vec<u8>, Error> {
+    let mut size = [0; 4];
+    match read_full(reader, &mut size).await? {
+        0 => return Err(Error::Disconnected),
+        received if received < size.len() => {
+            return Err(Error::TruncatedFrame {
+                expected: size.len(),
+                received,
+            })
+        }
+        _ => {}
+    }
+
+    let size = i32::from_be_bytes(size);
+    let len = usize::try_from(size).map_err(|_| Error::InvalidResponseSize(size))?;
+    if len > config.max_response_size {
+        return Err(Error::ResponseTooLarge(len));
+    }
+
+    let mut frame = vec![0; len];
+    let received = read_full(reader, &mut frame).await?;
+    match received == len {
+        true => Ok(frame),
+        false => Err(Error::TruncatedFrame {
+            expected: len,
+            received,
+        }),
+    }
+}
+
+/// Fills the buffer from the socket, and returns how many bytes were read before the broker
+/// closed the connection.
+async fn read_full(reader: &mut OwnedReadHalf, buf: &mut [u8]) -> Result<usize, Error> {
+    let mut received = 0;
+
+    while received < buf.len() {
+        match reader.read(&mut buf[received..]).await.map_err(classify)? {
+            0 => break,
+            read => received += read,
+        }
+    }
+
+    Ok(received)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::{net::TcpListener, task::JoinHandle};
+
+    use super::*;
+
+    /// Connects to a local listener, and returns the connection with the broker's end of it.
+    async fn connect(max_in_flight: usize) -> (PipelinedConnection, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let stream = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        let (broker, _) = listener.accept().await.unwrap();
+
+        let config = ConnectionConfig {
+            max_in_flight,
+            ..ConnectionConfig::default()
+        };
+        (PipelinedConnection::spawn(stream, config).unwrap(), broker)
+    }
+
+    /// Sends an ApiVersions request, and returns the max version of the one API in its response.
+    fn send(connection: &PipelinedConnection) -> JoinHandle<Result<i16, Error>> {
+        let connection = connection.clone();
+
+        tokio::spawn(async move {
+            let request = connection.shared.config.api_versions_request();
+            let response = connection
+                .send_versioned::<ApiVersionsApi>(&request, 0)
+                .await?;
+            Ok(response.api_keys[0].max_version)
+        })
+    }
+
+    /// Waits until the given number of requests are in flight.
+    async fn until_in_flight(connection: &PipelinedConnection, in_flight: usize) {
+        while connection.in_flight() != in_flight {
+            time::sleep(Duration::from_millis(1)).await;
+        }
+    }
+
+    /// Reads a request frame, and returns its correlation ID.
+    async fn read_request(broker: &mut TcpStream) -> i32 {
+        let size = broker.read_i32().await.unwrap();
+        let mut body = vec![0; size as usize];
+        broker.read_exact(&mut body).await.unwrap();
+        i32::from_be_bytes(body[4..8].try_into().unwrap())
+    }
+
+    /// Writes a version 0 ApiVersions response, whose one API has the correlation ID as its max
+    /// version.
+    async fn write_response(broker: &mut TcpStream, correlation_id: i32) {
+        let mut frame = 16_i32.to_be_bytes().to_vec();
+        frame.extend_from_slice(&correlation_id.to_be_bytes());
+        frame.extend_from_slice(&0_i16.to_be_bytes());
+        frame.extend_from_slice(&1_i32.to_be_bytes());
+        frame.extend_from_slice(&18_i16.to_be_bytes());
+        frame.extend_from_slice(&0_i16.to_be_bytes());
+        frame.extend_from_slice(&(correlation_id as i16).to_be_bytes());
+        broker.write_all(&frame).await.unwrap();
+    }
+
+    fn failed_with(result: Result<i16, Error>, cause: impl Fn(&Error) -> bool) -> bool {
+        matches!(result, Err(Error::ConnectionFailed(error)) if cause(&error))
+    }
+
+    #[tokio::test]
+    async fn responses_are_matched_by_correlation_id() {
+        let (connection, mut broker) = connect(5).await;
+
+        // Each request is in flight before the next is sent, so request i has correlation ID i.
+        let mut requests = vec![];
+        for sent in 1..=3 {
+            requests.push(send(&connection));
+            until_in_flight(&connection, sent).await;
+        }
+
+        let mut correlation_ids = vec![];
+        for _ in 0..3 {
+            correlation_ids.push(read_request(&mut broker).await);
+        }
+        assert_eq!(correlation_ids, [0, 1, 2]);
+
+        for correlation_id in correlation_ids.into_iter().rev() {
+            write_response(&mut broker, correlation_id).await;
+        }
+        for (correlation_id, request) in requests.into_iter().enumerate() {
+            assert_eq!(request.await.unwrap().unwrap(), correlation_id as i16);
+        }
+        assert_eq!(connection.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn max_in_flight_blocks_the_next_send() {
+        let (connection, mut broker) = connect(1).await;
+
+        let first = send(&connection);
+        until_in_flight(&connection, 1).await;
+        let second = send(&connection);
+
+        assert_eq!(read_request(&mut broker).await, 0);
+        let mut next = [0; 4];
+        let blocked = time::timeout(Duration::from_millis(50), broker.read_exact(&mut next));
+        assert!(blocked.await.is_err());
+        assert_eq!(connection.in_flight(), 1);
+
+        write_response(&mut broker, 0).await;
+        assert_eq!(first.await.unwrap().unwrap(), 0);
+
+        // The size of the second request was already read above.
+        let size = i32::from_be_bytes(next);
+        let mut body = vec![0; size as usize];
+        broker.read_exact(&mut body).await.unwrap();
+        write_response(&mut broker, 1).await;
+        assert_eq!(second.await.unwrap().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn eof_fails_every_pending_request() {
+        let (connection, mut broker) = connect(5).await;
+
+        let requests = [send(&connection), send(&connection)];
+        until_in_flight(&connection, 2).await;
+        read_request(&mut broker).await;
+        read_request(&mut broker).await;
+        drop(broker);
+
+        for request in requests {
+            let result = request.await.unwrap();
+            assert!(failed_with(result, |error| matches!(
+                error,
+                Error::Disconnected
+            )));
+        }
+        assert!(connection.is_closed());
+        assert_eq!(connection.in_flight(), 0);
+        assert!(matches!(
+            send(&connection).await.unwrap(),
+            Err(Error::Closed)
+        ));
+    }
+
+    #[tokio::test]
+    async fn disconnect_mid_frame_fails_every_pending_request() {
+        let (connection, mut broker) = connect(5).await;
+
+        let requests = [send(&connection), send(&connection)];
+        until_in_flight(&connection, 2).await;
+        read_request(&mut broker).await;
+        read_request(&mut broker).await;
+        broker.write_all(&10_i32.to_be_bytes()).await.unwrap();
+        broker.write_all(&[0, 0, 0]).await.unwrap();
+        drop(broker);
+
+        for request in requests {
+            let result = request.await.unwrap();
+            assert!(failed_with(result, |error| matches!(
+                error,
+                Error::TruncatedFrame {
+                    expected: 10,
+                    received: 3
+                }
+            )));
+        }
+        assert!(connection.is_closed());
+    }
+
+    #[tokio::test]
+    async fn unexpected_correlation_id_fails_every_pending_request() {
+        let (connection, mut broker) = connect(5).await;
+
+        let request = send(&connection);
+        until_in_flight(&connection, 1).await;
+        read_request(&mut broker).await;
+        write_response(&mut broker, 7).await;
+
+        let result = request.await.unwrap();
+        assert!(failed_with(result, |error| matches!(
+            error,
+            Error::UnexpectedCorrelationId(7)
+        )));
+        assert!(connection.is_closed());
+    }
+}
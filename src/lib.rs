@@ -1 +1,6 @@
 //! A client library for [Apache Kafka](https://kafka.apache.org/).
+
+pub mod cluster;
+pub mod connection;
+pub mod pool;
+pub mod producer;
@@ -0,0 +1,468 @@
+//! Includes a pool of connections to the brokers in a cluster, keyed by broker ID.
+//!
+//! Connections are opened the first time a broker is needed, using the address the [Cluster]
+//! last reported for it, so that a broker which moves to a new host or IP address is found again
+//! after the next metadata refresh. Each connection negotiates API versions and authenticates
+//! once, when it is opened.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use prague_protocol::{
+    api::prelude::*,
+    codec::{Decode, Encode},
+    types::prelude::*,
+};
+use rand::Rng;
+use tokio::{
+    task::JoinHandle,
+    time::{self, Instant},
+};
+
+use crate::{
+    cluster::{self, Cluster},
+    connection::{self, pipelined::PipelinedConnection, ConnectionConfig},
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Broker `{0:?}` is not part of the cluster metadata")]
+    UnknownBroker(BrokerId),
+
+    #[error(transparent)]
+    Cluster(#[from] cluster::Error),
+
+    #[error(transparent)]
+    Connection(#[from] connection::Error),
+}
+
+/// Configures a [ConnectionPool].
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    /// Configures each connection in the pool.
+    pub connection: ConnectionConfig,
+
+    /// How long to wait before reconnecting to a broker after the first failed attempt.
+    pub reconnect_backoff: Duration,
+
+    /// The longest to wait before reconnecting to a broker, however many attempts failed.
+    pub reconnect_backoff_max: Duration,
+
+    /// How long a connection may go unused before it is closed.
+    pub connections_max_idle: Duration,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            connection: ConnectionConfig::default(),
+            reconnect_backoff: Duration::from_millis(50),
+            reconnect_backoff_max: Duration::from_secs(1),
+            connections_max_idle: Duration::from_secs(9 * 60),
+        }
+    }
+}
+
+impl PoolConfig {
+    /// The random factor applied to each reconnect backoff, so that clients which lost a broker
+    /// at the same time do not reconnect at the same time.
+    const RECONNECT_JITTER: f64 = 0.2;
+
+    /// Returns how long to wait before reconnecting after the given number of failed attempts.
+    fn reconnect_backoff(&self, failures: u32) -> Duration {
+        let exponent = failures.saturating_sub(1).min(31) as i32;
+        let backoff = self
+            .reconnect_backoff
+            .mul_f64(2f64.powi(exponent))
+            .min(self.reconnect_backoff_max);
+        let jitter = rand::thread_rng()
+            .gen_range(1.0 - Self::RECONNECT_JITTER..=1.0 + Self::RECONNECT_JITTER);
+
+        backoff.mul_f64(jitter)
+    }
+}
+
+/// A pool with at most one connection per broker.
+///
+/// Connections which fail are dropped from the pool, and the broker is reconnected to on demand
+/// after an exponential backoff. A failed connection attempt also invalidates the cluster
+/// metadata, in case the broker has moved.
+#[derive(Debug)]
+pub struct ConnectionPool {
+    cluster: Arc<Cluster>,
+    config: PoolConfig,
+    brokers: Mutex<HashMap<BrokerId, Arc<Slot>>>,
+}
+
+/// The connection to a single broker, and the state of reconnecting to it.
+#[derive(Debug, Default)]
+struct Slot {
+    connecting: tokio::sync::Mutex<()>,
+    state: Mutex<SlotState>,
+}
+
+#[derive(Debug, Default)]
+struct SlotState {
+    connection: Option<PipelinedConnection>,
+    last_used: Option<Instant>,
+    failures: u32,
+    reconnect_at: Option<Instant>,
+}
+
+impl Slot {
+    fn state(&self) -> MutexGuard<'_, SlotState> {
+        self.state.lock().unwrap_or_else(|error| error.into_inner())
+    }
+
+    /// Returns the open connection, if any, and marks it as used.
+    fn connection(&self) -> Option<PipelinedConnection> {
+        let mut state = self.state();
+        state.connection = state.connection.take().filter(|c| !c.is_closed());
+
+        let connection = state.connection.clone()?;
+        state.last_used = Some(Instant::now());
+        Some(connection)
+    }
+}
+
+impl ConnectionPool {
+    /// Creates an empty pool for the brokers of the given cluster.
+    pub fn new(cluster: Arc<Cluster>, config: PoolConfig) -> Self {
+        Self {
+            cluster,
+            config,
+            brokers: Mutex::default(),
+        }
+    }
+
+    /// Returns the cluster whose brokers this pool connects to.
+    pub fn cluster(&self) -> &Arc<Cluster> {
+        &self.cluster
+    }
+
+    /// Returns the connection to the given broker, opening it if needed.
+    ///
+    /// If previous attempts to connect to the broker failed, this first waits out the reconnect
+    /// backoff.
+    pub async fn get(&self, broker: BrokerId) -> Result<PipelinedConnection, Error> {
+        let slot = self.slot(broker);
+        if let Some(connection) = slot.connection() {
+            return Ok(connection);
+        }
+
+        // Only one task connects to each broker at a time. The others wait, and then use the
+        // connection it opened.
+        let _connecting = slot.connecting.lock().await;
+        if let Some(connection) = slot.connection() {
+            return Ok(connection);
+        }
+
+        let reconnect_at = slot.state().reconnect_at;
+        if let Some(reconnect_at) = reconnect_at {
+            time::sleep_until(reconnect_at).await;
+        }
+
+        match self.connect(broker).await {
+            Ok(connection) => {
+                let mut state = slot.state();
+                state.connection = Some(connection.clone());
+                state.last_used = Some(Instant::now());
+                state.failures = 0;
+                state.reconnect_at = None;
+                Ok(connection)
+            }
+            Err(error) => {
+                let mut state = slot.state();
+                state.failures += 1;
+                state.reconnect_at =
+                    Some(Instant::now() + self.config.reconnect_backoff(state.failures));
+                self.cluster.invalidate();
+                Err(error)
+            }
+        }
+    }
+
+    /// Sends a request to the given broker, opening a connection to it if needed.
+    pub async fn send<A>(
+        &self,
+        broker: BrokerId,
+        request: &A::Request,
+    ) -> Result<A::Response, Error>
+    where
+        A: VersionedApi,
+        A::Request: Encode,
+        A::Response: Decode,
+    {
+        let connection = self.get(broker).await?;
+        Ok(connection.send::<A>(request).await?)
+    }
+
+    /// Sends a request which the broker does not respond to, such as a produce request without
+    /// acknowledgements, to the given broker, opening a connection to it if needed.
+    pub async fn send_without_response<A>(
+        &self,
+        broker: BrokerId,
+        request: &A::Request,
+    ) -> Result<(), Error>
+    where
+        A: VersionedApi,
+        A::Request: Encode,
+    {
+        let connection = self.get(broker).await?;
+        Ok(connection.send_without_response::<A>(request).await?)
+    }
+
+    /// Closes the connection to the given broker, if any. Requests already in flight on it still
+    /// complete.
+    pub fn close(&self, broker: BrokerId) {
+        if let Some(slot) = self.brokers().get(&broker) {
+            slot.state().connection = None;
+        }
+    }
+
+    /// Closes every connection which has been unused for longer than
+    /// [connections_max_idle](PoolConfig::connections_max_idle), and returns when the next one
+    /// may become idle.
+    pub fn close_idle(&self) -> Instant {
+        let now = Instant::now();
+        let max_idle = self.config.connections_max_idle;
+        let mut next_check = now + max_idle;
+
+        for slot in self.brokers().values() {
+            let mut state = slot.state();
+            let (Some(last_used), Some(_)) = (state.last_used, &state.connection) else {
+                continue;
+            };
+
+            match last_used + max_idle {
+                idle_at if idle_at <= now => state.connection = None,
+                idle_at => next_check = next_check.min(idle_at),
+            }
+        }
+
+        next_check
+    }
+
+    /// Spawns a task which closes idle connections, until the pool is dropped.
+    pub fn spawn_idle_reaper(self: &Arc<Self>) -> JoinHandle<()> {
+        let pool = Arc::downgrade(self);
+
+        tokio::spawn(async move {
+            loop {
+                let Some(next_check) = pool.upgrade().map(|pool| pool.close_idle()) else {
+                    return;
+                };
+
+                time::sleep_until(next_check).await;
+            }
+        })
+    }
+
+    fn brokers(&self) -> MutexGuard<'_, HashMap<BrokerId, Arc<Slot>>> {
+        self.brokers
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+
+    fn slot(&self, broker: BrokerId) -> Arc<Slot> {
+        Arc::clone(self.brokers().entry(broker).or_default())
+    }
+
+    /// Opens a connection to the broker's latest address, refreshing the metadata once if the
+    /// broker is not known. The address is resolved anew for every attempt.
+    async fn connect(&self, broker: BrokerId) -> Result<PipelinedConnection, Error> {
+        let address = match self.address(broker) {
+            Some(address) => address,
+            None => {
+                self.cluster.refresh().await?;
+                self.address(broker).ok_or(Error::UnknownBroker(broker))?
+            }
+        };
+
+        Ok(PipelinedConnection::connect(address, self.config.connection.clone()).await?)
+    }
+
+    fn address(&self, broker: BrokerId) -> Option<String> {
+        let metadata = self.cluster.metadata();
+        let broker = metadata.broker(broker)?;
+        Some(format!("{}:{}", broker.hostname, broker.port))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    use prague_protocol::metadata::response::BrokerMetadata;
+
+    use super::*;
+    use crate::cluster::{ClusterConfig, Metadata};
+
+    /// A local broker which serves one connection at a time, and only answers ApiVersions.
+    struct Broker {
+        port: u16,
+        accepted: Arc<AtomicUsize>,
+        task: JoinHandle<()>,
+    }
+
+    impl Broker {
+        async fn start() -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = listener.local_addr().unwrap().port();
+            let accepted = Arc::new(AtomicUsize::new(0));
+
+            let task = tokio::spawn({
+                let accepted = Arc::clone(&accepted);
+                async move {
+                    while let Ok((mut stream, _)) = listener.accept().await {
+                        accepted.fetch_add(1, Ordering::Relaxed);
+                        while Self::respond(&mut stream).await.is_ok() {}
+                    }
+                }
+            });
+
+            Self {
+                port,
+                accepted,
+                task,
+            }
+        }
+
+        /// Answers an ApiVersions request. Versions after 0 are rejected, so that the client
+        /// retries with version 0, whose response supports only ApiVersions 0.
+        async fn respond(stream: &mut TcpStream) -> std::io::Result<()> {
+            let size = stream.read_i32().await?;
+            let mut request = vec![0; size as usize];
+            stream.read_exact(&mut request).await?;
+
+            let version = i16::from_be_bytes([request[2], request[3]]);
+            let error_code: i16 = match version {
+                0 => 0,
+                _ => 35,
+            };
+
+            let mut frame = 16_i32.to_be_bytes().to_vec();
+            frame.extend_from_slice(&request[4..8]);
+            frame.extend_from_slice(&error_code.to_be_bytes());
+            frame.extend_from_slice(&1_i32.to_be_bytes());
+            frame.extend_from_slice(&[0, 18, 0, 0, 0, 0]);
+            stream.write_all(&frame).await
+        }
+
+        fn metadata(&self, id: i32) -> BrokerMetadata {
+            BrokerMetadata {
+                id: BrokerId(id),
+                hostname: "127.0.0.1".to_owned(),
+                port: i32::from(self.port),
+                rack: None,
+                tagged_fields: Default::default(),
+            }
+        }
+
+        fn accepted(&self) -> usize {
+            self.accepted.load(Ordering::Relaxed)
+        }
+    }
+
+    fn metadata(brokers: impl IntoIterator<Item = BrokerMetadata>) -> Metadata {
+        let mut metadata = Metadata::default();
+        metadata
+            .brokers
+            .extend(brokers.into_iter().map(|broker| (broker.id, broker)));
+        metadata
+    }
+
+    fn pool(metadata: Metadata, config: PoolConfig) -> ConnectionPool {
+        let cluster = Cluster::new(ClusterConfig::new(["127.0.0.1:1"]));
+        cluster.set_metadata(metadata);
+        ConnectionPool::new(Arc::new(cluster), config)
+    }
+
+    fn is_open(pool: &ConnectionPool, broker: i32) -> bool {
+        pool.brokers()[&BrokerId(broker)]
+            .state()
+            .connection
+            .is_some()
+    }
+
+    #[test]
+    fn reconnect_backoff_doubles_up_to_max_with_jitter() {
+        let config = PoolConfig::default();
+
+        for failures in 0..=40 {
+            let exponent = failures.max(1) - 1;
+            let expected = match exponent < 10 {
+                true => (config.reconnect_backoff * 2u32.pow(exponent))
+                    .min(config.reconnect_backoff_max),
+                false => config.reconnect_backoff_max,
+            };
+
+            for _ in 0..100 {
+                let backoff = config.reconnect_backoff(failures);
+                assert!(backoff >= expected.mul_f64(0.8), "{failures}: {backoff:?}");
+                assert!(backoff <= expected.mul_f64(1.2), "{failures}: {backoff:?}");
+            }
+        }
+
+        let backoff = config.reconnect_backoff(u32::MAX);
+        assert!(backoff <= config.reconnect_backoff_max.mul_f64(1.2));
+    }
+
+    #[tokio::test]
+    async fn close_idle_closes_only_idle_connections() {
+        let (first, second) = (Broker::start().await, Broker::start().await);
+        let pool = pool(
+            metadata([first.metadata(1), second.metadata(2)]),
+            PoolConfig {
+                connections_max_idle: Duration::from_millis(200),
+                ..PoolConfig::default()
+            },
+        );
+
+        pool.get(BrokerId(1)).await.unwrap();
+        time::sleep(Duration::from_millis(120)).await;
+        pool.get(BrokerId(2)).await.unwrap();
+        time::sleep(Duration::from_millis(120)).await;
+
+        let next_check = pool.close_idle();
+        assert!(!is_open(&pool, 1));
+        assert!(is_open(&pool, 2));
+
+        let last_used = pool.brokers()[&BrokerId(2)].state().last_used.unwrap();
+        assert_eq!(next_check, last_used + Duration::from_millis(200));
+
+        // A closed connection is opened again when it is next needed.
+        pool.get(BrokerId(1)).await.unwrap();
+        assert!(is_open(&pool, 1));
+        assert_eq!(first.accepted(), 2);
+        assert_eq!(second.accepted(), 1);
+    }
+
+    #[tokio::test]
+    async fn get_reconnects_to_the_latest_address() {
+        let (old, new) = (Broker::start().await, Broker::start().await);
+        let pool = pool(metadata([old.metadata(1)]), PoolConfig::default());
+
+        let connection = pool.get(BrokerId(1)).await.unwrap();
+        assert_eq!(old.accepted(), 1);
+
+        // The broker moves, and its old address stops accepting connections.
+        pool.cluster().set_metadata(metadata([new.metadata(1)]));
+        old.task.abort();
+        while !connection.is_closed() {
+            time::sleep(Duration::from_millis(1)).await;
+        }
+
+        let connection = pool.get(BrokerId(1)).await.unwrap();
+        assert!(!connection.is_closed());
+        assert_eq!(new.accepted(), 1);
+    }
+}
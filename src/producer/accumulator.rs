@@ -0,0 +1,589 @@
+//! Includes the record accumulator, which collects records into batches per partition until the
+//! sender takes them.
+
+use std::collections::{HashMap, VecDeque};
+
+use prague_protocol::{records::*, types::prelude::*};
+use tokio::{sync::oneshot, time::Instant};
+
+use super::{idempotence::ProducerIdAndEpoch, Error, RecordMetadata};
+use crate::cluster::Metadata;
+
+/// Identifies a partition by topic name and index.
+pub(crate) type TopicPartition = (String, PartitionIndex);
+
+/// Resolves the [DeliveryFuture](super::DeliveryFuture) of a record.
+pub(crate) type Delivery = oneshot::Sender<Result<RecordMetadata, Error>>;
+
+/// A batch of records for a single partition, and the deliveries waiting on it.
+#[derive(Debug)]
+pub(crate) struct ProducerBatch {
+    /// Orders the batches of a partition by when they were created.
+    pub id: u64,
+
+    pub topic: String,
+    pub partition: PartitionIndex,
+    pub records: Vec<Record>,
+    pub deliveries: Vec<Delivery>,
+    pub base_timestamp: Timestamp,
+    pub max_timestamp: Timestamp,
+
+    /// The size in bytes of the batch once encoded, before compression.
+    pub size: usize,
+
+    pub created: Instant,
+
+    /// How many times the batch was sent and failed with a retriable error.
+    pub attempts: u32,
+
+    /// When the batch may be sent again after a failed attempt.
+    pub retry_at: Option<Instant>,
+
+    /// The producer ID and epoch the batch was first sent with, if the producer is idempotent.
+    pub producer: Option<ProducerIdAndEpoch>,
+
+    /// The sequence of the first record, if the producer is idempotent. It is kept when the batch
+    /// is retried with the same producer ID and epoch.
+    pub base_sequence: Option<i32>,
+}
+
+impl ProducerBatch {
+    fn new(id: u64, topic: String, partition: PartitionIndex, created: Instant) -> Self {
+        Self {
+            id,
+            topic,
+            partition,
+            records: Vec::new(),
+            deliveries: Vec::new(),
+            base_timestamp: Timestamp(0),
+            max_timestamp: Timestamp(0),
+            size: Records::BATCH_OVERHEAD,
+            created,
+            attempts: 0,
+            retry_at: None,
+            producer: None,
+            base_sequence: None,
+        }
+    }
+
+    /// Appends a record if it fits within `batch_size`, or if the batch is empty. Otherwise, the
+    /// record and its delivery are handed back.
+    fn try_append(
+        &mut self,
+        mut record: Record,
+        timestamp: Timestamp,
+        delivery: Delivery,
+        batch_size: usize,
+    ) -> Result<(), (Record, Delivery)> {
+        if self.records.is_empty() {
+            self.base_timestamp = timestamp;
+            self.max_timestamp = timestamp;
+        }
+
+        record.offset_delta = VarInt(self.records.len() as i32);
+        record.timestamp_delta = VarLong(timestamp.0 - self.base_timestamp.0);
+
+        let size = record.encoded_len();
+        if !self.records.is_empty() && self.size + size > batch_size {
+            return Err((record, delivery));
+        }
+
+        self.size += size;
+        self.max_timestamp = self.max_timestamp.max(timestamp);
+        self.records.push(record);
+        self.deliveries.push(delivery);
+        Ok(())
+    }
+
+    /// Returns whether another record is unlikely to fit in this batch.
+    pub fn is_full(&self, batch_size: usize) -> bool {
+        self.size >= batch_size
+    }
+
+    /// Returns the timestamp of the record at the given index.
+    pub fn timestamp(&self, index: usize) -> Timestamp {
+        Timestamp(self.base_timestamp.0 + self.records[index].timestamp_delta.0)
+    }
+
+    /// Returns the record batch to send for this batch, as part of a transaction if
+    /// `is_transactional` is set.
+    pub fn to_records(
+        &self,
+        compression: RecordBatchCompression,
+        is_transactional: bool,
+    ) -> Records {
+        Records {
+            base_offset: Offset(0),
+            batch_length: 0,
+            partition_leader_epoch: Epoch(-1),
+            magic_number: Records::MAGIC_NUMBER,
+            crc: CyclicRedundancyCheck(0),
+            attributes: RecordBatchAttributes {
+                compression,
+                timestamp_type: false,
+                is_transactional,
+                is_control_batch: false,
+                has_delete_horizon_ms: false,
+            },
+            last_offset_delta: Duration(self.records.len() as i32 - 1),
+            base_timestamp: self.base_timestamp,
+            max_timestamp: self.max_timestamp,
+            producer_id: self.producer.map_or(ProducerId(-1), |producer| producer.id),
+            producer_epoch: self.producer.map_or(-1, |producer| producer.epoch),
+            base_sequence: self.base_sequence.unwrap_or(-1),
+            records: self.records.clone(),
+        }
+    }
+}
+
+/// The partitions which have batches ready to send, grouped by the broker which leads them.
+#[derive(Debug)]
+pub(crate) struct Ready {
+    pub brokers: HashMap<BrokerId, Vec<TopicPartition>>,
+
+    /// When a batch which is not ready yet will become ready, if any.
+    pub next_check: Option<Instant>,
+
+    /// Whether some partition with a batch has no known leader.
+    pub unknown_leader: bool,
+}
+
+/// Collects records into a queue of batches per partition.
+#[derive(Debug)]
+pub(crate) struct Accumulator {
+    batch_size: usize,
+    linger: std::time::Duration,
+    batches: HashMap<TopicPartition, VecDeque<ProducerBatch>>,
+    next_id: u64,
+}
+
+impl Accumulator {
+    pub fn new(batch_size: usize, linger: std::time::Duration) -> Self {
+        Self {
+            batch_size,
+            linger,
+            batches: HashMap::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Appends a record to the last batch of its partition, starting a new batch if it does not
+    /// fit. Returns whether the sender should be woken, which is when a batch was started or
+    /// filled.
+    pub fn append(
+        &mut self,
+        topic: &str,
+        partition: PartitionIndex,
+        record: Record,
+        timestamp: Timestamp,
+        delivery: Delivery,
+        now: Instant,
+    ) -> bool {
+        let queue = self
+            .batches
+            .entry((topic.to_owned(), partition))
+            .or_default();
+
+        // Batches which were sent before are never appended to, so that a retry sends the same
+        // records again.
+        let (record, delivery) = match queue.back_mut().filter(|batch| batch.attempts == 0) {
+            Some(batch) => match batch.try_append(record, timestamp, delivery, self.batch_size) {
+                Ok(()) => return batch.is_full(self.batch_size),
+                Err(rejected) => rejected,
+            },
+            None => (record, delivery),
+        };
+
+        let mut batch = ProducerBatch::new(self.next_id, topic.to_owned(), partition, now);
+        self.next_id += 1;
+        if batch
+            .try_append(record, timestamp, delivery, self.batch_size)
+            .is_err()
+        {
+            unreachable!("an empty batch accepts any record");
+        }
+
+        queue.push_back(batch);
+        true
+    }
+
+    /// Returns the partitions whose first batch is ready to send: because it is full, its linger
+    /// elapsed, or `flush` is set. Batches waiting out a retry backoff are never ready.
+    pub fn ready(&self, metadata: &Metadata, now: Instant, flush: bool) -> Ready {
+        let mut brokers: HashMap<BrokerId, Vec<TopicPartition>> = HashMap::new();
+        let mut unknown_leader = false;
+        let mut next_check: Option<Instant> = None;
+        let mut wake_at = |at: Instant| {
+            next_check = Some(next_check.map_or(at, |next| next.min(at)));
+        };
+
+        for ((topic, partition), queue) in &self.batches {
+            let Some(batch) = queue.front() else {
+                continue;
+            };
+
+            if let Some(retry_at) = batch.retry_at.filter(|&at| at > now) {
+                wake_at(retry_at);
+                continue;
+            }
+
+            let Some(leader) = metadata
+                .partition(topic, *partition)
+                .and_then(|partition| partition.leader)
+            else {
+                unknown_leader = true;
+                continue;
+            };
+
+            let linger_at = batch.created + self.linger;
+            let full = queue.len() > 1 || batch.is_full(self.batch_size);
+            if full || flush || batch.attempts > 0 || linger_at <= now {
+                brokers
+                    .entry(leader)
+                    .or_default()
+                    .push((topic.clone(), *partition));
+            } else {
+                wake_at(linger_at);
+            }
+        }
+
+        Ready {
+            brokers,
+            next_check,
+            unknown_leader,
+        }
+    }
+
+    /// Takes the first batch of each given partition, for as long as they fit in a request of
+    /// `max_request_size` bytes. At least one batch is taken, if any is left.
+    ///
+    /// Each batch is passed to `prepare` before it is taken, and left in place if that returns
+    /// false.
+    pub fn drain(
+        &mut self,
+        partitions: &[TopicPartition],
+        max_request_size: usize,
+        mut prepare: impl FnMut(&mut ProducerBatch) -> bool,
+    ) -> Vec<ProducerBatch> {
+        let mut drained = Vec::new();
+        let mut size = 0;
+
+        for key in partitions {
+            let Some(queue) = self.batches.get_mut(key) else {
+                continue;
+            };
+
+            match queue.front_mut() {
+                Some(batch) if drained.is_empty() || size + batch.size <= max_request_size => {
+                    if !prepare(batch) {
+                        continue;
+                    }
+                    size += batch.size;
+                }
+                Some(_) => break,
+                None => continue,
+            }
+
+            drained.extend(queue.pop_front());
+            if queue.is_empty() {
+                self.batches.remove(key);
+            }
+        }
+
+        drained
+    }
+
+    /// Puts a batch which failed back in its partition's queue, ahead of every batch created
+    /// after it, so that it is sent before them.
+    pub fn reenqueue(&mut self, batch: ProducerBatch) {
+        let queue = self
+            .batches
+            .entry((batch.topic.clone(), batch.partition))
+            .or_default();
+
+        let index = queue
+            .iter()
+            .position(|queued| queued.id > batch.id)
+            .unwrap_or(queue.len());
+        queue.insert(index, batch);
+    }
+
+    /// Removes and returns every batch.
+    pub fn take_all(&mut self) -> Vec<ProducerBatch> {
+        self.batches.drain().flat_map(|(_, queue)| queue).collect()
+    }
+
+    /// Returns whether there are no batches.
+    pub fn is_empty(&self) -> bool {
+        self.batches.is_empty()
+    }
+
+    /// Removes and returns every batch created longer ago than `delivery_timeout`.
+    pub fn expire(
+        &mut self,
+        now: Instant,
+        delivery_timeout: std::time::Duration,
+    ) -> Vec<ProducerBatch> {
+        let mut expired = Vec::new();
+
+        self.batches.retain(|_, queue| {
+            while queue
+                .front()
+                .is_some_and(|batch| batch.created + delivery_timeout <= now)
+            {
+                expired.extend(queue.pop_front());
+            }
+            !queue.is_empty()
+        });
+
+        expired
+    }
+
+    /// Returns when the oldest batch expires, if there is any batch.
+    pub fn next_expiry(&self, delivery_timeout: std::time::Duration) -> Option<Instant> {
+        self.batches
+            .values()
+            .filter_map(|queue| queue.front())
+            .map(|batch| batch.created + delivery_timeout)
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, time::Duration};
+
+    use super::*;
+    use crate::cluster::{PartitionInfo, TopicInfo};
+
+    const LINGER: Duration = Duration::from_millis(10);
+
+    fn record() -> Record {
+        Record {
+            length: VarInt(0),
+            attributes: 0,
+            timestamp_delta: VarLong(0),
+            offset_delta: VarInt(0),
+            key: None,
+            value: Some(vec![0; 100]),
+            headers: vec![],
+        }
+    }
+
+    /// Returns a batch size which fits `count` records.
+    fn fits(count: usize) -> usize {
+        Records::BATCH_OVERHEAD + count * record().encoded_len()
+    }
+
+    fn append(accumulator: &mut Accumulator, partition: i32, now: Instant) -> bool {
+        let (delivery, _) = oneshot::channel();
+        accumulator.append(
+            "topic",
+            PartitionIndex(partition),
+            record(),
+            Timestamp(0),
+            delivery,
+            now,
+        )
+    }
+
+    fn key(partition: i32) -> TopicPartition {
+        ("topic".to_owned(), PartitionIndex(partition))
+    }
+
+    fn queue(accumulator: &Accumulator, partition: i32) -> Vec<(u64, usize)> {
+        accumulator.batches[&key(partition)]
+            .iter()
+            .map(|batch| (batch.id, batch.records.len()))
+            .collect()
+    }
+
+    /// Returns metadata in which broker 1 leads partitions 0 and 1, and partition 2 has no
+    /// leader.
+    fn metadata() -> Metadata {
+        let partitions = [(0, Some(BrokerId(1))), (1, Some(BrokerId(1))), (2, None)]
+            .into_iter()
+            .map(|(index, leader)| {
+                let partition = PartitionInfo {
+                    index: PartitionIndex(index),
+                    leader,
+                    leader_epoch: None,
+                    replicas: vec![],
+                    isr: vec![],
+                    offline_replicas: vec![],
+                    error_code: None,
+                };
+                (PartitionIndex(index), partition)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut metadata = Metadata::default();
+        metadata.topics.insert(
+            "topic".to_owned(),
+            TopicInfo {
+                name: "topic".to_owned(),
+                id: None,
+                is_internal: false,
+                error_code: None,
+                partitions,
+            },
+        );
+        metadata
+    }
+
+    fn ready_partitions(ready: &Ready) -> Vec<TopicPartition> {
+        let mut partitions = ready.brokers.get(&BrokerId(1)).cloned().unwrap_or_default();
+        partitions.sort();
+        partitions
+    }
+
+    #[test]
+    fn append_fills_and_rolls_batches() {
+        let now = Instant::now();
+        let mut accumulator = Accumulator::new(fits(2) + 1, LINGER);
+
+        assert!(append(&mut accumulator, 0, now));
+        assert!(!append(&mut accumulator, 0, now));
+        assert!(append(&mut accumulator, 0, now));
+        assert!(append(&mut accumulator, 1, now));
+        assert_eq!(queue(&accumulator, 0), [(0, 2), (1, 1)]);
+        assert_eq!(queue(&accumulator, 1), [(2, 1)]);
+
+        // A record larger than the batch size still gets a batch of its own.
+        let mut accumulator = Accumulator::new(1, LINGER);
+        assert!(append(&mut accumulator, 0, now));
+        assert!(append(&mut accumulator, 0, now));
+        assert_eq!(queue(&accumulator, 0), [(0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn append_skips_batches_sent_before() {
+        let now = Instant::now();
+        let mut accumulator = Accumulator::new(fits(2) + 1, LINGER);
+        append(&mut accumulator, 0, now);
+
+        let mut batch = accumulator.drain(&[key(0)], usize::MAX, |_| true).remove(0);
+        batch.attempts = 1;
+        accumulator.reenqueue(batch);
+
+        assert!(append(&mut accumulator, 0, now));
+        assert_eq!(queue(&accumulator, 0), [(0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn ready_after_linger() {
+        let now = Instant::now();
+        let metadata = metadata();
+        let mut accumulator = Accumulator::new(fits(2) + 1, LINGER);
+        append(&mut accumulator, 0, now);
+
+        let ready = accumulator.ready(&metadata, now, false);
+        assert!(ready.brokers.is_empty());
+        assert_eq!(ready.next_check, Some(now + LINGER));
+
+        let ready = accumulator.ready(&metadata, now + LINGER, false);
+        assert_eq!(ready_partitions(&ready), [key(0)]);
+        assert_eq!(ready.next_check, None);
+
+        let ready = accumulator.ready(&metadata, now, true);
+        assert_eq!(ready_partitions(&ready), [key(0)]);
+    }
+
+    #[test]
+    fn ready_when_full() {
+        let now = Instant::now();
+        let metadata = metadata();
+        let mut accumulator = Accumulator::new(fits(1), LINGER);
+
+        // The batch is full once it reaches the batch size, or once another batch follows it.
+        append(&mut accumulator, 0, now);
+        assert_eq!(
+            ready_partitions(&accumulator.ready(&metadata, now, false)),
+            [key(0)]
+        );
+
+        let mut accumulator = Accumulator::new(fits(2) + 1, LINGER);
+        append(&mut accumulator, 1, now);
+        append(&mut accumulator, 1, now);
+        assert!(accumulator.ready(&metadata, now, false).brokers.is_empty());
+        append(&mut accumulator, 1, now);
+        assert_eq!(
+            ready_partitions(&accumulator.ready(&metadata, now, false)),
+            [key(1)]
+        );
+    }
+
+    #[test]
+    fn ready_skips_backoff_and_unknown_leaders() {
+        let now = Instant::now();
+        let metadata = metadata();
+        let mut accumulator = Accumulator::new(fits(1), LINGER);
+        append(&mut accumulator, 0, now);
+        append(&mut accumulator, 2, now);
+
+        let mut batch = accumulator.drain(&[key(0)], usize::MAX, |_| true).remove(0);
+        batch.attempts = 1;
+        batch.retry_at = Some(now + Duration::from_millis(100));
+        accumulator.reenqueue(batch);
+
+        let ready = accumulator.ready(&metadata, now, true);
+        assert!(ready.brokers.is_empty());
+        assert!(ready.unknown_leader);
+        assert_eq!(ready.next_check, Some(now + Duration::from_millis(100)));
+
+        let ready = accumulator.ready(&metadata, now + Duration::from_millis(100), false);
+        assert_eq!(ready_partitions(&ready), [key(0)]);
+    }
+
+    #[test]
+    fn drain_limits_request_size() {
+        let now = Instant::now();
+        let mut accumulator = Accumulator::new(fits(1), LINGER);
+        append(&mut accumulator, 0, now);
+        append(&mut accumulator, 0, now);
+        append(&mut accumulator, 1, now);
+
+        // The first batch is taken even if it does not fit.
+        let drained = accumulator.drain(&[key(0), key(1)], 1, |_| true);
+        assert_eq!(drained.len(), 1);
+        assert_eq!(drained[0].id, 0);
+
+        // Only the first batch of each partition is taken.
+        let drained = accumulator.drain(&[key(0), key(1)], 2 * fits(1), |_| true);
+        let ids: Vec<_> = drained.iter().map(|batch| batch.id).collect();
+        assert_eq!(ids, [1, 2]);
+        assert!(accumulator.is_empty());
+    }
+
+    #[test]
+    fn drain_leaves_unprepared_batches() {
+        let now = Instant::now();
+        let mut accumulator = Accumulator::new(fits(1), LINGER);
+        append(&mut accumulator, 0, now);
+        append(&mut accumulator, 1, now);
+
+        let drained = accumulator.drain(&[key(0), key(1)], usize::MAX, |batch| {
+            batch.partition == PartitionIndex(1)
+        });
+        assert_eq!(drained.len(), 1);
+        assert_eq!(drained[0].partition, PartitionIndex(1));
+        assert_eq!(queue(&accumulator, 0), [(0, 1)]);
+    }
+
+    #[test]
+    fn reenqueue_puts_retries_first() {
+        let now = Instant::now();
+        let mut accumulator = Accumulator::new(fits(1), LINGER);
+        append(&mut accumulator, 0, now);
+        append(&mut accumulator, 0, now);
+        append(&mut accumulator, 0, now);
+
+        let first = accumulator.drain(&[key(0)], usize::MAX, |_| true).remove(0);
+        let second = accumulator.drain(&[key(0)], usize::MAX, |_| true).remove(0);
+        append(&mut accumulator, 0, now);
+
+        accumulator.reenqueue(second);
+        accumulator.reenqueue(first);
+        assert_eq!(queue(&accumulator, 0), [(0, 1), (1, 1), (2, 1), (3, 1)]);
+    }
+}
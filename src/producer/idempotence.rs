@@ -0,0 +1,294 @@
+//! Includes the state of an idempotent producer: its producer ID and epoch, and the sequence
+//! numbers of the batches sent to each partition.
+//!
+//! The broker only appends a batch whose base sequence directly follows the last batch it
+//! appended from the same producer ID and epoch, so that a batch which is retried after it was
+//! written is not written again, and batches are never written out of order.
+
+use std::collections::{HashMap, VecDeque};
+
+use prague_protocol::types::prelude::*;
+
+use super::accumulator::{ProducerBatch, TopicPartition};
+
+/// The producer ID and epoch which the brokers use to deduplicate batches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct ProducerIdAndEpoch {
+    pub id: ProducerId,
+    pub epoch: i16,
+}
+
+/// Assigns sequence numbers to batches, and tracks which of them are not acknowledged yet.
+#[derive(Debug, Default)]
+pub(crate) struct Sequences {
+    producer: Option<ProducerIdAndEpoch>,
+
+    /// Whether the epoch must be bumped before any more batches are sent, because a gap in the
+    /// sequence numbers of some partition cannot be closed.
+    bump_required: bool,
+
+    partitions: HashMap<TopicPartition, PartitionSequences>,
+}
+
+#[derive(Debug, Default)]
+struct PartitionSequences {
+    next: i32,
+
+    /// The base sequences of the batches which were assigned one and have not completed, in the
+    /// order they were assigned.
+    in_flight: VecDeque<i32>,
+}
+
+impl Sequences {
+    /// Returns the producer ID and epoch to send batches with, or None if a new one must be
+    /// initialized first.
+    pub fn producer(&self) -> Option<ProducerIdAndEpoch> {
+        self.producer.filter(|_| !self.bump_required)
+    }
+
+    /// Returns the producer ID and epoch whose epoch needs bumping, if any.
+    pub fn bump_required(&self) -> Option<ProducerIdAndEpoch> {
+        self.producer.filter(|_| self.bump_required)
+    }
+
+    /// Starts over with a new producer ID or epoch. Every partition's sequence starts at zero
+    /// again, and batches assigned a sequence with the previous epoch are assigned a new one when
+    /// they are sent again.
+    pub fn reset(&mut self, producer: ProducerIdAndEpoch) {
+        self.producer = Some(producer);
+        self.bump_required = false;
+        self.partitions.clear();
+    }
+
+    /// Requests an epoch bump, once every request in flight has completed.
+    pub fn request_bump(&mut self) {
+        self.bump_required = true;
+    }
+
+    /// Prepares a batch to be sent, and returns whether it may be sent now.
+    ///
+    /// A batch sent for the first time with the current epoch is assigned the next sequence of
+    /// its partition. A batch which is retried keeps its sequence, and is only sent once every
+    /// batch before it was acknowledged, so that retries cannot reorder batches.
+    pub fn prepare(&mut self, batch: &mut ProducerBatch) -> bool {
+        let Some(producer) = self.producer() else {
+            return false;
+        };
+
+        let partition = self
+            .partitions
+            .entry((batch.topic.clone(), batch.partition))
+            .or_default();
+
+        match batch.base_sequence {
+            Some(sequence) if batch.producer == Some(producer) => {
+                partition.in_flight.front() == Some(&sequence)
+            }
+            _ => {
+                let sequence = partition.next;
+                partition.next = increment(sequence, batch.records.len());
+                partition.in_flight.push_back(sequence);
+
+                batch.producer = Some(producer);
+                batch.base_sequence = Some(sequence);
+                true
+            }
+        }
+    }
+
+    /// Returns whether every batch of the same partition sent before this one was acknowledged.
+    pub fn is_first_in_flight(&self, batch: &ProducerBatch) -> bool {
+        self.sequence_of(batch)
+            .is_some_and(|(partition, sequence)| partition.in_flight.front() == Some(&sequence))
+    }
+
+    /// Records that a batch was acknowledged.
+    pub fn complete(&mut self, batch: &ProducerBatch) {
+        if let Some(partition) = self.in_flight_of(batch) {
+            partition.retain(|&sequence| Some(sequence) != batch.base_sequence);
+        }
+    }
+
+    /// Records that a batch failed for good. The broker may be waiting for its sequence, so the
+    /// epoch is bumped to start every partition's sequence over.
+    pub fn fail(&mut self, batch: &ProducerBatch) {
+        if let Some(partition) = self.in_flight_of(batch) {
+            partition.retain(|&sequence| Some(sequence) != batch.base_sequence);
+            self.bump_required = true;
+        }
+    }
+
+    fn sequence_of(&self, batch: &ProducerBatch) -> Option<(&PartitionSequences, i32)> {
+        let sequence = batch
+            .base_sequence
+            .filter(|_| batch.producer == self.producer)?;
+        let partition = self
+            .partitions
+            .get(&(batch.topic.clone(), batch.partition))?;
+        Some((partition, sequence))
+    }
+
+    /// Returns the in-flight sequences of the batch's partition, if the batch was assigned a
+    /// sequence with the current epoch.
+    fn in_flight_of(&mut self, batch: &ProducerBatch) -> Option<&mut VecDeque<i32>> {
+        if batch.base_sequence.is_none() || batch.producer != self.producer {
+            return None;
+        }
+
+        self.partitions
+            .get_mut(&(batch.topic.clone(), batch.partition))
+            .map(|partition| &mut partition.in_flight)
+    }
+}
+
+/// Returns the sequence following a batch of `count` records, which wraps around to zero after
+/// `i32::MAX` like the broker expects.
+fn increment(sequence: i32, count: usize) -> i32 {
+    ((i64::from(sequence) + count as i64) % (i64::from(i32::MAX) + 1)) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use prague_protocol::records::Record;
+    use tokio::time::Instant;
+
+    use super::*;
+    use crate::producer::accumulator::Accumulator;
+
+    const PRODUCER: ProducerIdAndEpoch = ProducerIdAndEpoch {
+        id: ProducerId(1000),
+        epoch: 0,
+    };
+
+    /// Returns a batch of `count` records for the given partition.
+    fn batch(partition: i32, count: usize) -> ProducerBatch {
+        let mut accumulator = Accumulator::new(usize::MAX, std::time::Duration::ZERO);
+        for _ in 0..count {
+            let record = Record {
+                length: VarInt(0),
+                attributes: 0,
+                timestamp_delta: VarLong(0),
+                offset_delta: VarInt(0),
+                key: None,
+                value: None,
+                headers: vec![],
+            };
+            let (delivery, _) = tokio::sync::oneshot::channel();
+            accumulator.append(
+                "topic",
+                PartitionIndex(partition),
+                record,
+                Timestamp(0),
+                delivery,
+                Instant::now(),
+            );
+        }
+
+        let partitions = [("topic".to_owned(), PartitionIndex(partition))];
+        accumulator
+            .drain(&partitions, usize::MAX, |_| true)
+            .remove(0)
+    }
+
+    #[test]
+    fn assigns_sequences_per_partition() {
+        let mut sequences = Sequences::default();
+        let mut first = batch(0, 3);
+        assert!(!sequences.prepare(&mut first));
+        assert_eq!(first.base_sequence, None);
+
+        sequences.reset(PRODUCER);
+        let mut second = batch(0, 2);
+        let mut other = batch(1, 1);
+        assert!(sequences.prepare(&mut first));
+        assert!(sequences.prepare(&mut second));
+        assert!(sequences.prepare(&mut other));
+
+        assert_eq!(first.base_sequence, Some(0));
+        assert_eq!(first.producer, Some(PRODUCER));
+        assert_eq!(second.base_sequence, Some(3));
+        assert_eq!(other.base_sequence, Some(0));
+    }
+
+    #[test]
+    fn completes_in_flight_batches() {
+        let mut sequences = Sequences::default();
+        sequences.reset(PRODUCER);
+
+        let mut first = batch(0, 1);
+        let mut second = batch(0, 1);
+        sequences.prepare(&mut first);
+        sequences.prepare(&mut second);
+        assert!(sequences.is_first_in_flight(&first));
+        assert!(!sequences.is_first_in_flight(&second));
+
+        sequences.complete(&first);
+        assert!(sequences.is_first_in_flight(&second));
+        assert_eq!(sequences.producer(), Some(PRODUCER));
+    }
+
+    #[test]
+    fn retries_only_the_first_batch_in_flight() {
+        let mut sequences = Sequences::default();
+        sequences.reset(PRODUCER);
+
+        let mut first = batch(0, 1);
+        let mut second = batch(0, 1);
+        sequences.prepare(&mut first);
+        sequences.prepare(&mut second);
+
+        // Retried batches keep their sequence, and wait for every batch before them.
+        assert!(!sequences.prepare(&mut second));
+        assert!(sequences.prepare(&mut first));
+        assert_eq!(first.base_sequence, Some(0));
+
+        sequences.complete(&first);
+        assert!(sequences.prepare(&mut second));
+        assert_eq!(second.base_sequence, Some(1));
+    }
+
+    #[test]
+    fn failed_batch_requires_bump() {
+        let mut sequences = Sequences::default();
+        sequences.reset(PRODUCER);
+
+        let mut first = batch(0, 1);
+        let mut second = batch(0, 1);
+        sequences.prepare(&mut first);
+        sequences.prepare(&mut second);
+
+        sequences.fail(&first);
+        assert_eq!(sequences.producer(), None);
+        assert_eq!(sequences.bump_required(), Some(PRODUCER));
+        assert!(!sequences.prepare(&mut second));
+
+        // A batch sent with the previous epoch is assigned a new sequence.
+        let bumped = ProducerIdAndEpoch {
+            epoch: 1,
+            ..PRODUCER
+        };
+        sequences.reset(bumped);
+        assert_eq!(sequences.bump_required(), None);
+        assert!(sequences.prepare(&mut second));
+        assert_eq!(second.producer, Some(bumped));
+        assert_eq!(second.base_sequence, Some(0));
+    }
+
+    #[test]
+    fn requested_bump_waits_for_reset() {
+        let mut sequences = Sequences::default();
+        sequences.reset(PRODUCER);
+
+        sequences.request_bump();
+        assert_eq!(sequences.bump_required(), Some(PRODUCER));
+        assert!(!sequences.prepare(&mut batch(0, 1)));
+    }
+
+    #[test]
+    fn sequences_wrap_after_max() {
+        assert_eq!(increment(0, 5), 5);
+        assert_eq!(increment(i32::MAX - 1, 1), i32::MAX);
+        assert_eq!(increment(i32::MAX, 1), 0);
+        assert_eq!(increment(i32::MAX - 1, 3), 1);
+    }
+}
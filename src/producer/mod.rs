@@ -0,0 +1,1218 @@
+//! Includes the producer, which batches records per partition and sends them to the brokers
+//! which lead those partitions.
+//!
+//! [send](Producer::send) only appends a record to the batch of its partition, and returns a
+//! [DeliveryFuture] which resolves once the broker acknowledged it. A background task sends a
+//! batch once it is full or has lingered long enough, and combines the batches for partitions led
+//! by the same broker into one request.
+//!
+//! A producer with a [transactional_id](ProducerConfig::transactional_id) writes its records in
+//! transactions instead, which are committed or aborted atomically together with the offsets of
+//! a consumer group. See [begin_transaction](Producer::begin_transaction).
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use prague_protocol::{
+    acks::AckRequirement,
+    api::prelude::ApiKey,
+    error_code::ErrorCode,
+    init_producer_id::prelude::*,
+    produce::{
+        prelude::*,
+        request::{PartitionProduceTarget, TopicProduceTarget},
+        response::PartitionProduceResult,
+    },
+    records::{Header, Record, RecordBatchCompression, Records},
+    types::prelude::*,
+};
+use tokio::{
+    sync::{oneshot, watch, Notify},
+    task::JoinHandle,
+    time::{self, Instant},
+};
+
+use self::{
+    accumulator::{Accumulator, ProducerBatch, TopicPartition},
+    idempotence::{ProducerIdAndEpoch, Sequences},
+    partitioner::{DefaultPartitioner, Partitioner},
+    transaction::Transaction,
+};
+use crate::{
+    cluster::{self, TopicInfo},
+    pool::{self, ConnectionPool},
+};
+
+mod accumulator;
+mod idempotence;
+pub mod partitioner;
+mod transaction;
+
+pub use self::transaction::{ConsumerGroupMetadata, OffsetAndMetadata};
+
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum Error {
+    #[error("Invalid producer configuration: {0}")]
+    InvalidConfig(&'static str),
+
+    #[error(transparent)]
+    Cluster(Arc<cluster::Error>),
+
+    #[error(transparent)]
+    Pool(Arc<pool::Error>),
+
+    #[error("A record of {0} bytes is larger than the maximum request size")]
+    RecordTooLarge(usize),
+
+    #[error("Topic `{topic}` has no partition `{partition:?}`")]
+    UnknownPartition {
+        topic: String,
+        partition: PartitionIndex,
+    },
+
+    #[error(
+        "Producing to partition `{partition:?}` of topic `{topic}` failed with `{error_code:?}`"
+    )]
+    Broker {
+        topic: String,
+        partition: PartitionIndex,
+        error_code: ErrorCode,
+        message: Option<String>,
+    },
+
+    #[error("Initializing the producer ID failed with `{0:?}`")]
+    InitProducerId(ErrorCode),
+
+    #[error(
+        "The record was not written because another record in its batch to partition \
+         `{partition:?}` of topic `{topic}` failed with `{error_code:?}`"
+    )]
+    BatchRejected {
+        topic: String,
+        partition: PartitionIndex,
+        error_code: ErrorCode,
+    },
+
+    #[error("The response did not include partition `{partition:?}` of topic `{topic}`")]
+    MissingResult {
+        topic: String,
+        partition: PartitionIndex,
+    },
+
+    #[error("The record was not acknowledged within the delivery timeout")]
+    Timeout,
+
+    #[error("The producer is closed")]
+    Closed,
+
+    #[error("The transaction must be aborted: {0}")]
+    AbortableError(Box<Error>),
+
+    #[error("The producer cannot be used anymore: {0}")]
+    FatalError(Box<Error>),
+
+    #[error("Invalid transaction state: {0}")]
+    InvalidTransactionState(&'static str),
+
+    #[error("The coordinator failed with `{0:?}`")]
+    Coordinator(ErrorCode),
+
+    #[error("The transaction was aborted before the record was sent")]
+    TransactionAborted,
+
+    #[error("No coordinator completed the `{0:?}` request within the maximum block time")]
+    CoordinatorTimeout(ApiKey),
+}
+
+/// The most batches per partition the broker deduplicates for an idempotent producer.
+const MAX_IDEMPOTENT_IN_FLIGHT: usize = 5;
+
+/// Configures a [Producer].
+#[derive(Debug, Clone)]
+pub struct ProducerConfig {
+    /// How many replicas must have received a batch before the leader acknowledges it.
+    ///
+    /// With [NoAcknowledgements](AckRequirement::NoAcknowledgements), the broker does not respond
+    /// at all, and a record is considered delivered once its request was sent. Unless
+    /// [idempotence](Self::idempotence) is enabled explicitly, this disables it, as it requires
+    /// acks from the full ISR.
+    pub acks: AckRequirement,
+
+    /// The size in bytes up to which records for the same partition are batched together.
+    pub batch_size: usize,
+
+    /// How long a batch which is not full waits for more records before it is sent.
+    pub linger: Duration,
+
+    /// The largest request sent to a broker, which also bounds the size of a single record.
+    pub max_request_size: usize,
+
+    /// The compression applied to each batch.
+    pub compression: RecordBatchCompression,
+
+    /// How long the broker may wait for replicas to acknowledge a batch.
+    pub request_timeout: Duration,
+
+    /// How long after a record is sent it may still be retried. Records which are not
+    /// acknowledged by then fail with [Error::Timeout].
+    pub delivery_timeout: Duration,
+
+    /// How long to wait before retrying a batch which failed with a retriable error.
+    pub retry_backoff: Duration,
+
+    /// The most produce requests which may be in flight to a single broker. This may be at most
+    /// 5 for an idempotent producer, which is how many batches the broker deduplicates.
+    pub max_in_flight: usize,
+
+    /// Whether the broker deduplicates the batches of this producer, so that a retry never
+    /// writes a record twice or out of order. This requires [acks](Self::acks) to be
+    /// [FullISR](AckRequirement::FullISR) and at most 5 [requests in flight](Self::max_in_flight).
+    ///
+    /// If None, the producer is idempotent unless the other settings rule it out, like the Java
+    /// client. See [is_idempotent](Self::is_idempotent).
+    pub idempotence: Option<bool>,
+
+    /// Chooses the partition of records which do not name one, or None for a
+    /// [DefaultPartitioner] using the [batch_size](Self::batch_size).
+    pub partitioner: Option<Arc<dyn Partitioner>>,
+
+    /// The ID which identifies this producer across restarts, or None if it does not use
+    /// transactions. A transactional producer must be [idempotent](Self::idempotence).
+    pub transactional_id: Option<String>,
+
+    /// How long a transaction may stay open before the coordinator aborts it.
+    pub transaction_timeout: Duration,
+}
+
+impl Default for ProducerConfig {
+    fn default() -> Self {
+        Self {
+            acks: AckRequirement::FullISR,
+            batch_size: 16 * 1024,
+            linger: Duration::from_millis(5),
+            max_request_size: 1024 * 1024,
+            compression: RecordBatchCompression::NoCompression,
+            request_timeout: Duration::from_secs(30),
+            delivery_timeout: Duration::from_secs(120),
+            retry_backoff: Duration::from_millis(100),
+            max_in_flight: 5,
+            idempotence: None,
+            partitioner: None,
+            transactional_id: None,
+            transaction_timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+impl ProducerConfig {
+    /// Returns whether the producer is idempotent. Unless [idempotence](Self::idempotence) is
+    /// set, it is for a transactional producer, or if [acks](Self::acks) and
+    /// [max_in_flight](Self::max_in_flight) allow it.
+    pub fn is_idempotent(&self) -> bool {
+        match self.idempotence {
+            Some(idempotence) => idempotence,
+            None => {
+                self.transactional_id.is_some()
+                    || (self.acks == AckRequirement::FullISR
+                        && self.max_in_flight <= MAX_IDEMPOTENT_IN_FLIGHT)
+            }
+        }
+    }
+}
+
+/// A record to send with [send_record](Producer::send_record).
+#[derive(Debug, Clone)]
+pub struct ProducerRecord {
+    pub topic: String,
+
+    /// The partition to send the record to, or None to let the
+    /// [partitioner](ProducerConfig::partitioner) choose one.
+    pub partition: Option<PartitionIndex>,
+
+    pub key: Option<Vec<u8>>,
+    pub value: Option<Vec<u8>>,
+    pub headers: Vec<Header>,
+
+    /// The creation time of the record in milliseconds since the epoch, or None for the time it
+    /// is sent.
+    pub timestamp: Option<Timestamp>,
+}
+
+/// Where a record was written once the broker acknowledged it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordMetadata {
+    pub topic: String,
+    pub partition: PartitionIndex,
+
+    /// The offset of the record, or None if the producer does not wait for
+    /// [acknowledgements](AckRequirement::NoAcknowledgements).
+    pub offset: Option<Offset>,
+
+    /// The creation time of the record, or the time the broker appended it if the topic uses
+    /// log append time.
+    pub timestamp: Timestamp,
+}
+
+/// Resolves once a record sent with [send](Producer::send) is acknowledged, or failed.
+#[derive(Debug)]
+pub struct DeliveryFuture {
+    receiver: oneshot::Receiver<Result<RecordMetadata, Error>>,
+}
+
+impl Future for DeliveryFuture {
+    type Output = Result<RecordMetadata, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(|result| result.unwrap_or(Err(Error::Closed)))
+    }
+}
+
+/// A producer which sends records to the partitions of any topic in a cluster.
+///
+/// Records are sent in the order they were appended to a partition. Unless the producer is
+/// [idempotent](ProducerConfig::idempotence), a batch which is retried may be written twice, or
+/// after batches sent later to the same partition.
+///
+/// A producer with a [transactional_id](ProducerConfig::transactional_id) only accepts records
+/// between [begin_transaction](Self::begin_transaction) and
+/// [commit_transaction](Self::commit_transaction) or
+/// [abort_transaction](Self::abort_transaction).
+#[derive(Debug)]
+pub struct Producer {
+    inner: Arc<Inner>,
+    refresher: JoinHandle<()>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    pool: Arc<ConnectionPool>,
+    config: ProducerConfig,
+    accumulator: Mutex<Accumulator>,
+    in_flight: Mutex<HashMap<BrokerId, usize>>,
+    sequences: Mutex<Sequences>,
+    transaction: Mutex<Transaction>,
+
+    /// Wakes the sender task when a batch may have become ready.
+    wakeup: Notify,
+
+    /// The number of records which have not been acknowledged or failed yet.
+    pending: watch::Sender<usize>,
+
+    partitioner: Arc<dyn Partitioner>,
+    flushing: AtomicUsize,
+    closed: AtomicBool,
+}
+
+impl Producer {
+    /// Creates a producer which sends records to the brokers of the pool's cluster.
+    ///
+    /// This spawns the task which sends batches, and a [refresher](cluster::Cluster::spawn_refresher)
+    /// so that the producer learns of new partition leaders.
+    pub fn new(pool: Arc<ConnectionPool>, config: ProducerConfig) -> Result<Self, Error> {
+        if config.transactional_id.is_some() && !config.is_idempotent() {
+            return Err(Error::InvalidConfig(
+                "a transactional producer must be idempotent",
+            ));
+        }
+        if config.is_idempotent() {
+            if config.acks != AckRequirement::FullISR {
+                return Err(Error::InvalidConfig(
+                    "an idempotent producer requires acks from the full ISR",
+                ));
+            }
+            if config.max_in_flight > MAX_IDEMPOTENT_IN_FLIGHT {
+                return Err(Error::InvalidConfig(
+                    "an idempotent producer allows at most 5 requests in flight",
+                ));
+            }
+        }
+
+        let refresher = pool.cluster().spawn_refresher();
+        let partitioner = config
+            .partitioner
+            .clone()
+            .unwrap_or_else(|| Arc::new(DefaultPartitioner::new(config.batch_size)));
+
+        let inner = Arc::new(Inner {
+            accumulator: Mutex::new(Accumulator::new(config.batch_size, config.linger)),
+            pool,
+            config,
+            in_flight: Mutex::default(),
+            sequences: Mutex::default(),
+            transaction: Mutex::default(),
+            wakeup: Notify::new(),
+            pending: watch::Sender::new(0),
+            partitioner,
+            flushing: AtomicUsize::new(0),
+            closed: AtomicBool::new(false),
+        });
+
+        tokio::spawn(Arc::clone(&inner).run());
+        Ok(Self { inner, refresher })
+    }
+
+    /// Returns the configuration of this producer.
+    pub fn config(&self) -> &ProducerConfig {
+        &self.inner.config
+    }
+
+    /// Appends a record to the batch of the partition chosen for its key, and returns a future
+    /// which resolves once the record is acknowledged.
+    ///
+    /// This waits only if the metadata of the topic is not known yet.
+    pub async fn send(
+        &self,
+        topic: impl Into<String>,
+        key: Option<Vec<u8>>,
+        value: Option<Vec<u8>>,
+        headers: Vec<Header>,
+    ) -> Result<DeliveryFuture, Error> {
+        self.send_record(ProducerRecord {
+            topic: topic.into(),
+            partition: None,
+            key,
+            value,
+            headers,
+            timestamp: None,
+        })
+        .await
+    }
+
+    /// Appends a record to the batch of its partition, and returns a future which resolves once
+    /// the record is acknowledged.
+    pub async fn send_record(&self, record: ProducerRecord) -> Result<DeliveryFuture, Error> {
+        if self.inner.closed.load(Ordering::Acquire) {
+            return Err(Error::Closed);
+        }
+
+        if self.inner.is_transactional() {
+            self.inner.transaction().check_can_send()?;
+        }
+
+        let topic = self.topic(&record.topic).await?;
+        let timestamp = record.timestamp.unwrap_or_else(now);
+        let encoded = Record {
+            length: VarInt(0),
+            attributes: 0,
+            timestamp_delta: VarLong(0),
+            offset_delta: VarInt(0),
+            key: record.key,
+            value: record.value,
+            headers: record.headers,
+        };
+
+        let size = Records::BATCH_OVERHEAD + encoded.encoded_len();
+        if size > self.inner.config.max_request_size {
+            return Err(Error::RecordTooLarge(size));
+        }
+
+        let partition = match record.partition {
+            Some(partition) if topic.partition(partition).is_some() => partition,
+            Some(partition) => {
+                return Err(Error::UnknownPartition {
+                    topic: record.topic,
+                    partition,
+                })
+            }
+            None => self.inner.partitioner.partition(
+                &topic,
+                encoded.key.as_deref(),
+                encoded.encoded_len(),
+            ),
+        };
+
+        // The transaction stays locked until the record is appended, so that an abort either
+        // rejects the record or takes it along with every other batch.
+        let transaction = match self.inner.is_transactional() {
+            true => {
+                let mut transaction = self.inner.transaction();
+                transaction.check_can_send()?;
+                transaction.add_partition((record.topic.clone(), partition));
+                Some(transaction)
+            }
+            false => None,
+        };
+
+        let (delivery, receiver) = oneshot::channel();
+        self.inner.pending.send_modify(|pending| *pending += 1);
+
+        let wake = self.inner.accumulator().append(
+            &record.topic,
+            partition,
+            encoded,
+            timestamp,
+            delivery,
+            Instant::now(),
+        );
+        drop(transaction);
+
+        if wake {
+            self.inner.wakeup.notify_one();
+        }
+
+        Ok(DeliveryFuture { receiver })
+    }
+
+    /// Sends every batch immediately, and waits until every record sent so far is acknowledged
+    /// or failed.
+    pub async fn flush(&self) {
+        self.inner.flushing.fetch_add(1, Ordering::AcqRel);
+        self.inner.wakeup.notify_one();
+
+        let mut pending = self.inner.pending.subscribe();
+        // The sender is never dropped while the producer exists.
+        let _ = pending.wait_for(|&pending| pending == 0).await;
+
+        self.inner.flushing.fetch_sub(1, Ordering::AcqRel);
+    }
+
+    /// Stops accepting records, and waits until every record sent so far is acknowledged or
+    /// failed.
+    pub async fn close(&self) {
+        self.inner.closed.store(true, Ordering::Release);
+        self.flush().await;
+    }
+
+    async fn topic(&self, name: &str) -> Result<TopicInfo, Error> {
+        let cluster = self.inner.pool.cluster();
+        match cluster.metadata().topic(name) {
+            Some(topic) if topic.error_code.is_none() && topic.partition_count() > 0 => {
+                Ok(topic.clone())
+            }
+            _ => cluster
+                .topic(name)
+                .await
+                .map_err(|error| Error::Cluster(Arc::new(error))),
+        }
+    }
+}
+
+impl Drop for Producer {
+    /// Lets the sender task finish the records already sent, and then stop.
+    fn drop(&mut self) {
+        self.inner.closed.store(true, Ordering::Release);
+        self.inner.wakeup.notify_one();
+        self.refresher.abort();
+    }
+}
+
+impl Inner {
+    fn accumulator(&self) -> MutexGuard<'_, Accumulator> {
+        self.accumulator
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+
+    fn in_flight(&self) -> MutexGuard<'_, HashMap<BrokerId, usize>> {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+
+    fn sequences(&self) -> MutexGuard<'_, Sequences> {
+        self.sequences
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+
+    fn transaction(&self) -> MutexGuard<'_, Transaction> {
+        self.transaction
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+
+    fn is_transactional(&self) -> bool {
+        self.config.transactional_id.is_some()
+    }
+
+    /// Sends ready batches until the producer is closed and every record was acknowledged or
+    /// failed.
+    async fn run(self: Arc<Self>) {
+        loop {
+            let now = Instant::now();
+            let metadata = self.pool.cluster().metadata();
+            let flush =
+                self.flushing.load(Ordering::Acquire) > 0 || self.closed.load(Ordering::Acquire);
+
+            let ready = self.accumulator().ready(&metadata, now, flush);
+            if ready.unknown_leader {
+                self.pool.cluster().invalidate();
+            }
+
+            // A transactional producer initializes its producer ID and bumps its epoch in
+            // init_transactions and abort_transaction instead.
+            let init_retry_at = match (self.config.is_idempotent(), self.is_transactional()) {
+                (true, false) => self.maybe_init_producer_id().await,
+                (true, true) => self.maybe_add_partitions().await,
+                (false, _) => None,
+            };
+
+            for (broker, partitions) in ready.brokers {
+                if !self.has_capacity(broker) {
+                    continue;
+                }
+
+                // Like send_record, the transaction is locked before the accumulator.
+                let transaction = self.is_transactional().then(|| self.transaction());
+                let batches =
+                    self.accumulator()
+                        .drain(&partitions, self.config.max_request_size, |batch| {
+                            self.may_send(batch, transaction.as_deref())
+                        });
+                drop(transaction);
+
+                if batches.is_empty() {
+                    continue;
+                }
+
+                self.acquire(broker);
+                tokio::spawn(Arc::clone(&self).produce(broker, batches));
+            }
+
+            let expired = self.accumulator().expire(now, self.config.delivery_timeout);
+            for batch in expired {
+                self.fail(batch, Error::Timeout);
+            }
+
+            if self.closed.load(Ordering::Acquire) && *self.pending.borrow() == 0 {
+                return;
+            }
+
+            // Partitions without a leader are checked again once the metadata may have been
+            // refreshed. Brokers at their in-flight limit wake the task when a request completes.
+            let next_check = [
+                ready.next_check,
+                ready
+                    .unknown_leader
+                    .then(|| now + self.config.retry_backoff),
+                init_retry_at,
+                self.accumulator().next_expiry(self.config.delivery_timeout),
+            ]
+            .into_iter()
+            .flatten()
+            .min();
+
+            match next_check {
+                Some(deadline) => {
+                    let _ = time::timeout_at(deadline, self.wakeup.notified()).await;
+                }
+                None => self.wakeup.notified().await,
+            }
+        }
+    }
+
+    /// Returns whether a batch may be sent now, assigning it a sequence if the producer is
+    /// idempotent. A transactional producer only sends batches to partitions which were added to
+    /// the given transaction.
+    fn may_send(&self, batch: &mut ProducerBatch, transaction: Option<&Transaction>) -> bool {
+        if !self.config.is_idempotent() {
+            return true;
+        }
+
+        if let Some(transaction) = transaction {
+            let partition = (batch.topic.clone(), batch.partition);
+            if !transaction.is_added(&partition) {
+                return false;
+            }
+        }
+        self.sequences().prepare(batch)
+    }
+
+    /// Returns whether another request may be sent to the broker.
+    fn has_capacity(&self, broker: BrokerId) -> bool {
+        self.in_flight().get(&broker).copied().unwrap_or(0) < self.config.max_in_flight
+    }
+
+    fn acquire(&self, broker: BrokerId) {
+        *self.in_flight().entry(broker).or_default() += 1;
+    }
+
+    fn release(&self, broker: BrokerId) {
+        if let Some(count) = self.in_flight().get_mut(&broker) {
+            *count = count.saturating_sub(1);
+        }
+        self.wakeup.notify_one();
+    }
+
+    /// Initializes the producer ID, or bumps its epoch, if batches are waiting for it and no
+    /// request is in flight. Returns when to try again if this failed with a retriable error.
+    ///
+    /// Batches wait for the new epoch until every request in flight has completed, since the
+    /// sequences of their batches would not match the new epoch.
+    async fn maybe_init_producer_id(&self) -> Option<Instant> {
+        let bump = {
+            let sequences = self.sequences();
+            if sequences.producer().is_some() {
+                return None;
+            }
+            sequences.bump_required()
+        };
+
+        let in_flight = self.in_flight().values().sum::<usize>();
+        if in_flight > 0 || self.accumulator().is_empty() {
+            return None;
+        }
+
+        let error = match self.init_producer_id(bump).await {
+            Ok(producer) => {
+                self.sequences().reset(producer);
+                return None;
+            }
+            Err(error) => error,
+        };
+
+        let retriable = match &error {
+            Error::InitProducerId(error_code) => error_code.is_retriable(),
+            _ => true,
+        };
+        if retriable {
+            return Some(Instant::now() + self.config.retry_backoff);
+        }
+
+        let batches = self.accumulator().take_all();
+        for batch in batches {
+            self.fail(batch, error.clone());
+        }
+        None
+    }
+
+    /// Sends an [InitProducerIdRequest] to the broker with the fewest requests in flight. With
+    /// the current producer ID and epoch, this bumps the epoch. Otherwise, it returns a new
+    /// producer ID.
+    async fn init_producer_id(
+        &self,
+        current: Option<ProducerIdAndEpoch>,
+    ) -> Result<ProducerIdAndEpoch, Error> {
+        let broker = self.any_broker().await?;
+        let response = self
+            .pool
+            .send::<InitProducerIdApi>(broker, &self.init_producer_id_request(current))
+            .await
+            .map_err(|error| Error::Pool(Arc::new(error)))?;
+
+        match response.error_code {
+            None => Ok(ProducerIdAndEpoch {
+                id: response.producer_id,
+                epoch: response.producer_epoch,
+            }),
+            Some(error_code) => Err(Error::InitProducerId(error_code)),
+        }
+    }
+
+    fn init_producer_id_request(
+        &self,
+        current: Option<ProducerIdAndEpoch>,
+    ) -> InitProducerIdRequest {
+        let timeout = self.config.transaction_timeout.as_millis();
+
+        InitProducerIdRequest {
+            transactional_id: self.config.transactional_id.clone(),
+            // The broker ignores the transaction timeout of producers without a transactional ID.
+            transaction_timeout: prague_protocol::types::prelude::Duration(
+                timeout.min(i32::MAX as u128) as i32,
+            ),
+            producer_id: current.map(|producer| producer.id),
+            producer_epoch: current.map(|producer| producer.epoch),
+            tagged_fields: Default::default(),
+        }
+    }
+
+    /// Returns the broker with the fewest requests in flight, refreshing the metadata if no
+    /// broker is known yet.
+    async fn any_broker(&self) -> Result<BrokerId, Error> {
+        if let Some(broker) = self.least_loaded_broker() {
+            return Ok(broker);
+        }
+
+        let metadata = self.pool.cluster().refresh().await;
+        metadata.map_err(|error| Error::Cluster(Arc::new(error)))?;
+        self.least_loaded_broker()
+            .ok_or(Error::Cluster(Arc::new(cluster::Error::NoBrokers)))
+    }
+
+    fn least_loaded_broker(&self) -> Option<BrokerId> {
+        let metadata = self.pool.cluster().metadata();
+        let in_flight = self.in_flight();
+
+        metadata
+            .brokers
+            .keys()
+            .min_by_key(|broker| in_flight.get(broker).copied().unwrap_or(0))
+            .copied()
+    }
+
+    /// Sends one request with the given batches to a broker, and completes or retries each batch
+    /// according to the response.
+    ///
+    /// Without [acknowledgements](AckRequirement::NoAcknowledgements), the broker never responds,
+    /// so each batch completes once the request was sent.
+    async fn produce(self: Arc<Self>, broker: BrokerId, batches: Vec<ProducerBatch>) {
+        let request = self.produce_request(&batches);
+        let response = match self.config.acks {
+            AckRequirement::NoAcknowledgements => self
+                .pool
+                .send_without_response::<ProduceApi>(broker, &request)
+                .await
+                .map(|()| None),
+            _ => self
+                .pool
+                .send::<ProduceApi>(broker, &request)
+                .await
+                .map(Some),
+        };
+        drop(request);
+
+        match response {
+            Ok(Some(response)) => self.handle_response(batches, response),
+            Ok(None) => {
+                for batch in batches {
+                    self.complete(batch, None, None);
+                }
+            }
+            Err(error) => {
+                let error = Error::Pool(Arc::new(error));
+                for batch in batches {
+                    self.retry_or_fail(batch, error.clone());
+                }
+            }
+        }
+
+        self.release(broker);
+    }
+
+    fn produce_request(&self, batches: &[ProducerBatch]) -> ProduceRequest {
+        let mut topics: Vec<TopicProduceTarget> = Vec::new();
+
+        for batch in batches {
+            let partition = PartitionProduceTarget {
+                index: batch.partition.0,
+                records: batch.to_records(self.config.compression, self.is_transactional()),
+                tagged_fields: Default::default(),
+            };
+
+            match topics.iter_mut().find(|topic| topic.name == batch.topic) {
+                Some(topic) => topic.partitions.push(partition),
+                None => topics.push(TopicProduceTarget {
+                    name: batch.topic.clone(),
+                    partitions: vec![partition],
+                    tagged_fields: Default::default(),
+                }),
+            }
+        }
+
+        ProduceRequest {
+            transactional_id: self.config.transactional_id.clone(),
+            acks: self.config.acks,
+            timeout: self
+                .config
+                .request_timeout
+                .as_millis()
+                .min(i32::MAX as u128) as i32,
+            topics,
+            tagged_fields: Default::default(),
+        }
+    }
+
+    fn handle_response(&self, batches: Vec<ProducerBatch>, response: ProduceResponse) {
+        let cluster = self.pool.cluster();
+        cluster.update_leaders_from_produce(&response);
+        let metadata = cluster.metadata();
+
+        let mut results: HashMap<TopicPartition, PartitionProduceResult> = HashMap::new();
+        for topic in response.topics {
+            for partition in topic.partitions {
+                results.insert(
+                    (topic.name.clone(), PartitionIndex(partition.index)),
+                    partition,
+                );
+            }
+        }
+
+        for batch in batches {
+            let Some(result) = results.remove(&(batch.topic.clone(), batch.partition)) else {
+                let error = Error::MissingResult {
+                    topic: batch.topic.clone(),
+                    partition: batch.partition,
+                };
+                self.fail(batch, error);
+                continue;
+            };
+
+            // A duplicate was written by an earlier attempt of the same batch, but the broker
+            // does not say where, so the batch succeeds without an offset.
+            let error_code = match result.error_code {
+                None => {
+                    self.complete(batch, Some(result.base_offset), result.log_append_time);
+                    continue;
+                }
+                Some(ErrorCode::DuplicateSequenceNumber) if batch.base_sequence.is_some() => {
+                    self.complete(batch, None, None);
+                    continue;
+                }
+                Some(error_code) => error_code,
+            };
+
+            // A broker which no longer leads the partition may name the new leader. Once the
+            // cache points at it, the batch is retried there right away, without refreshing the
+            // metadata first.
+            let moved = result
+                .current_leader
+                .as_ref()
+                .and_then(|leader| leader.id)
+                .is_some_and(|leader| {
+                    metadata
+                        .partition(&batch.topic, batch.partition)
+                        .and_then(|partition| partition.leader)
+                        == Some(BrokerId(leader))
+                });
+            if !moved {
+                cluster.handle_error_code(error_code);
+            }
+
+            let error = Error::Broker {
+                topic: batch.topic.clone(),
+                partition: batch.partition,
+                error_code,
+                message: result.error_message,
+            };
+
+            // The broker rejects the whole batch if any of its records is invalid, and names
+            // those records. The others are not at fault, but are not retried either. Indices
+            // which cannot name a record are ignored.
+            if !result.record_errors.is_empty() {
+                let record_errors: HashMap<usize, Option<String>> = result
+                    .record_errors
+                    .into_iter()
+                    .filter_map(|record_error| {
+                        let index = usize::try_from(record_error.index).ok()?;
+                        Some((index, record_error.message))
+                    })
+                    .collect();
+
+                self.fail_records(batch, error, |index, error| match error {
+                    Error::Broker {
+                        topic,
+                        partition,
+                        error_code,
+                        message,
+                    } => match record_errors.get(&index) {
+                        Some(record_message) => Error::Broker {
+                            topic: topic.clone(),
+                            partition: *partition,
+                            error_code: *error_code,
+                            message: record_message.clone().or_else(|| message.clone()),
+                        },
+                        None => Error::BatchRejected {
+                            topic: topic.clone(),
+                            partition: *partition,
+                            error_code: *error_code,
+                        },
+                    },
+                    error => error.clone(),
+                });
+                continue;
+            }
+
+            match error_code {
+                // Like the Java client, a batch behind others which are not acknowledged yet is
+                // retried once they are, since the broker rejects it until then. Otherwise, the
+                // broker lost the producer's state or there is a gap in the sequence which cannot
+                // close by itself, so the epoch is bumped and the batch is retried with a new
+                // sequence. A transactional producer bumps its epoch when the transaction is
+                // aborted instead.
+                ErrorCode::OutOfOrderSequenceNumber | ErrorCode::UnknownProducerId
+                    if self.is_transactional() =>
+                {
+                    self.fail(batch, error)
+                }
+                ErrorCode::OutOfOrderSequenceNumber | ErrorCode::UnknownProducerId
+                    if batch.base_sequence.is_some() =>
+                {
+                    {
+                        let mut sequences = self.sequences();
+                        if error_code == ErrorCode::UnknownProducerId
+                            || sequences.is_first_in_flight(&batch)
+                        {
+                            sequences.request_bump();
+                        }
+                    }
+                    self.retry_or_fail(batch, error);
+                }
+                error_code if error_code.is_retriable() && moved => {
+                    self.retry_after(batch, error, Duration::ZERO)
+                }
+                error_code if error_code.is_retriable() => self.retry_or_fail(batch, error),
+                _ => self.fail(batch, error),
+            }
+        }
+    }
+
+    /// Puts a batch back to be sent again after the retry backoff, unless that would exceed the
+    /// delivery timeout.
+    fn retry_or_fail(&self, batch: ProducerBatch, error: Error) {
+        self.retry_after(batch, error, self.config.retry_backoff);
+    }
+
+    /// Puts a batch back to be sent again after the given backoff, unless that would exceed the
+    /// delivery timeout.
+    fn retry_after(&self, mut batch: ProducerBatch, error: Error, backoff: Duration) {
+        let retry_at = Instant::now() + backoff;
+        if retry_at >= batch.created + self.config.delivery_timeout {
+            self.fail(batch, error);
+            return;
+        }
+
+        batch.attempts += 1;
+        batch.retry_at = Some(retry_at);
+        self.accumulator().reenqueue(batch);
+    }
+
+    /// Resolves the delivery of each record in a batch which the broker appended at the given
+    /// base offset, or which was sent without waiting for acknowledgements if there is none.
+    fn complete(
+        &self,
+        mut batch: ProducerBatch,
+        base_offset: Option<i64>,
+        log_append_time: Option<i64>,
+    ) {
+        self.sequences().complete(&batch);
+
+        let deliveries = std::mem::take(&mut batch.deliveries);
+        let count = deliveries.len();
+
+        for (index, delivery) in deliveries.into_iter().enumerate() {
+            let timestamp = log_append_time.map_or_else(|| batch.timestamp(index), Timestamp);
+            let _ = delivery.send(Ok(RecordMetadata {
+                topic: batch.topic.clone(),
+                partition: batch.partition,
+                offset: base_offset.map(|base_offset| Offset(base_offset + index as i64)),
+                timestamp,
+            }));
+        }
+
+        self.pending.send_modify(|pending| *pending -= count);
+    }
+
+    /// Fails the delivery of each record in a batch. This also fails the ongoing transaction of
+    /// a transactional producer, unless the batch was aborted with it.
+    fn fail(&self, batch: ProducerBatch, error: Error) {
+        self.fail_records(batch, error, |_, error| error.clone());
+    }
+
+    /// Fails the delivery of each record in a batch which failed with `error`, with the error
+    /// `record_error` returns for the record's index.
+    fn fail_records(
+        &self,
+        batch: ProducerBatch,
+        error: Error,
+        record_error: impl Fn(usize, &Error) -> Error,
+    ) {
+        self.sequences().fail(&batch);
+        if self.is_transactional() && !matches!(error, Error::TransactionAborted) {
+            self.transaction().fail(error.clone());
+        }
+
+        let count = batch.deliveries.len();
+
+        for (index, delivery) in batch.deliveries.into_iter().enumerate() {
+            let _ = delivery.send(Err(record_error(index, &error)));
+        }
+
+        self.pending.send_modify(|pending| *pending -= count);
+    }
+}
+
+/// Returns the current time in milliseconds since the epoch.
+fn now() -> Timestamp {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    Timestamp(elapsed.as_millis() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use prague_protocol::produce::response::{BatchError, TopicProduceResult};
+
+    use super::*;
+    use crate::cluster::{Cluster, ClusterConfig};
+
+    fn inner(config: ProducerConfig) -> Inner {
+        let cluster = Arc::new(Cluster::new(ClusterConfig::new(["127.0.0.1:1"])));
+        Inner {
+            pool: Arc::new(ConnectionPool::new(cluster, pool::PoolConfig::default())),
+            accumulator: Mutex::new(Accumulator::new(config.batch_size, config.linger)),
+            partitioner: Arc::new(DefaultPartitioner::new(config.batch_size)),
+            config,
+            in_flight: Mutex::default(),
+            sequences: Mutex::default(),
+            transaction: Mutex::default(),
+            wakeup: Notify::new(),
+            pending: watch::Sender::new(0),
+            flushing: AtomicUsize::new(0),
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    fn record() -> Record {
+        Record {
+            length: VarInt(0),
+            attributes: 0,
+            timestamp_delta: VarLong(0),
+            offset_delta: VarInt(0),
+            key: None,
+            value: Some(b"value".to_vec()),
+            headers: vec![],
+        }
+    }
+
+    fn response(error_code: Option<ErrorCode>, base_offset: i64) -> ProduceResponse {
+        ProduceResponse {
+            topics: vec![TopicProduceResult {
+                name: "topic".to_owned(),
+                partitions: vec![PartitionProduceResult {
+                    index: 0,
+                    error_code,
+                    base_offset,
+                    log_append_time: None,
+                    log_start_offset: None,
+                    record_errors: vec![],
+                    error_message: None,
+                    current_leader: None,
+                    tagged_fields: Default::default(),
+                }],
+                tagged_fields: Default::default(),
+            }],
+            throttle_time: None,
+            endpoints: vec![],
+            tagged_fields: Default::default(),
+        }
+    }
+
+    #[test]
+    fn duplicate_sequence_completes_without_offset() {
+        let inner = inner(ProducerConfig::default());
+        inner.sequences().reset(ProducerIdAndEpoch {
+            id: ProducerId(1000),
+            epoch: 0,
+        });
+
+        let (delivery, mut receiver) = oneshot::channel();
+        inner.pending.send_modify(|pending| *pending += 1);
+        inner.accumulator().append(
+            "topic",
+            PartitionIndex(0),
+            record(),
+            Timestamp(1),
+            delivery,
+            Instant::now(),
+        );
+
+        let partitions = [("topic".to_owned(), PartitionIndex(0))];
+        let batches = inner.accumulator().drain(&partitions, usize::MAX, |batch| {
+            inner.sequences().prepare(batch)
+        });
+        assert_eq!(batches[0].base_sequence, Some(0));
+
+        inner.handle_response(
+            batches,
+            response(Some(ErrorCode::DuplicateSequenceNumber), -1),
+        );
+
+        let metadata = receiver.try_recv().unwrap().unwrap();
+        assert_eq!(metadata.offset, None);
+        assert_eq!(metadata.timestamp, Timestamp(1));
+        assert_eq!(*inner.pending.borrow(), 0);
+    }
+
+    #[test]
+    fn idempotence_follows_other_settings_unless_set() {
+        let config = ProducerConfig::default();
+        assert!(config.is_idempotent());
+
+        let config = ProducerConfig {
+            acks: AckRequirement::NoAcknowledgements,
+            ..Default::default()
+        };
+        assert!(!config.is_idempotent());
+
+        let config = ProducerConfig {
+            max_in_flight: 6,
+            ..Default::default()
+        };
+        assert!(!config.is_idempotent());
+
+        let config = ProducerConfig {
+            acks: AckRequirement::NoAcknowledgements,
+            idempotence: Some(true),
+            ..Default::default()
+        };
+        assert!(config.is_idempotent());
+
+        let config = ProducerConfig {
+            transactional_id: Some("transactional-id".to_owned()),
+            max_in_flight: 6,
+            ..Default::default()
+        };
+        assert!(config.is_idempotent());
+    }
+
+    #[test]
+    fn record_errors_ignore_invalid_indices() {
+        let inner = inner(ProducerConfig {
+            idempotence: Some(false),
+            ..Default::default()
+        });
+
+        let receivers: Vec<_> = (0..2)
+            .map(|_| {
+                let (delivery, receiver) = oneshot::channel();
+                inner.pending.send_modify(|pending| *pending += 1);
+                inner.accumulator().append(
+                    "topic",
+                    PartitionIndex(0),
+                    record(),
+                    Timestamp(1),
+                    delivery,
+                    Instant::now(),
+                );
+                receiver
+            })
+            .collect();
+
+        let partitions = [("topic".to_owned(), PartitionIndex(0))];
+        let batches = inner.accumulator().drain(&partitions, usize::MAX, |_| true);
+
+        let mut response = response(Some(ErrorCode::InvalidRecord), -1);
+        response.topics[0].partitions[0].record_errors = [-1, 1]
+            .into_iter()
+            .map(|index| BatchError {
+                index,
+                message: Some(format!("record {index}")),
+                tagged_fields: Default::default(),
+            })
+            .collect();
+        inner.handle_response(batches, response);
+
+        let errors: Vec<_> = receivers
+            .into_iter()
+            .map(|mut receiver| receiver.try_recv().unwrap().unwrap_err())
+            .collect();
+        assert!(matches!(errors[0], Error::BatchRejected { .. }));
+        assert!(matches!(
+            &errors[1],
+            Error::Broker { message: Some(message), .. } if message == "record 1"
+        ));
+    }
+}
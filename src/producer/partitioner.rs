@@ -0,0 +1,304 @@
+//! Includes the strategies a [Producer](super::Producer) uses to choose the partition of a record
+//! which does not name one.
+//!
+//! The built-in partitioners assign records the same way as their namesakes in the Java client,
+//! so that records with the same key land on the same partition whichever client produced them.
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use prague_protocol::types::prelude::*;
+use rand::Rng;
+
+use crate::cluster::TopicInfo;
+
+/// Chooses the partition of a record which does not name one.
+pub trait Partitioner: Debug + Send + Sync {
+    /// Returns the partition of a record with the given key, whose size in bytes once encoded is
+    /// `record_size`.
+    ///
+    /// This is only called for topics with at least one partition.
+    fn partition(
+        &self,
+        topic: &TopicInfo,
+        key: Option<&[u8]>,
+        record_size: usize,
+    ) -> PartitionIndex;
+}
+
+/// Hashes keys with [murmur2], like the Java client's `DefaultPartitioner`, and spreads records
+/// without a key like the [UniformStickyPartitioner].
+#[derive(Debug)]
+pub struct DefaultPartitioner {
+    sticky: UniformStickyPartitioner,
+}
+
+impl DefaultPartitioner {
+    /// Creates a partitioner which sticks to a partition for `batch_size` bytes of records
+    /// without a key.
+    pub fn new(batch_size: usize) -> Self {
+        Self {
+            sticky: UniformStickyPartitioner::new(batch_size),
+        }
+    }
+}
+
+impl Partitioner for DefaultPartitioner {
+    fn partition(
+        &self,
+        topic: &TopicInfo,
+        key: Option<&[u8]>,
+        record_size: usize,
+    ) -> PartitionIndex {
+        match key {
+            Some(key) => partition_for_key(key, topic.partition_count()),
+            None => self.sticky.partition(topic, None, record_size),
+        }
+    }
+}
+
+/// Assigns each record of a topic to the next partition in turn, ignoring its key, like the Java
+/// client's `RoundRobinPartitioner`.
+///
+/// Only partitions with a known leader are chosen, unless none has one.
+///
+/// ```
+/// # use std::collections::BTreeMap;
+/// # use prague::{cluster::{PartitionInfo, TopicInfo}, producer::partitioner::*};
+/// # use prague_protocol::types::prelude::*;
+/// # let partition = |index, leader: Option<i32>| PartitionInfo {
+/// #     index: PartitionIndex(index),
+/// #     leader: leader.map(BrokerId),
+/// #     leader_epoch: None,
+/// #     replicas: vec![],
+/// #     isr: vec![],
+/// #     offline_replicas: vec![],
+/// #     error_code: None,
+/// # };
+/// # let topic = |partitions: Vec<PartitionInfo>| TopicInfo {
+/// #     name: "orders".to_owned(),
+/// #     id: None,
+/// #     is_internal: false,
+/// #     error_code: None,
+/// #     partitions: partitions.into_iter().map(|p| (p.index, p)).collect::<BTreeMap<_, _>>(),
+/// # };
+/// let partitioner = RoundRobinPartitioner::default();
+///
+/// // Partition 1 has no leader, so it is skipped.
+/// let orders = topic(vec![partition(0, Some(1)), partition(1, None), partition(2, Some(2))]);
+/// let assigned: Vec<_> = (0..4)
+///     .map(|_| partitioner.partition(&orders, Some(b"key"), 100).0)
+///     .collect();
+///
+/// assert_eq!(assigned, [0, 2, 0, 2]);
+/// ```
+#[derive(Debug, Default)]
+pub struct RoundRobinPartitioner {
+    counters: Mutex<HashMap<String, AtomicUsize>>,
+}
+
+impl Partitioner for RoundRobinPartitioner {
+    fn partition(
+        &self,
+        topic: &TopicInfo,
+        _key: Option<&[u8]>,
+        _record_size: usize,
+    ) -> PartitionIndex {
+        let next = {
+            let mut counters = self
+                .counters
+                .lock()
+                .unwrap_or_else(|error| error.into_inner());
+            counters
+                .entry(topic.name.clone())
+                .or_default()
+                .fetch_add(1, Ordering::Relaxed)
+        };
+
+        let available = available_partitions(topic);
+        match available.is_empty() {
+            true => PartitionIndex((next % topic.partition_count()) as i32),
+            false => available[next % available.len()],
+        }
+    }
+}
+
+/// Sends records to one partition of a topic until `batch_size` bytes were sent to it, and then
+/// switches to another partition at random, ignoring keys, as described by
+/// [KIP-794](https://cwiki.apache.org/confluence/display/KAFKA/KIP-794%3A+Strictly+Uniform+Sticky+Partitioner).
+///
+/// Sticking to a partition fills batches faster than spreading records over every partition, and
+/// switching after a fixed number of bytes keeps the partitions evenly loaded. Only partitions
+/// with a known leader are chosen, unless none has one.
+///
+/// ```
+/// # use std::collections::BTreeMap;
+/// # use prague::{cluster::{PartitionInfo, TopicInfo}, producer::partitioner::*};
+/// # use prague_protocol::types::prelude::*;
+/// # let orders = TopicInfo {
+/// #     name: "orders".to_owned(),
+/// #     id: None,
+/// #     is_internal: false,
+/// #     error_code: None,
+/// #     partitions: (0..8)
+/// #         .map(|index| {
+/// #             let partition = PartitionInfo {
+/// #                 index: PartitionIndex(index),
+/// #                 leader: Some(BrokerId(1)),
+/// #                 leader_epoch: None,
+/// #                 replicas: vec![],
+/// #                 isr: vec![],
+/// #                 offline_replicas: vec![],
+/// #                 error_code: None,
+/// #             };
+/// #             (partition.index, partition)
+/// #         })
+/// #         .collect::<BTreeMap<_, _>>(),
+/// # };
+/// let partitioner = UniformStickyPartitioner::new(1000);
+///
+/// // Ten records of 100 bytes fill the sticky partition before another one is chosen.
+/// let first = partitioner.partition(&orders, None, 100);
+/// for _ in 1..10 {
+///     assert_eq!(partitioner.partition(&orders, Some(b"ignored"), 100), first);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct UniformStickyPartitioner {
+    batch_size: usize,
+    topics: Mutex<HashMap<String, StickyPartition>>,
+}
+
+/// The partition records of a topic currently stick to.
+#[derive(Debug)]
+struct StickyPartition {
+    partition: PartitionIndex,
+    produced: usize,
+}
+
+impl UniformStickyPartitioner {
+    /// Creates a partitioner which switches partitions after `batch_size` bytes.
+    pub fn new(batch_size: usize) -> Self {
+        Self {
+            batch_size,
+            topics: Mutex::default(),
+        }
+    }
+
+    fn next_partition(topic: &TopicInfo) -> PartitionIndex {
+        let random = rand::thread_rng().gen::<usize>();
+        let available = available_partitions(topic);
+
+        match available.is_empty() {
+            true => PartitionIndex((random % topic.partition_count()) as i32),
+            false => available[random % available.len()],
+        }
+    }
+}
+
+impl Partitioner for UniformStickyPartitioner {
+    fn partition(
+        &self,
+        topic: &TopicInfo,
+        _key: Option<&[u8]>,
+        record_size: usize,
+    ) -> PartitionIndex {
+        let mut topics = self
+            .topics
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        let sticky = topics
+            .entry(topic.name.clone())
+            .or_insert_with(|| StickyPartition {
+                partition: Self::next_partition(topic),
+                produced: 0,
+            });
+
+        // The partition count may have changed since the partition was chosen.
+        if sticky.produced >= self.batch_size || topic.partition(sticky.partition).is_none() {
+            sticky.partition = Self::next_partition(topic);
+            sticky.produced = 0;
+        }
+
+        sticky.produced += record_size;
+        sticky.partition
+    }
+}
+
+/// Returns the partition the Java client's `DefaultPartitioner` assigns to a key, out of
+/// `partition_count` partitions.
+///
+/// ```
+/// # use prague::producer::partitioner::partition_for_key;
+/// # use prague_protocol::types::prelude::*;
+/// assert_eq!(partition_for_key(b"foobar", 3), PartitionIndex(0));
+/// assert_eq!(partition_for_key(b"foobar", 10), PartitionIndex(6));
+/// assert_eq!(partition_for_key(b"21", 7), PartitionIndex(3));
+/// ```
+pub fn partition_for_key(key: &[u8], partition_count: usize) -> PartitionIndex {
+    let positive = (murmur2(key) & 0x7fffffff) as usize;
+    PartitionIndex((positive % partition_count) as i32)
+}
+
+/// Computes the 32-bit murmur2 hash of some data, using the same seed as the Java client.
+///
+/// ```
+/// # use prague::producer::partitioner::murmur2;
+/// assert_eq!(murmur2(b"21"), -973932308);
+/// assert_eq!(murmur2(b"foobar"), -790332482);
+/// assert_eq!(murmur2(b"a-little-bit-long-string"), -985981536);
+/// assert_eq!(murmur2(b"a-little-bit-longer-string"), -1486304829);
+/// assert_eq!(murmur2(b"lkjh234lh9fiuh90y23oiuhsafujhadof229phr9h19h89h8"), -58897971);
+/// assert_eq!(murmur2(b"abc"), 479470107);
+/// ```
+pub fn murmur2(data: &[u8]) -> i32 {
+    const SEED: u32 = 0x9747b28c;
+    const M: u32 = 0x5bd1e995;
+    const R: u32 = 24;
+
+    let mut h = SEED ^ data.len() as u32;
+
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(M);
+        k ^= k >> R;
+        k = k.wrapping_mul(M);
+        h = h.wrapping_mul(M);
+        h ^= k;
+    }
+
+    let tail = chunks.remainder();
+    if tail.len() >= 3 {
+        h ^= u32::from(tail[2]) << 16;
+    }
+    if tail.len() >= 2 {
+        h ^= u32::from(tail[1]) << 8;
+    }
+    if !tail.is_empty() {
+        h ^= u32::from(tail[0]);
+        h = h.wrapping_mul(M);
+    }
+
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^= h >> 15;
+    h as i32
+}
+
+/// Returns the partitions of a topic which have a known leader.
+fn available_partitions(topic: &TopicInfo) -> Vec<PartitionIndex> {
+    topic
+        .partitions
+        .values()
+        .filter(|partition| partition.leader.is_some())
+        .map(|partition| partition.index)
+        .collect()
+}
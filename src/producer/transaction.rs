@@ -0,0 +1,704 @@
+//! Includes the transactions of a producer with a
+//! [transactional_id](super::ProducerConfig::transactional_id).
+//!
+//! The transaction coordinator of the transactional ID tracks which partitions and consumer
+//! groups each transaction wrote to. A partition is added to the transaction before its first
+//! batch is sent, and the offsets of a consumer group before they are committed, so that the
+//! coordinator can write a commit or abort marker to each of them when the transaction ends.
+//!
+//! Errors which only affect the current transaction are abortable: the producer may be used
+//! again once the transaction was aborted. Errors which mean another producer took over the
+//! transactional ID, such as [ProducerFenced](ErrorCode::ProducerFenced), are fatal.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use prague_protocol::{
+    add_offsets_to_transaction::prelude::*,
+    add_partitions_to_transaction::{prelude::*, request::AddPartitionsToTransactionTopic},
+    api::prelude::*,
+    codec::{Decode, Encode},
+    end_transaction::prelude::*,
+    error_code::ErrorCode,
+    find_coordinator::{prelude::*, request::CoordinatorType},
+    init_producer_id::prelude::*,
+    transaction_offset_commit::{
+        prelude::*,
+        request::{TransactionOffsetCommitPartition, TransactionOffsetCommitTopic},
+    },
+    types::prelude::*,
+};
+use tokio::time::{self, Instant};
+
+use super::{accumulator::TopicPartition, idempotence::ProducerIdAndEpoch, Error, Inner, Producer};
+
+/// Identifies the consumer whose offsets are committed with
+/// [send_offsets_to_transaction](Producer::send_offsets_to_transaction), so that the group
+/// coordinator rejects them if the consumer was fenced from the group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumerGroupMetadata {
+    pub group_id: String,
+
+    /// The generation of the consumer, or None if it is not part of a generation.
+    pub generation_id: Option<i32>,
+
+    pub member_id: String,
+
+    /// The unique identifier of the consumer instance, or None if it is not a static member.
+    pub group_instance_id: Option<String>,
+}
+
+impl ConsumerGroupMetadata {
+    /// Returns the metadata of a consumer which is not a member of the group, whose offsets the
+    /// coordinator does not check against a generation.
+    pub fn new(group_id: impl Into<String>) -> Self {
+        Self {
+            group_id: group_id.into(),
+            generation_id: None,
+            member_id: String::new(),
+            group_instance_id: None,
+        }
+    }
+}
+
+/// The offset to commit for a partition, i.e. the offset of the next record to consume.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetAndMetadata {
+    pub offset: Offset,
+
+    /// The leader epoch of the last consumed record, or None if it is unknown.
+    pub leader_epoch: Option<Epoch>,
+
+    /// Any metadata to keep with the offset.
+    pub metadata: Option<String>,
+}
+
+impl OffsetAndMetadata {
+    pub fn new(offset: Offset) -> Self {
+        Self {
+            offset,
+            leader_epoch: None,
+            metadata: None,
+        }
+    }
+}
+
+/// The transaction state of a transactional producer.
+#[derive(Debug, Default)]
+pub(crate) struct Transaction {
+    state: State,
+
+    /// The transaction coordinator, once it was found.
+    coordinator: Option<BrokerId>,
+
+    /// The partitions the coordinator added to the current transaction.
+    added: HashSet<TopicPartition>,
+
+    /// The partitions records were sent to in the current transaction, which are not added yet.
+    pending: HashSet<TopicPartition>,
+
+    /// Whether the offsets of a consumer group were added to the current transaction.
+    offsets_added: bool,
+}
+
+#[derive(Debug, Default)]
+enum State {
+    #[default]
+    Uninitialized,
+    Initializing,
+    Ready,
+    InTransaction,
+    Committing,
+    Aborting,
+
+    /// The current transaction failed, and must be aborted.
+    AbortableError(Error),
+
+    /// The producer cannot be used anymore.
+    FatalError(Error),
+}
+
+impl Transaction {
+    /// Returns an error unless records may be sent, which is only within a transaction.
+    pub fn check_can_send(&self) -> Result<(), Error> {
+        match &self.state {
+            State::InTransaction => Ok(()),
+            _ => Err(self.error().unwrap_or(Error::InvalidTransactionState(
+                "records may only be sent within a transaction",
+            ))),
+        }
+    }
+
+    /// Records that a record is sent to a partition, which is added to the transaction before
+    /// its first batch is sent.
+    pub fn add_partition(&mut self, partition: TopicPartition) {
+        if !self.added.contains(&partition) {
+            self.pending.insert(partition);
+        }
+    }
+
+    /// Returns whether the coordinator added a partition to the current transaction.
+    pub fn is_added(&self, partition: &TopicPartition) -> bool {
+        self.added.contains(partition)
+    }
+
+    /// Records that the current transaction failed with an error. The transaction must then be
+    /// aborted, unless the error is fatal.
+    pub fn fail(&mut self, error: Error) {
+        match &self.state {
+            State::FatalError(_) => {}
+            _ if is_fatal(&error) => self.state = State::FatalError(error),
+            State::InTransaction | State::Committing => self.state = State::AbortableError(error),
+            _ => {}
+        }
+    }
+
+    /// Returns the error the current transaction failed with, if any.
+    pub fn error(&self) -> Option<Error> {
+        match &self.state {
+            State::AbortableError(error) => Some(Error::AbortableError(Box::new(error.clone()))),
+            State::FatalError(error) => Some(Error::FatalError(Box::new(error.clone()))),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the coordinator must end the current transaction, which is once it was
+    /// told about any partition or offsets.
+    fn is_started(&self) -> bool {
+        !self.added.is_empty() || self.offsets_added
+    }
+
+    /// Moves to the given state if the current one is allowed, and returns an error otherwise.
+    fn transition(
+        &mut self,
+        to: State,
+        allowed: impl Fn(&State) -> bool,
+        invalid: &'static str,
+    ) -> Result<(), Error> {
+        if allowed(&self.state) {
+            self.state = to;
+            return Ok(());
+        }
+
+        Err(self
+            .error()
+            .unwrap_or(Error::InvalidTransactionState(invalid)))
+    }
+
+    /// Ends the current transaction, so that a new one may begin.
+    fn reset(&mut self) {
+        self.state = State::Ready;
+        self.added.clear();
+        self.pending.clear();
+        self.offsets_added = false;
+    }
+}
+
+impl Producer {
+    /// Initializes the producer ID of the transactional ID, which must be done once before the
+    /// first transaction.
+    ///
+    /// This fences any other producer with the same transactional ID, and completes the
+    /// transaction it left open, if any.
+    pub async fn init_transactions(&self) -> Result<(), Error> {
+        let inner = &self.inner;
+        let transactional_id = inner.transactional_id()?;
+
+        inner.transaction().transition(
+            State::Initializing,
+            |state| matches!(state, State::Uninitialized),
+            "transactions were already initialized",
+        )?;
+
+        let request = inner.init_producer_id_request(None);
+        let result = inner
+            .coordinator_request::<InitProducerIdApi>(
+                CoordinatorType::Transaction,
+                transactional_id,
+                &request,
+                |response| response.error_code,
+            )
+            .await;
+
+        match result {
+            Ok(response) => {
+                inner.sequences().reset(ProducerIdAndEpoch {
+                    id: response.producer_id,
+                    epoch: response.producer_epoch,
+                });
+                inner.transaction().reset();
+                Ok(())
+            }
+            Err(error) => {
+                let mut transaction = inner.transaction();
+                transaction.state = match is_fatal(&error) {
+                    true => State::FatalError(error.clone()),
+                    false => State::Uninitialized,
+                };
+                Err(transaction.error().unwrap_or(error))
+            }
+        }
+    }
+
+    /// Begins a transaction. Records sent from now on are only visible to consumers reading
+    /// committed records once [commit_transaction](Self::commit_transaction) succeeds.
+    pub fn begin_transaction(&self) -> Result<(), Error> {
+        self.inner.transactional_id()?;
+        self.inner.transaction().transition(
+            State::InTransaction,
+            |state| matches!(state, State::Ready),
+            "a transaction may only begin once transactions were initialized and the previous one ended",
+        )
+    }
+
+    /// Commits the offsets of a consumer group as part of the current transaction, so that they
+    /// only take effect if the transaction commits.
+    ///
+    /// This is how a consume-transform-produce loop consumes each record exactly once: the
+    /// offsets of the records it consumed are committed together with the records it produced.
+    pub async fn send_offsets_to_transaction(
+        &self,
+        offsets: HashMap<(String, PartitionIndex), OffsetAndMetadata>,
+        group: &ConsumerGroupMetadata,
+    ) -> Result<(), Error> {
+        let inner = &self.inner;
+        let transactional_id = inner.transactional_id()?;
+        inner.transaction().check_can_send()?;
+        let producer = inner.producer()?;
+
+        let request = AddOffsetsToTransactionRequest {
+            transactional_id: transactional_id.to_owned(),
+            producer_id: producer.id,
+            producer_epoch: producer.epoch,
+            group_id: group.group_id.clone(),
+            tagged_fields: Default::default(),
+        };
+        inner
+            .coordinator_request::<AddOffsetsToTransactionApi>(
+                CoordinatorType::Transaction,
+                transactional_id,
+                &request,
+                |response| response.error_code,
+            )
+            .await
+            .map_err(|error| inner.fail_transaction(error))?;
+        inner.transaction().offsets_added = true;
+
+        let mut topics: Vec<TransactionOffsetCommitTopic> = Vec::new();
+        for ((topic, partition), offset) in offsets {
+            let partition = TransactionOffsetCommitPartition {
+                partition_index: partition,
+                committed_offset: offset.offset,
+                committed_leader_epoch: offset.leader_epoch,
+                committed_metadata: offset.metadata,
+                tagged_fields: Default::default(),
+            };
+
+            match topics.iter_mut().find(|target| target.name == topic) {
+                Some(target) => target.partitions.push(partition),
+                None => topics.push(TransactionOffsetCommitTopic {
+                    name: topic,
+                    partitions: vec![partition],
+                    tagged_fields: Default::default(),
+                }),
+            }
+        }
+
+        let request = TransactionOffsetCommitRequest {
+            transactional_id: transactional_id.to_owned(),
+            group_id: group.group_id.clone(),
+            producer_id: producer.id,
+            producer_epoch: producer.epoch,
+            generation_id: group.generation_id,
+            member_id: group.member_id.clone(),
+            group_instance_id: group.group_instance_id.clone(),
+            topics,
+            tagged_fields: Default::default(),
+        };
+        inner
+            .coordinator_request::<TransactionOffsetCommitApi>(
+                CoordinatorType::Group,
+                &group.group_id,
+                &request,
+                |response| {
+                    let partitions = response.topics.iter().flat_map(|topic| &topic.partitions);
+                    first_error(partitions.map(|partition| partition.error_code))
+                },
+            )
+            .await
+            .map_err(|error| inner.fail_transaction(error))?;
+
+        Ok(())
+    }
+
+    /// Sends every record of the current transaction, and then commits it.
+    ///
+    /// If a record failed, the transaction fails with an [AbortableError](Error::AbortableError)
+    /// and must be aborted with [abort_transaction](Self::abort_transaction) instead. A commit
+    /// which timed out may be retried.
+    pub async fn commit_transaction(&self) -> Result<(), Error> {
+        let inner = &self.inner;
+        let transactional_id = inner.transactional_id()?;
+
+        inner.transaction().transition(
+            State::Committing,
+            |state| matches!(state, State::InTransaction | State::Committing),
+            "only an ongoing transaction may be committed",
+        )?;
+
+        self.flush().await;
+
+        let started = {
+            let transaction = inner.transaction();
+            if let Some(error) = transaction.error() {
+                return Err(error);
+            }
+            transaction.is_started()
+        };
+
+        if started {
+            inner.end_transaction(transactional_id, true).await?;
+        }
+
+        inner.transaction().reset();
+        Ok(())
+    }
+
+    /// Aborts the current transaction. Records which were not sent yet fail with
+    /// [TransactionAborted](Error::TransactionAborted), and records which were written are never
+    /// visible to consumers reading committed records.
+    pub async fn abort_transaction(&self) -> Result<(), Error> {
+        let inner = &self.inner;
+        let transactional_id = inner.transactional_id()?;
+
+        inner.transaction().transition(
+            State::Aborting,
+            |state| {
+                matches!(
+                    state,
+                    State::InTransaction | State::AbortableError(_) | State::Aborting
+                )
+            },
+            "only an ongoing transaction may be aborted",
+        )?;
+
+        let batches = inner.accumulator().take_all();
+        for batch in batches {
+            inner.fail(batch, Error::TransactionAborted);
+        }
+
+        // Batches in flight may still be written, and are aborted with the transaction.
+        let mut pending = inner.pending.subscribe();
+        let _ = pending.wait_for(|&pending| pending == 0).await;
+
+        if inner.transaction().is_started() {
+            inner.end_transaction(transactional_id, false).await?;
+        }
+
+        // A batch which failed may have left a gap in the sequence of its partition, which only
+        // a new epoch closes.
+        let bump = inner.sequences().bump_required();
+        if let Some(current) = bump {
+            let request = inner.init_producer_id_request(Some(current));
+            let response = inner
+                .coordinator_request::<InitProducerIdApi>(
+                    CoordinatorType::Transaction,
+                    transactional_id,
+                    &request,
+                    |response| response.error_code,
+                )
+                .await
+                .map_err(|error| inner.fail_transaction(error))?;
+
+            inner.sequences().reset(ProducerIdAndEpoch {
+                id: response.producer_id,
+                epoch: response.producer_epoch,
+            });
+        }
+
+        inner.transaction().reset();
+        Ok(())
+    }
+}
+
+impl Inner {
+    fn transactional_id(&self) -> Result<&str, Error> {
+        self.config
+            .transactional_id
+            .as_deref()
+            .ok_or(Error::InvalidTransactionState(
+                "the producer has no transactional ID",
+            ))
+    }
+
+    fn producer(&self) -> Result<ProducerIdAndEpoch, Error> {
+        self.sequences()
+            .producer()
+            .ok_or(Error::InvalidTransactionState(
+                "the producer ID must be initialized first",
+            ))
+    }
+
+    /// Records that the current transaction failed with an error from its coordinator, and
+    /// returns the error to report. A timeout leaves the transaction as is, so that the request
+    /// may be retried.
+    fn fail_transaction(&self, error: Error) -> Error {
+        if matches!(error, Error::CoordinatorTimeout(_)) {
+            return error;
+        }
+
+        let mut transaction = self.transaction();
+        transaction.fail(error.clone());
+        transaction.error().unwrap_or(error)
+    }
+
+    /// Adds the partitions records were sent to in the current transaction, if any, before
+    /// their batches are sent. Returns when to try again if the coordinator could not be
+    /// reached.
+    ///
+    /// Once the transaction failed, its batches fail without being sent.
+    pub(super) async fn maybe_add_partitions(&self) -> Option<Instant> {
+        let (pending, error) = {
+            let transaction = self.transaction();
+            let pending = match transaction.state {
+                State::InTransaction | State::Committing => transaction.pending.clone(),
+                _ => HashSet::new(),
+            };
+            (pending, transaction.error())
+        };
+
+        if let Some(error) = error {
+            let batches = self.accumulator().take_all();
+            for batch in batches {
+                self.fail(batch, error.clone());
+            }
+            return None;
+        }
+
+        let (Ok(transactional_id), Ok(producer)) = (self.transactional_id(), self.producer())
+        else {
+            return None;
+        };
+        if pending.is_empty() {
+            return None;
+        }
+
+        let mut topics: Vec<AddPartitionsToTransactionTopic> = Vec::new();
+        for (topic, partition) in &pending {
+            match topics.iter_mut().find(|target| &target.name == topic) {
+                Some(target) => target.partitions.push(*partition),
+                None => topics.push(AddPartitionsToTransactionTopic {
+                    name: topic.clone(),
+                    partitions: vec![*partition],
+                    tagged_fields: Default::default(),
+                }),
+            }
+        }
+
+        let request = AddPartitionsToTransactionRequest {
+            transactional_id: transactional_id.to_owned(),
+            producer_id: producer.id,
+            producer_epoch: producer.epoch,
+            topics,
+            tagged_fields: Default::default(),
+        };
+        let result = self
+            .coordinator_request::<AddPartitionsToTransactionApi>(
+                CoordinatorType::Transaction,
+                transactional_id,
+                &request,
+                |response| {
+                    let partitions = response
+                        .results_by_topic
+                        .iter()
+                        .flat_map(|topic| &topic.results_by_partition);
+                    first_error(partitions.map(|partition| partition.partition_error_code))
+                },
+            )
+            .await;
+
+        match result {
+            Ok(_) => {
+                let mut transaction = self.transaction();
+                for partition in pending {
+                    transaction.pending.remove(&partition);
+                    transaction.added.insert(partition);
+                }
+                None
+            }
+            Err(Error::CoordinatorTimeout(_)) => Some(Instant::now() + self.config.retry_backoff),
+            Err(error) => {
+                self.fail_transaction(error);
+                Some(Instant::now())
+            }
+        }
+    }
+
+    /// Commits or aborts the current transaction.
+    async fn end_transaction(&self, transactional_id: &str, committed: bool) -> Result<(), Error> {
+        let producer = self.producer()?;
+        let request = EndTransactionRequest {
+            transactional_id: transactional_id.to_owned(),
+            producer_id: producer.id,
+            producer_epoch: producer.epoch,
+            committed,
+            tagged_fields: Default::default(),
+        };
+
+        self.coordinator_request::<EndTransactionApi>(
+            CoordinatorType::Transaction,
+            transactional_id,
+            &request,
+            |response| response.error_code,
+        )
+        .await
+        .map_err(|error| self.fail_transaction(error))?;
+        Ok(())
+    }
+
+    /// Sends a request to the coordinator of a key, and returns its response unless
+    /// `error_code` finds an error in it.
+    ///
+    /// Retriable errors are retried after the [retry_backoff](super::ProducerConfig::retry_backoff),
+    /// looking up the coordinator again if it moved, until the cluster's
+    /// [max_block](crate::cluster::ClusterConfig::max_block) elapses.
+    async fn coordinator_request<A>(
+        &self,
+        key_type: CoordinatorType,
+        key: &str,
+        request: &A::Request,
+        error_code: impl Fn(&A::Response) -> Option<ErrorCode>,
+    ) -> Result<A::Response, Error>
+    where
+        A: VersionedApi,
+        A::Request: Encode,
+        A::Response: Decode,
+    {
+        let deadline = Instant::now() + self.pool.cluster().config().max_block;
+
+        loop {
+            let result = match self.find_coordinator(key_type, key).await {
+                Ok(coordinator) => self
+                    .pool
+                    .send::<A>(coordinator, request)
+                    .await
+                    .map_err(|error| Error::Pool(Arc::new(error))),
+                Err(error) => Err(error),
+            };
+
+            let error = match result {
+                Ok(response) => match error_code(&response) {
+                    None => return Ok(response),
+                    Some(error_code) => Error::Coordinator(error_code),
+                },
+                Err(error) => error,
+            };
+
+            match error {
+                Error::Coordinator(
+                    ErrorCode::NotCoordinator | ErrorCode::CoordinatorNotAvailable,
+                ) => self.forget_coordinator(key_type),
+                Error::Coordinator(error_code) if !error_code.is_retriable() => {
+                    return Err(error);
+                }
+                Error::Coordinator(_) => {}
+                // The coordinator may have moved, or be unreachable for a while.
+                _ => self.forget_coordinator(key_type),
+            }
+
+            let retry_at = Instant::now() + self.config.retry_backoff;
+            if retry_at >= deadline {
+                return Err(Error::CoordinatorTimeout(A::KEY));
+            }
+            time::sleep_until(retry_at).await;
+        }
+    }
+
+    /// Returns the coordinator of a key, asking any broker for it unless it is the transaction
+    /// coordinator and was found before.
+    async fn find_coordinator(
+        &self,
+        key_type: CoordinatorType,
+        key: &str,
+    ) -> Result<BrokerId, Error> {
+        if key_type == CoordinatorType::Transaction {
+            if let Some(coordinator) = self.transaction().coordinator {
+                return Ok(coordinator);
+            }
+        }
+
+        let request = FindCoordinatorRequest {
+            key: key.to_owned(),
+            key_type,
+            coordinator_keys: vec![key.to_owned()],
+            tagged_fields: Default::default(),
+        };
+        let broker = self.any_broker().await?;
+        let response = self
+            .pool
+            .send::<FindCoordinatorApi>(broker, &request)
+            .await
+            .map_err(|error| Error::Pool(Arc::new(error)))?;
+
+        let coordinator = response.into_coordinators(key).into_iter().next();
+        let node_id = match coordinator {
+            Some(coordinator) => match coordinator.error_code {
+                Some(error_code) => return Err(Error::Coordinator(error_code)),
+                None => coordinator.node_id,
+            },
+            None => None,
+        };
+        let node_id = node_id.ok_or(Error::Coordinator(ErrorCode::CoordinatorNotAvailable))?;
+
+        if key_type == CoordinatorType::Transaction {
+            self.transaction().coordinator = Some(node_id);
+        }
+        Ok(node_id)
+    }
+
+    fn forget_coordinator(&self, key_type: CoordinatorType) {
+        if key_type == CoordinatorType::Transaction {
+            self.transaction().coordinator = None;
+        }
+    }
+}
+
+/// Returns the error of a response with one error code per partition. The partitions which
+/// were not attempted because another one failed are only reported if nothing else is.
+fn first_error(error_codes: impl Iterator<Item = Option<ErrorCode>>) -> Option<ErrorCode> {
+    let mut first = None;
+    for error_code in error_codes.flatten() {
+        match error_code {
+            ErrorCode::OperationNotAttempted => first = first.or(Some(error_code)),
+            _ => return Some(error_code),
+        }
+    }
+    first
+}
+
+/// Returns whether an error means the producer cannot be used anymore, rather than only the
+/// current transaction.
+fn is_fatal(error: &Error) -> bool {
+    let error_code = match error {
+        Error::Broker { error_code, .. }
+        | Error::Coordinator(error_code)
+        | Error::InitProducerId(error_code) => *error_code,
+        Error::FatalError(_) => return true,
+        _ => return false,
+    };
+
+    matches!(
+        error_code,
+        ErrorCode::ProducerFenced
+            | ErrorCode::InvalidProducerEpoch
+            | ErrorCode::TransactionalIdAuthorizationFailed
+            | ErrorCode::ClusterAuthorizationFailed
+            | ErrorCode::InvalidTransactionState
+            | ErrorCode::InvalidTransactionTimeout
+            | ErrorCode::TransactionalIdNotFound
+            | ErrorCode::UnsupportedVersion
+            | ErrorCode::UnsupportedForMessageFormat
+    )
+}